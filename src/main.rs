@@ -5,7 +5,7 @@ use sfml::{
     window::{Event, Style},
 };
 use tracing::error;
-use ui::ui_settings::UISettings;
+use ui::{ui_settings::UISettings, ui_state::UIState};
 use utils::{
     fps_counter::FPSCounter,
     resource_manager::{ResourceManager, ASSETS_PATH},
@@ -36,6 +36,7 @@ fn main() {
     let mut window = RenderWindow::new(WINDOW_SIZE, "PACRB", Style::DEFAULT, &Default::default());
     set_window_logo(&mut window);
     let mut ui_settings = UISettings::from_file();
+    let mut ui_state = UIState::default();
     ui_settings.synchronize_ui_settings_and_sfml(&mut window);
     // This prevents ui elements from creating render textures that are of size 0x0
     ui_settings.event_handler(Event::Resized {
@@ -49,6 +50,7 @@ fn main() {
     while window.is_open() {
         for event in ui_settings.normalize_events(&mut window) {
             ui_settings.event_handler(event);
+            ui_state.event_handler(&ui_settings, event);
             match event {
                 Event::Closed => window.close(),
                 Event::Resized { width, height } => {
@@ -58,12 +60,12 @@ fn main() {
                 }
                 _ => {}
             }
-            pallete_builder.event_handler(&mut window, &mut ui_settings, event);
+            pallete_builder.event_handler(&mut window, &mut ui_settings, &mut ui_state, event);
         }
         fps_counter.new_frame();
         pallete_builder.update(&resource_manager);
         window.clear(Color::rgb(35, 38, 39));
-        pallete_builder.render(&mut window);
+        pallete_builder.render(&mut window, &ui_state);
         // window.draw(fps_counter.fps_text());
         window.display();
     }
@@ -1,39 +1,66 @@
+use std::mem;
+
 use sfml::{
-    graphics::{RenderTarget, RenderWindow, View},
+    graphics::{FloatRect, RenderTarget, RenderWindow},
     system::{Vector2, Vector2f, Vector2i},
     window::{
         mouse::{Button, Wheel},
-        Event, Key,
+        Event,
+    },
+};
+use tracing::{error, warn};
+use ui::{
+    dom_controller::DomControllerInterface,
+    ui_settings::{
+        controls::{possible_binds::PossibleBinds, Bindings},
+        UISettings,
     },
-    SfBox,
+    ui_state::UIState,
+    utils::consts::DUMMY_MOUSE_MOVED_EVENT,
 };
-use tracing::error;
-use ui::{dom_controller::DomControllerInterface, ui_settings::UISettings};
 use utils::resource_manager::ResourceManager;
 
 use crate::generate_ramp_mode_event_handler_arguments;
 
 use self::{
     color_grid::{
-        color_cell::CELL_SIZE,
         load_save::{export_color_grid, load_color_grid, save_color_grid},
+        renderer::ColorGridRenderer,
         undo_redo::UndoRedoCell,
         ColorGrid,
     },
+    grid_view::GridView,
+    hsv_color::Hsv,
     normal_mode::{NormalMode, NormalModeEventHandlerArguments},
+    pallete_tab::{tab_title, PalleteTab, SavedState},
     ramp_mode::{RampMode, RampModeEventHandlerArguments},
+    reference_image::{ReferenceImage, ReferenceImageState},
+    screen_eyedropper::ScreenEyedropper,
     ui_components::{
-        config_selector::ConfigSelector, confirm_color_ramp::ConfirmColorRamp,
-        current_quick_save_file::CurrentQuickSaveFile, erase_mode::EraseMode,
-        hsv_selector::HSVSelector, settings::Settings,
+        color_swatches::ColorSwatches,
+        config_selector::ConfigSelector,
+        confirm_color_ramp::ConfirmColorRamp,
+        current_quick_save_file::CurrentQuickSaveFile,
+        erase_mode::EraseMode,
+        hsv_selector::HSVSelector,
+        minimap::Minimap,
+        recolor_preview::RecolorPreview,
+        reference_panel::ReferencePanel,
+        settings::Settings,
+        tab_strip::{TabStrip, MAX_TABS},
     },
 };
 
 mod color_grid;
+mod grid_view;
 mod hover_handler;
 pub mod hsv_color;
 mod normal_mode;
+mod pallete_tab;
 mod ramp_mode;
+mod reference_image;
+mod screen_eyedropper;
+mod sprite_recolor;
 mod ui_components;
 
 enum Mode {
@@ -41,18 +68,45 @@ enum Mode {
     RampMode(RampMode),
 }
 
-const VIEW_MIN_SIZE: Vector2f = Vector2f::new(100f32, 100f32);
-const VIEW_MAX_SIZE: Vector2f = Vector2f::new(1920f32, 1080f32);
+const ZOOM_STEP: f32 = 1.1;
+/// Fraction of the view moved per keyboard pan
+const KEYBOARD_PAN_STEP: f32 = 0.125;
+const KEYBOARD_PAN_BINDS: [(PossibleBinds, Vector2f); 4] = [
+    (PossibleBinds::PanLeft, Vector2f::new(-1., 0.)),
+    (PossibleBinds::PanRight, Vector2f::new(1., 0.)),
+    (PossibleBinds::PanUp, Vector2f::new(0., -1.)),
+    (PossibleBinds::PanDown, Vector2f::new(0., 1.)),
+];
+const ZOOM_PRESET_BINDS: [(PossibleBinds, f32); 4] = [
+    (PossibleBinds::ZoomPreset1, 1.),
+    (PossibleBinds::ZoomPreset2, 2.),
+    (PossibleBinds::ZoomPreset3, 3.),
+    (PossibleBinds::ZoomPreset4, 4.),
+];
 pub struct PalleteBuilder {
     current_mode: Mode,
     config_selector: ConfigSelector,
     hsv_selector: HSVSelector,
+    color_swatches: ColorSwatches,
     erase_mode: EraseMode,
     current_quick_save_file: CurrentQuickSaveFile,
     confirm_color_ramp: ConfirmColorRamp,
     settings: Settings,
+    minimap: Minimap,
+    recolor_preview: RecolorPreview,
+    reference_panel: ReferencePanel,
+    tab_strip: TabStrip,
+    /// The active tab is unpacked into `color_grid`, `undo_redo`, `reference_image`, the
+    /// config selector, the grid view and the save file in settings. See [`PalleteTab`]
+    tabs: Vec<PalleteTab>,
+    active_tab: usize,
     color_grid: ColorGrid,
-    view: SfBox<View>,
+    color_grid_renderer: ColorGridRenderer,
+    grid_view: GridView,
+    reference_image: Option<ReferenceImage>,
+    /// World position of the cursor while the reference image is being dragged
+    dragging_reference_from: Option<Vector2f>,
+    screen_eyedropper: ScreenEyedropper,
     is_dragging_erase: bool,
     is_dragging_screen: bool,
     lmb_dragging_from_ui_component: bool,
@@ -62,41 +116,51 @@ pub struct PalleteBuilder {
 
 impl PalleteBuilder {
     pub fn new(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
-        let color_grid = ColorGrid::new();
-        Self {
+        let mut first_tab = PalleteTab::default();
+        let color_grid = mem::take(&mut first_tab.color_grid);
+        let mut pallete_builder = Self {
             current_mode: Mode::NormalMode(Default::default()),
             hsv_selector: HSVSelector::new(resource_manager, ui_settings),
+            color_swatches: ColorSwatches::new(resource_manager, ui_settings),
             config_selector: ConfigSelector::new(resource_manager, ui_settings),
             confirm_color_ramp: ConfirmColorRamp::new(resource_manager, ui_settings),
             current_quick_save_file: CurrentQuickSaveFile::new(resource_manager, ui_settings),
             erase_mode: EraseMode::new(resource_manager, ui_settings),
             settings: Settings::new(resource_manager, ui_settings),
+            minimap: Minimap::new(resource_manager, ui_settings),
+            recolor_preview: RecolorPreview::new(resource_manager, ui_settings),
+            reference_panel: ReferencePanel::new(resource_manager, ui_settings),
+            tab_strip: TabStrip::new(resource_manager, ui_settings),
+            tabs: vec![first_tab],
+            active_tab: 0,
             is_dragging_erase: false,
             is_dragging_screen: false,
             previous_mouse_position: Default::default(),
-            view: View::new(
-                Vector2f::new(
-                    (color_grid[0].len() / 2
-                        * usize::try_from(CELL_SIZE.x)
-                            .expect("Crashes immediately bad CELL_SIZE definition"))
-                        as f32,
-                    (color_grid[0].len() / 2
-                        * usize::try_from(CELL_SIZE.y)
-                            .expect("Crashes immediately bad CELL_SIZE definition"))
-                        as f32,
-                ),
+            grid_view: GridView::new(
+                color_grid.world_center(),
                 ui_settings.aspect_ratio.current_resolution,
             ),
             color_grid,
+            color_grid_renderer: Default::default(),
+            reference_image: None,
+            dragging_reference_from: None,
+            screen_eyedropper: Default::default(),
             undo_redo: Default::default(),
             lmb_dragging_from_ui_component: false,
-        }
+        };
+        pallete_builder.mark_active_tab_saved();
+        pallete_builder
     }
 
-    pub fn dom_controller_interfaces_iter_mut(&mut self) -> [&mut dyn DomControllerInterface; 6] {
+    pub fn dom_controller_interfaces_iter_mut(&mut self) -> [&mut dyn DomControllerInterface; 11] {
         [
+            &mut self.minimap,
+            &mut self.recolor_preview,
+            &mut self.reference_panel,
+            &mut self.tab_strip,
             &mut self.config_selector,
             &mut self.hsv_selector,
+            &mut self.color_swatches,
             &mut self.erase_mode,
             &mut self.confirm_color_ramp,
             &mut self.settings,
@@ -108,20 +172,60 @@ impl PalleteBuilder {
         &mut self,
         window: &mut RenderWindow,
         ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
+        event: Event,
+    ) {
+        self.dispatch_event(window, ui_settings, ui_state, event);
+        self.finish_focus_navigation(window, ui_settings, ui_state);
+    }
+
+    /// Once every component has offered its elements, moves focus to the closest one
+    fn finish_focus_navigation(
+        &mut self,
+        window: &mut RenderWindow,
+        ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
+    ) {
+        if !ui_state.focus.finish_navigation() {
+            return;
+        }
+
+        // Lets the newly focused element show itself as hovered
+        for dci in self.dom_controller_interfaces_iter_mut() {
+            dci.event_handler(window, ui_settings, ui_state, DUMMY_MOUSE_MOVED_EVENT);
+        }
+    }
+
+    fn dispatch_event(
+        &mut self,
+        window: &mut RenderWindow,
+        ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
         event: Event,
     ) {
         let mut events = Vec::new();
-        if (!self
-            .settings
-            .event_handler(window, ui_settings, event)
-            .is_empty()
-            || self.settings.is_displayed())
-            && !matches!(event, Event::Resized { .. })
+        // Dialogs are modal, everything under them only sees resizes
+        if ui_state.dialogs.blocks_input() && !matches!(event, Event::Resized { .. }) {
+            for dci in self.dom_controller_interfaces_iter_mut() {
+                if dci.has_open_dialog() {
+                    dci.event_handler(window, ui_settings, ui_state, event);
+                }
+            }
+            return;
+        }
+        if !ui_state.text_input.is_typing()
+            && self
+                .screen_eyedropper
+                .event_handler(&event, &ui_settings.binds)
         {
             return;
         }
         for dci in self.dom_controller_interfaces_iter_mut() {
-            events.append(&mut dci.event_handler(window, ui_settings, event))
+            events.append(&mut dci.event_handler(window, ui_settings, ui_state, event))
+        }
+        // A dialog opened by this event, it must not reach the grid
+        if ui_state.dialogs.blocks_input() && !matches!(event, Event::Resized { .. }) {
+            return;
         }
 
         // Whenever configs change, we need to modify the ramp, if it is ramping
@@ -134,7 +238,7 @@ impl PalleteBuilder {
 
             if self.confirm_color_ramp.cancel_ramp() {
                 self.confirm_color_ramp.set_enable(false);
-                ramp_mode.clear_the_ramp(&mut self.undo_redo);
+                ramp_mode.clear_the_ramp(&mut self.color_grid, &mut self.undo_redo);
             }
         }
 
@@ -144,7 +248,7 @@ impl PalleteBuilder {
             }
             Mode::RampMode(ramp_mode) if !self.config_selector.current_config().auto_ramping => {
                 self.confirm_color_ramp.set_enable(false);
-                ramp_mode.clear_the_ramp(&mut self.undo_redo);
+                ramp_mode.clear_the_ramp(&mut self.color_grid, &mut self.undo_redo);
                 self.current_mode = Mode::NormalMode(NormalMode::default())
             }
             _ => {}
@@ -161,16 +265,24 @@ impl PalleteBuilder {
             self.lmb_dragging_from_ui_component = false;
             return;
         }
+        // Key presses are typed into the selected text box, they are not shortcuts
+        if ui_state.text_input.is_typing() && matches!(event, Event::KeyPressed { .. }) {
+            return;
+        }
 
-        self.view_event_handler(&event);
-        let event = self.correct_mouse_pos_event(event, window);
-        if self.general_mouse_button_event_handler(&event) {
+        let binds = &ui_settings.binds;
+        self.view_event_handler(&event, binds, ui_state.focus.has_focus());
+        let event = self.correct_mouse_pos_event(event);
+        if self.recolor_mapping_event_handler(&event)
+            || self.general_mouse_button_event_handler(&event, binds)
+            || self.reference_drag_event_handler(&event, binds)
+        {
             return;
         }
-        self.erase_event_handler(&event);
-        self.drag_screen_event_handler(&event);
-        self.undo_redo_event_handler(&event);
-        self.general_other_key_event_handler(&event, ui_settings);
+        self.erase_event_handler(&event, binds);
+        self.drag_screen_event_handler(&event, binds);
+        self.undo_redo_event_handler(&event, binds);
+        self.general_other_key_event_handler(&event, ui_settings, ui_state);
 
         match &mut self.current_mode {
             Mode::NormalMode(normal_mode) => {
@@ -216,132 +328,164 @@ impl PalleteBuilder {
 
             if self.confirm_color_ramp.cancel_ramp() {
                 self.confirm_color_ramp.set_enable(false);
-                ramp_mode.clear_the_ramp(&mut self.undo_redo);
+                ramp_mode.clear_the_ramp(&mut self.color_grid, &mut self.undo_redo);
             }
         }
 
-        self.color_grid.update();
+        let hovered_cells = match &self.current_mode {
+            Mode::NormalMode(normal_mode) => normal_mode.hovered_cells(),
+            Mode::RampMode(ramp_mode) => ramp_mode.hovered_cells(),
+        };
+        self.color_grid_renderer
+            .update(&self.color_grid, hovered_cells);
+
+        self.check_tab_strip_and_update_tabs_if_necessary();
+        self.check_reference_panel_and_update_reference_if_necessary();
+        self.check_screen_eyedropper_and_set_color_if_necessary();
+        self.check_color_swatches_and_pick_color_if_necessary();
+        self.check_minimap_and_move_view_if_necessary();
+        self.minimap.update_color_grid(&self.color_grid);
+        self.recolor_preview.update_color_grid(&self.color_grid);
+        self.minimap.set_view_rect(self.grid_view.world_rect());
 
         self.check_settings_and_load_file_if_necessary();
+        self.check_settings_and_resize_grid_if_necessary();
         self.check_settings_and_save_file_if_necessary();
         self.check_quick_save_file_name_and_update_if_necessary();
         self.check_export_file_status_and_export_if_necessary();
     }
 
-    pub fn render(&mut self, window: &mut RenderWindow) {
-        window.set_view(&self.view);
-        self.color_grid.render(window);
+    pub fn render(&mut self, window: &mut RenderWindow, ui_state: &UIState) {
+        window.set_view(self.grid_view.view());
+        if let Some(reference_image) = &self.reference_image {
+            reference_image.render(window);
+        }
+        self.color_grid_renderer.render(window);
 
         for dci in self.dom_controller_interfaces_iter_mut() {
             dci.render(window);
         }
+        // Open dialogs go over every other component, the ones opened last on top
+        for &dom_id in ui_state.dialogs.dom_ids() {
+            for dci in self.dom_controller_interfaces_iter_mut() {
+                dci.render_dialog(window, dom_id);
+            }
+        }
+        self.screen_eyedropper.render(window);
     }
 }
 
 /********* EVENT HANDLING CODE ***********/
 impl PalleteBuilder {
-    fn correct_mouse_pos_event(&mut self, mut event: Event, window: &RenderWindow) -> Event {
-        fn mutate_mouse_pos(x: &mut i32, y: &mut i32, view: &SfBox<View>, window: &RenderWindow) {
-            let tx = view.size().x / (window.size().x as f32) * (*x as f32);
-            let ty = view.size().y / (window.size().y as f32) * (*y as f32);
-            *x = (tx + view.center().x - view.size().x / 2f32) as i32;
-            *y = (ty + view.center().y - view.size().y / 2f32) as i32;
+    fn correct_mouse_pos_event(&mut self, mut event: Event) -> Event {
+        fn mutate_mouse_pos(x: &mut i32, y: &mut i32, grid_view: &GridView) {
+            let world_position = grid_view.window_to_world(Vector2::new(*x, *y));
+            *x = world_position.x as i32;
+            *y = world_position.y as i32;
         }
         match event {
             Event::MouseButtonPressed {
                 button: _,
                 ref mut x,
                 ref mut y,
-            } => mutate_mouse_pos(x, y, &self.view, window),
+            } => mutate_mouse_pos(x, y, &self.grid_view),
             Event::MouseButtonReleased {
                 button: _,
                 ref mut x,
                 ref mut y,
-            } => mutate_mouse_pos(x, y, &self.view, window),
+            } => mutate_mouse_pos(x, y, &self.grid_view),
             Event::MouseMoved {
                 ref mut x,
                 ref mut y,
-            } => mutate_mouse_pos(x, y, &self.view, window),
+            } => mutate_mouse_pos(x, y, &self.grid_view),
             Event::MouseWheelScrolled {
                 wheel: _,
-                delta,
+                delta: _,
                 ref mut x,
                 ref mut y,
-            } if self.view.size().x > VIEW_MIN_SIZE.x
-                && self.view.size().y > VIEW_MIN_SIZE.y
-                && delta.is_sign_positive() =>
-            {
-                mutate_mouse_pos(x, y, &self.view, window)
-            }
+            } => mutate_mouse_pos(x, y, &self.grid_view),
             _ => {}
         };
 
         event
     }
 
-    fn view_event_handler(&mut self, event: &Event) {
-        match event {
-            &Event::Resized { width, height } => {
-                self.view.set_size(Vector2::new(width, height).as_other())
-            }
-            Event::MouseWheelScrolled {
-                wheel,
-                delta,
-                x: _,
-                y: _,
-            } if *wheel == Wheel::VerticalWheel
-                && delta.is_sign_negative()
-                && self.view.size().x < VIEW_MAX_SIZE.x
-                && self.view.size().y < VIEW_MAX_SIZE.y =>
+    /// Expects mouse positions to still be in window coordinates.
+    ///
+    /// While a UI element has focus, the keyboard binds are left to the UI, so the arrow keys
+    /// move focus rather than panning
+    fn view_event_handler(&mut self, event: &Event, binds: &Bindings, is_ui_focused: bool) {
+        match *event {
+            Event::Resized { width, height } => self
+                .grid_view
+                .set_window_size(Vector2::new(width, height).as_other()),
+            // Zoom anchored at the mouse
+            Event::MouseWheelScrolled { wheel, delta, x, y }
+                if wheel == Wheel::VerticalWheel && delta != 0. =>
             {
-                self.view.zoom(1.1);
+                let factor = if delta.is_sign_positive() {
+                    ZOOM_STEP
+                } else {
+                    1. / ZOOM_STEP
+                };
+                self.grid_view.zoom_at(factor, Vector2::new(x, y));
+                self.ensure_color_grid_is_still_in_view();
             }
-            Event::MouseWheelScrolled {
-                wheel,
-                delta,
-                x: _,
-                y: _,
-            } if *wheel == Wheel::VerticalWheel
-                && delta.is_sign_positive()
-                && self.view.size().x > VIEW_MIN_SIZE.x
-                && self.view.size().y > VIEW_MIN_SIZE.y =>
-            {
-                self.view.zoom(0.9);
-            }
-            Event::KeyPressed { code, .. } if code == &Key::Space => {
-                self.view.set_center(Vector2f::new(
-                    (self.color_grid[0].len() / 2
-                        * usize::try_from(CELL_SIZE.x)
-                            .expect("Crashes immediately bad CELL_SIZE definition"))
-                        as f32,
-                    (self.color_grid[0].len() / 2
-                        * usize::try_from(CELL_SIZE.y)
-                            .expect("Crashes immediately bad CELL_SIZE definition"))
-                        as f32,
-                ))
+            // The keyboard binds below are left to the focused UI element
+            _ if is_ui_focused => {}
+            _ if binds.is_shortcut_pressed(*event, PossibleBinds::RecenterView) => {
+                self.grid_view.set_center(self.color_grid.world_center())
+            }
+            _ if binds.is_shortcut_pressed(*event, PossibleBinds::FitView) => {
+                self.fit_view_to_content();
+            }
+            // Zoom around the center of the view
+            _ if binds.is_shortcut_pressed(*event, PossibleBinds::ZoomIn) => {
+                self.grid_view.set_zoom(self.grid_view.zoom() * ZOOM_STEP)
+            }
+            _ if binds.is_shortcut_pressed(*event, PossibleBinds::ZoomOut) => {
+                self.grid_view.set_zoom(self.grid_view.zoom() / ZOOM_STEP)
+            }
+            _ => {
+                for (bind, direction) in KEYBOARD_PAN_BINDS {
+                    if binds.is_shortcut_pressed(*event, bind) {
+                        self.grid_view.pan(direction * KEYBOARD_PAN_STEP);
+                        self.ensure_color_grid_is_still_in_view();
+                    }
+                }
+                for (bind, zoom) in ZOOM_PRESET_BINDS {
+                    if binds.is_shortcut_pressed(*event, bind) {
+                        self.grid_view.set_zoom(zoom);
+                    }
+                }
             }
-            _ => {}
         }
     }
 
-    fn erase_event_handler(&mut self, event: &Event) {
+    /// Fits the view to every filled cell, or to the whole grid if it is empty
+    fn fit_view_to_content(&mut self) {
+        let bounds = self
+            .color_grid
+            .used_bounds()
+            .unwrap_or(FloatRect::from_vecs(
+                Vector2::new(0., 0.),
+                self.color_grid.world_size(),
+            ));
+        self.grid_view.fit_rect(bounds);
+    }
+
+    fn erase_event_handler(&mut self, event: &Event, binds: &Bindings) {
         match event {
             // Enable erase
-            &Event::KeyReleased {
-                code,
-                ctrl,
-                alt,
-                system,
-                ..
-            } if code == Key::E && !ctrl && !alt && !system => {
+            _ if binds.is_shortcut_released(*event, PossibleBinds::ToggleErase) => {
                 self.erase_mode.toggle_erase();
             }
             // Erase color
             &Event::MouseButtonPressed { button, x, y }
                 if self.erase_mode.is_erase_mode_enabled() && button == Button::Left =>
             {
-                if let Some(color_cell) = self.color_grid.coord_to_cell_mut(Vector2::new(x, y)) {
-                    color_cell.borrow_mut().empty_the_cell(&mut self.undo_redo);
+                if let Some(idx) = self.color_grid.coord_to_idx(Vector2::new(x, y)) {
+                    self.color_grid.empty_cell(idx, &mut self.undo_redo);
                 }
                 self.is_dragging_erase = true
             }
@@ -357,8 +501,8 @@ impl PalleteBuilder {
             &Event::MouseMoved { x, y }
                 if self.erase_mode.is_erase_mode_enabled() && self.is_dragging_erase =>
             {
-                if let Some(color_cell) = self.color_grid.coord_to_cell_mut(Vector2::new(x, y)) {
-                    color_cell.borrow_mut().empty_the_cell(&mut self.undo_redo);
+                if let Some(idx) = self.color_grid.coord_to_idx(Vector2::new(x, y)) {
+                    self.color_grid.empty_cell(idx, &mut self.undo_redo);
                 }
             }
 
@@ -367,8 +511,8 @@ impl PalleteBuilder {
                 if self.erase_mode.is_erase_mode_enabled() && self.is_dragging_erase =>
             {
                 self.is_dragging_erase = false;
-                if let Some(color_cell) = self.color_grid.coord_to_cell_mut(Vector2::new(x, y)) {
-                    color_cell.borrow_mut().empty_the_cell(&mut self.undo_redo);
+                if let Some(idx) = self.color_grid.coord_to_idx(Vector2::new(x, y)) {
+                    self.color_grid.empty_cell(idx, &mut self.undo_redo);
                 }
             }
 
@@ -376,41 +520,29 @@ impl PalleteBuilder {
         }
     }
 
+    /// The view can be moved anywhere over the grid or the reference image
     fn ensure_color_grid_is_still_in_view(&mut self) {
-        let center = self.view.center();
-        let max = Vector2::new(
-            self.color_grid[0].len() as f32 * CELL_SIZE.x as f32,
-            self.color_grid[0].len() as f32 * CELL_SIZE.y as f32,
-        );
-        if center.x < 0. {
-            self.view.set_center(Vector2::new(0., center.y));
-        }
-        if center.x > max.x {
-            self.view.set_center(Vector2::new(max.x, center.y));
-        }
-        let center = self.view.center();
-        if center.y < 0. {
-            self.view.set_center(Vector2::new(center.x, 0.));
-        }
-        if center.y > max.y {
-            self.view.set_center(Vector2::new(center.x, max.y));
+        let mut bounds = FloatRect::from_vecs(Vector2::new(0., 0.), self.color_grid.world_size());
+        if let Some(reference_image) = &self.reference_image {
+            bounds = union_rect(bounds, reference_image.world_bounds());
         }
+        self.grid_view.clamp_center(bounds);
     }
 
-    fn drag_screen_event_handler(&mut self, event: &Event) {
+    fn drag_screen_event_handler(&mut self, event: &Event, binds: &Bindings) {
         match *event {
             // Begin dragging the screen around
-            Event::MouseButtonPressed { button, x, y }
-                if (button == Button::Right || button == Button::Middle) =>
+            Event::MouseButtonPressed { x, y, .. }
+                if binds.is_shortcut_pressed(*event, PossibleBinds::PanDrag) =>
             {
                 self.is_dragging_screen = true;
                 self.previous_mouse_position = Vector2::new(x, y);
             }
 
-            // Make sure the middle or right button is still pressed. If not, stop dragging
+            // Make sure the drag button is still pressed. If not, stop dragging
             Event::MouseMoved { x: _, y: _ }
                 if self.is_dragging_screen
-                    && !(Button::Middle.is_pressed() || Button::Right.is_pressed()) =>
+                    && !binds.is_shortcut_input_held(PossibleBinds::PanDrag) =>
             {
                 self.is_dragging_screen = false;
             }
@@ -418,16 +550,16 @@ impl PalleteBuilder {
             Event::MouseMoved { x, y } if self.is_dragging_screen => {
                 let mouse_diff = Vector2::new(x, y) - self.previous_mouse_position;
                 self.previous_mouse_position = Vector2::new(x, y) - mouse_diff;
-                let center = self.view.center();
-                self.view.set_center(center - mouse_diff.as_other());
+                let center = self.grid_view.center();
+                self.grid_view.set_center(center - mouse_diff.as_other());
 
                 self.ensure_color_grid_is_still_in_view();
             }
 
             // Finished dragging the screen around
-            Event::MouseButtonReleased { button, x: _, y: _ }
+            Event::MouseButtonReleased { .. }
                 if self.is_dragging_screen
-                    && (button == Button::Right || button == Button::Middle) =>
+                    && binds.is_shortcut_input_released(*event, PossibleBinds::PanDrag) =>
             {
                 self.is_dragging_screen = false
             }
@@ -435,35 +567,113 @@ impl PalleteBuilder {
         }
     }
 
-    fn undo_redo_event_handler(&mut self, event: &Event) {
+    /// Drags the reference image around. Expects mouse positions in world coordinates.
+    ///
+    /// Returns true if the event was used
+    fn reference_drag_event_handler(&mut self, event: &Event, binds: &Bindings) -> bool {
+        let Some(reference_bounds) = self
+            .reference_image
+            .as_ref()
+            .map(ReferenceImage::world_bounds)
+        else {
+            return false;
+        };
+
+        match *event {
+            Event::MouseButtonPressed { x, y, .. }
+                if binds.is_shortcut_pressed(*event, PossibleBinds::MoveReference)
+                    && reference_bounds.contains(Vector2::new(x, y).as_other()) =>
+            {
+                self.dragging_reference_from = Some(Vector2::new(x, y).as_other());
+                true
+            }
+            Event::MouseMoved { x, y } => {
+                let Some(dragging_from) = self.dragging_reference_from else {
+                    return false;
+                };
+                if !binds.is_shortcut_input_held(PossibleBinds::MoveReference) {
+                    self.dragging_reference_from = None;
+                    return false;
+                }
+                let cursor_position: Vector2f = Vector2::new(x, y).as_other();
+                if let Some(reference_image) = &mut self.reference_image {
+                    reference_image.move_by(cursor_position - dragging_from);
+                }
+                self.dragging_reference_from = Some(cursor_position);
+                self.ensure_color_grid_is_still_in_view();
+                true
+            }
+            Event::MouseButtonReleased { .. } if self.dragging_reference_from.is_some() => {
+                self.dragging_reference_from = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Gives the recolor preview the cell to draw its selected color with. Expects mouse
+    /// positions in world coordinates.
+    ///
+    /// Returns true if the event was used
+    fn recolor_mapping_event_handler(&mut self, event: &Event) -> bool {
+        if !self.recolor_preview.is_choosing_cell() {
+            return false;
+        }
+
+        match *event {
+            Event::MouseButtonPressed {
+                button: Button::Left,
+                x,
+                y,
+            } => {
+                if let Some(idx) = self.color_grid.coord_to_idx(Vector2::new(x, y)) {
+                    self.recolor_preview.map_selected_color(idx);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn undo_redo_event_handler(&mut self, event: &Event, binds: &Bindings) {
+        let grid_size = self.color_grid.size();
         match *event {
             // Undo
-            Event::KeyPressed { code, ctrl, .. } if code == Key::Z && ctrl => {
+            _ if binds.is_shortcut_pressed(*event, PossibleBinds::Undo) => {
                 self.undo_redo.undo(&mut self.color_grid);
             }
 
             // Redo
-            Event::KeyPressed { code, ctrl, .. } if code == Key::R && ctrl => {
+            _ if binds.is_shortcut_pressed(*event, PossibleBinds::Redo) => {
                 self.undo_redo.redo(&mut self.color_grid);
             }
             _ => {}
         }
+        // Undoing a change made before the grid shrunk grows it back
+        if self.color_grid.size() != grid_size {
+            self.settings.set_grid_size(self.color_grid.size());
+        }
     }
 
-    fn general_mouse_button_event_handler(&mut self, event: &Event) -> bool {
+    fn general_mouse_button_event_handler(&mut self, event: &Event, binds: &Bindings) -> bool {
         match *event {
             // Eye dropper
-            Event::MouseButtonPressed { button, x, y }
-                if (Key::LControl.is_pressed() || Key::RControl.is_pressed())
-                    && button == Button::Left =>
+            Event::MouseButtonPressed { x, y, .. }
+                if binds.is_shortcut_pressed(*event, PossibleBinds::Eyedrop) =>
             {
-                if let Some(idx) = self.color_grid.coord_to_idx(Vector2::new(x, y)) {
-                    if self.color_grid[idx.x][idx.y].borrow().draw_full_cell() {
-                        let hsv = self.color_grid[idx.x][idx.y]
-                            .borrow()
-                            .full_cell_current_color();
-                        self.hsv_selector.set_hsv_color(hsv)
-                    }
+                // Filled cells are drawn over the reference image, so they are picked first
+                if let Some(hsv) = self
+                    .color_grid
+                    .coord_to_idx(Vector2::new(x, y))
+                    .and_then(|idx| self.color_grid.get(idx))
+                    .or_else(|| {
+                        self.reference_image
+                            .as_ref()?
+                            .color_at(Vector2::new(x, y).as_other())
+                            .map(Hsv::from)
+                    })
+                {
+                    self.hsv_selector.set_hsv_color(hsv)
                 }
 
                 true
@@ -472,30 +682,38 @@ impl PalleteBuilder {
         }
     }
 
-    fn general_other_key_event_handler(&mut self, event: &Event, ui_settings: &UISettings) {
+    fn general_other_key_event_handler(
+        &mut self,
+        event: &Event,
+        ui_settings: &UISettings,
+        ui_state: &mut UIState,
+    ) {
+        let binds = &ui_settings.binds;
         match *event {
             // Quick Save
-            Event::KeyPressed { code, ctrl, .. } if code == Key::S && ctrl => {
+            _ if binds.is_shortcut_pressed(*event, PossibleBinds::QuickSave) => {
                 if !self.settings.save_file().is_empty() {
-                    if let Err(err) = save_color_grid(
-                        &self.color_grid,
-                        &self.config_selector.current_config(),
-                        self.settings.save_file(),
-                    ) {
-                        error!(err);
-                    }
+                    self.save_active_tab();
                 } else {
-                    self.settings.open_save_menu(ui_settings)
+                    self.settings.open_save_menu(ui_settings, ui_state)
                 }
             }
-            // Enable erase
-            Event::KeyReleased {
-                code,
-                ctrl,
-                alt,
-                system,
-                ..
-            } if code == Key::A && !ctrl && !alt && !system => {
+            // Cycle through tabs
+            _ if binds.is_shortcut_pressed(*event, PossibleBinds::NextTab) => {
+                self.switch_to_tab((self.active_tab + 1) % self.tabs.len());
+            }
+            _ if binds.is_shortcut_pressed(*event, PossibleBinds::PreviousTab) => {
+                self.switch_to_tab(
+                    self.active_tab
+                        .checked_sub(1)
+                        .unwrap_or(self.tabs.len() - 1),
+                );
+            }
+            _ if binds.is_shortcut_pressed(*event, PossibleBinds::ToggleRecolorPreview) => {
+                self.recolor_preview.toggle_display();
+            }
+            // Enable auto ramping
+            _ if binds.is_shortcut_released(*event, PossibleBinds::ToggleAutoRamp) => {
                 self.config_selector.toggle_auto_ramping();
             }
             _ => {}
@@ -520,41 +738,198 @@ macro_rules! generate_ramp_mode_event_handler_arguments {
 
 // General utility
 impl PalleteBuilder {
+    /// Files are opened in a new tab, unless the active tab is untouched or there is no
+    /// room left for another tab. A file that is already open is switched to instead, the
+    /// active one is only reloaded when it has no unsaved changes.
     fn check_settings_and_load_file_if_necessary(&mut self) {
-        let mut exterior_file_to_load = None;
-        if let Some(file_to_load) = self.settings.file_to_load() {
-            exterior_file_to_load = Some(file_to_load.to_string());
-            let ramp_config = &mut Default::default();
-            if let Err(err) = load_color_grid(
-                &mut self.color_grid,
-                ramp_config,
-                file_to_load,
-                &mut self.undo_redo,
-            ) {
-                error!("{:#?}", err);
-            }
+        let Some(file_to_load) = self.settings.file_to_load().map(str::to_string) else {
+            return;
+        };
+        self.settings.clear_file_to_load();
+
+        if let Some(tab) = self
+            .tabs
+            .iter()
+            .enumerate()
+            .position(|(idx, tab)| idx != self.active_tab && tab.save_file == file_to_load)
+        {
+            self.switch_to_tab(tab);
+            return;
+        }
+        let is_reload = self.settings.save_file() == file_to_load;
+        if is_reload && self.active_tab_has_unsaved_changes() {
+            warn!(
+                "Not reloading {}, the active tab has unsaved changes to it",
+                file_to_load
+            );
+            return;
+        }
 
-            self.config_selector.set_config(*ramp_config);
+        // Loaded aside, so a file that fails to load leaves every tab as it was
+        let mut tab = PalleteTab {
+            save_file: file_to_load.clone(),
+            ..Default::default()
+        };
+        let mut reference_image = None;
+        if let Err(err) = load_color_grid(
+            &mut tab.color_grid,
+            &mut tab.ramp_config,
+            &mut tab.view_state,
+            &mut reference_image,
+            &mut tab.notes,
+            &file_to_load,
+            &mut tab.undo_redo,
+        ) {
+            error!("{:#?}", err);
+            return;
         }
+        tab.reference_image = reference_image.and_then(load_reference_image);
 
-        if let Some(file_to_load) = exterior_file_to_load {
-            self.settings.clear_file_to_load();
-            self.settings.set_save_file(&file_to_load)
+        let state = self.active_tab_state();
+        if is_reload || self.tabs[self.active_tab].is_untouched(&self.color_grid, &state) {
+            self.replace_active_tab(tab);
+        } else if self.tabs.len() < MAX_TABS {
+            self.open_tab(tab);
+        } else if !self.active_tab_has_unsaved_changes() {
+            // Without room for a new tab, only a saved tab is replaced
+            self.replace_active_tab(tab);
+        } else {
+            warn!(
+                "Not loading {}, every tab is in use and the active one has unsaved changes",
+                file_to_load
+            );
         }
     }
 
-    fn check_settings_and_save_file_if_necessary(&mut self) {
-        if !self.settings.trigger_save_event() || self.settings.save_file().is_empty() {
+    fn check_reference_panel_and_update_reference_if_necessary(&mut self) {
+        if let Some(file_name) = self.reference_panel.take_file_to_load() {
+            let state = match &self.reference_image {
+                // Swapping the png keeps it where it was
+                Some(reference_image) => ReferenceImageState {
+                    file_name,
+                    ..reference_image.state().clone()
+                },
+                None => {
+                    ReferenceImageState::beside_color_grid(&file_name, self.color_grid.world_size())
+                }
+            };
+            self.set_reference_image(Some(state));
+        }
+        if self.reference_panel.take_clear_request() {
+            self.set_reference_image(None);
+        }
+
+        let Some(reference_image) = &mut self.reference_image else {
             return;
+        };
+        if let Some(opacity) = self.reference_panel.take_opacity() {
+            reference_image.set_opacity(opacity);
+        }
+        if let Some(scale) = self.reference_panel.take_scale() {
+            reference_image.set_scale(scale);
+        }
+    }
+
+    /// A reference image that fails to load is dropped
+    fn set_reference_image(&mut self, state: Option<ReferenceImageState>) {
+        self.dragging_reference_from = None;
+        self.reference_image = state.and_then(load_reference_image);
+        self.reference_panel
+            .set_reference(self.reference_image.as_ref().map(ReferenceImage::state));
+    }
+
+    fn check_screen_eyedropper_and_set_color_if_necessary(&mut self) {
+        if let Some(color) = self.screen_eyedropper.take_sampled_color() {
+            self.hsv_selector.set_hsv_color(Hsv::from(color));
+        }
+    }
+
+    fn check_color_swatches_and_pick_color_if_necessary(&mut self) {
+        if let Some(hsv) = self.color_swatches.take_color_to_pick() {
+            self.hsv_selector.set_hsv_color(hsv);
+        }
+        if let Some(hsv) = self.hsv_selector.take_settled_color() {
+            self.color_swatches.push_recent_color(hsv);
+        }
+        self.color_swatches
+            .set_current_color(self.hsv_selector.curr_color());
+    }
+
+    fn check_tab_strip_and_update_tabs_if_necessary(&mut self) {
+        if let Some(tab) = self.tab_strip.take_tab_to_select() {
+            self.switch_to_tab(tab);
+        }
+        if self.tab_strip.take_new_tab_request() {
+            self.new_tab();
+        }
+        if self
+            .tab_strip
+            .take_close_request(self.active_tab_has_unsaved_changes())
+        {
+            self.close_active_tab();
         }
 
+        let titles = self
+            .tabs
+            .iter()
+            .enumerate()
+            .map(|(idx, tab)| {
+                if idx == self.active_tab {
+                    tab_title(
+                        self.settings.save_file(),
+                        self.active_tab_has_unsaved_changes(),
+                    )
+                } else {
+                    tab_title(
+                        &tab.save_file,
+                        tab.has_unsaved_changes(&tab.stashed_state()),
+                    )
+                }
+            })
+            .collect();
+        self.tab_strip.set_tabs(titles, self.active_tab);
+    }
+
+    fn save_active_tab(&mut self) {
         if let Err(err) = save_color_grid(
             &self.color_grid,
             &self.config_selector.current_config(),
+            &self.grid_view.state(),
+            self.reference_image.as_ref().map(ReferenceImage::state),
+            self.settings.notes(),
             self.settings.save_file(),
         ) {
             error!(err);
+            return;
         }
+        self.mark_active_tab_saved();
+    }
+
+    fn check_minimap_and_move_view_if_necessary(&mut self) {
+        if let Some(view_center) = self.minimap.take_view_center() {
+            self.grid_view.set_center(view_center);
+            self.ensure_color_grid_is_still_in_view();
+        }
+    }
+
+    fn check_settings_and_resize_grid_if_necessary(&mut self) {
+        let Some(grid_size) = self.settings.grid_size_to_apply() else {
+            return;
+        };
+
+        self.color_grid.resize(grid_size);
+        self.settings.clear_grid_size_to_apply();
+        // The grid may refuse to shrink past filled cells, so show the size that was applied
+        self.settings.set_grid_size(self.color_grid.size());
+        self.ensure_color_grid_is_still_in_view();
+    }
+
+    fn check_settings_and_save_file_if_necessary(&mut self) {
+        if !self.settings.trigger_save_event() || self.settings.save_file().is_empty() {
+            return;
+        }
+
+        self.save_active_tab();
         self.settings.untrigger_save_event();
     }
 
@@ -576,3 +951,140 @@ impl PalleteBuilder {
         self.settings.untrigger_export_event();
     }
 }
+
+// Tabs
+impl PalleteBuilder {
+    fn active_tab_has_unsaved_changes(&self) -> bool {
+        self.tabs[self.active_tab].has_unsaved_changes(&self.active_tab_state())
+    }
+
+    /// The state of the active tab, which is unpacked into the builder while being edited
+    fn active_tab_state(&self) -> SavedState {
+        SavedState {
+            grid_revision: self.color_grid.revision(),
+            ramp_config: self.config_selector.current_config(),
+            view_state: Some(self.grid_view.state()),
+            reference_image: self
+                .reference_image
+                .as_ref()
+                .map(|reference_image| reference_image.state().clone()),
+            notes: self.settings.notes().to_string(),
+        }
+    }
+
+    fn mark_active_tab_saved(&mut self) {
+        self.tabs[self.active_tab].saved_state = self.active_tab_state();
+    }
+
+    fn switch_to_tab(&mut self, tab: usize) {
+        if tab == self.active_tab || tab >= self.tabs.len() {
+            return;
+        }
+
+        self.reset_mode();
+        self.stash_active_tab();
+        self.unstash_tab(tab);
+    }
+
+    /// Returns whether a tab was added, there are at most [`MAX_TABS`]
+    fn new_tab(&mut self) -> bool {
+        if self.tabs.len() >= MAX_TABS {
+            return false;
+        }
+
+        self.open_tab(Default::default());
+        true
+    }
+
+    /// Adds `tab` after the others and switches to it, expects there to be room for it
+    fn open_tab(&mut self, tab: PalleteTab) {
+        self.reset_mode();
+        self.stash_active_tab();
+        self.tabs.push(tab);
+        self.unstash_tab(self.tabs.len() - 1);
+        self.mark_active_tab_saved();
+    }
+
+    /// Replaces the active tab with `tab`, dropping whatever was in it
+    fn replace_active_tab(&mut self, tab: PalleteTab) {
+        self.reset_mode();
+        self.tabs[self.active_tab] = tab;
+        self.unstash_tab(self.active_tab);
+        self.mark_active_tab_saved();
+    }
+
+    /// The last tab is never closed, it is replaced by an empty one instead
+    fn close_active_tab(&mut self) {
+        if self.tabs.len() == 1 {
+            self.replace_active_tab(Default::default());
+            return;
+        }
+
+        self.reset_mode();
+        self.tabs.remove(self.active_tab);
+        self.unstash_tab(self.active_tab.min(self.tabs.len() - 1));
+    }
+
+    /// Stops any ramp in progress, so it is not left behind in the grid of another tab
+    fn reset_mode(&mut self) {
+        if let Mode::RampMode(ramp_mode) = &mut self.current_mode {
+            self.confirm_color_ramp.set_enable(false);
+            ramp_mode.clear_the_ramp(&mut self.color_grid, &mut self.undo_redo);
+        }
+        self.current_mode = Mode::NormalMode(Default::default());
+        self.is_dragging_erase = false;
+    }
+
+    fn stash_active_tab(&mut self) {
+        let tab = &mut self.tabs[self.active_tab];
+        tab.color_grid = mem::take(&mut self.color_grid);
+        tab.undo_redo = mem::take(&mut self.undo_redo);
+        tab.ramp_config = self.config_selector.current_config();
+        tab.view_state = Some(self.grid_view.state());
+        tab.reference_image = self.reference_image.take();
+        tab.save_file = self.settings.save_file().to_string();
+        tab.notes = self.settings.notes().to_string();
+    }
+
+    fn unstash_tab(&mut self, tab: usize) {
+        self.active_tab = tab;
+        let tab = &mut self.tabs[tab];
+        self.color_grid = mem::take(&mut tab.color_grid);
+        self.undo_redo = mem::take(&mut tab.undo_redo);
+        self.reference_image = tab.reference_image.take();
+        self.dragging_reference_from = None;
+        self.reference_panel
+            .set_reference(self.reference_image.as_ref().map(ReferenceImage::state));
+        self.config_selector.set_config(tab.ramp_config);
+        self.settings.set_save_file(&tab.save_file);
+        self.settings.set_notes(&tab.notes);
+        self.settings.set_grid_size(self.color_grid.size());
+
+        if let Some(view_state) = tab.view_state {
+            self.grid_view.set_state(view_state);
+            self.ensure_color_grid_is_still_in_view();
+        } else {
+            self.fit_view_to_content();
+        }
+    }
+}
+
+/// Logs and drops a reference image that fails to load
+fn load_reference_image(state: ReferenceImageState) -> Option<ReferenceImage> {
+    match ReferenceImage::load(state) {
+        Ok(reference_image) => Some(reference_image),
+        Err(err) => {
+            error!("Failed to load reference image! {:#?}", err);
+            None
+        }
+    }
+}
+
+/// Smallest rect that contains both `a` and `b`
+fn union_rect(a: FloatRect, b: FloatRect) -> FloatRect {
+    let left = a.left.min(b.left);
+    let top = a.top.min(b.top);
+    let right = (a.left + a.width).max(b.left + b.width);
+    let bottom = (a.top + a.height).max(b.top + b.height);
+    FloatRect::new(left, top, right - left, bottom - top)
+}
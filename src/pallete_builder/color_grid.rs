@@ -1,88 +1,380 @@
 use std::{
-    array,
-    cell::RefCell,
-    ops::{Index, IndexMut},
-    rc::Rc,
+    collections::BTreeMap,
+    sync::atomic::{AtomicU64, Ordering},
 };
 
-use self::color_cell::{ColorCell, RcColorCell};
 use sfml::{
-    graphics::RenderWindow,
-    system::{Vector2, Vector2i},
+    graphics::{Color, FloatRect},
+    system::{Vector2, Vector2f, Vector2i, Vector2u},
 };
 
-pub mod color_cell;
+use self::undo_redo::UndoRedoCell;
+
+use super::hsv_color::Hsv;
+
 pub mod load_save;
+pub mod renderer;
 pub mod undo_redo;
 
-pub const GRID_SIZE: usize = 64;
-pub struct ColorGrid(Vec<[RcColorCell; GRID_SIZE]>);
+pub const CELL_SIZE: Vector2u = Vector2::new(32, 32);
+
+/// Size of a brand new grid, and of any file saved before grid sizes were stored.
+pub const DEFAULT_GRID_SIZE: Vector2<usize> = Vector2::new(64, 64);
+/// Only filled cells are stored, but exporting allocates every pixel of the grid.
+pub const MAX_GRID_SIZE: Vector2<usize> = Vector2::new(1024, 1024);
+pub const MIN_GRID_SIZE: Vector2<usize> = Vector2::new(1, 1);
+
+/// Shared between every grid, so two grids never report the same revision.
+static NEXT_REVISION: AtomicU64 = AtomicU64::new(0);
+
+fn next_revision() -> u64 {
+    NEXT_REVISION.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Sparse palette data model. Maps a cell index to the color inside of it.
+///
+/// Cells that are not in the map are empty. Rendering is handled by
+/// [`ColorGridRenderer`](renderer::ColorGridRenderer).
+#[derive(Clone, Debug)]
+pub struct ColorGrid {
+    size: Vector2<usize>,
+    cells: BTreeMap<(usize, usize), Hsv>,
+    revision: u64,
+}
 
 impl ColorGrid {
-    pub fn new() -> Self {
-        Self(
-            (0..GRID_SIZE)
-                .map(|i| {
-                    array::from_fn(|j| {
-                        Rc::new(RefCell::new(ColorCell::new(Vector2::new(i, j).as_other())))
-                    })
-                })
-                .collect(),
+    pub fn new(size: Vector2<usize>) -> Self {
+        let mut color_grid = Self {
+            size: MIN_GRID_SIZE,
+            cells: Default::default(),
+            revision: next_revision(),
+        };
+        color_grid.resize(size);
+
+        color_grid
+    }
+
+    pub fn size(&self) -> Vector2<usize> {
+        self.size
+    }
+
+    /// Size in world coordinates
+    pub fn world_size(&self) -> Vector2f {
+        Vector2::new(
+            self.size.x as f32 * CELL_SIZE.x as f32,
+            self.size.y as f32 * CELL_SIZE.y as f32,
         )
     }
 
-    pub fn coord_to_cell_mut(&mut self, coord: Vector2i) -> Option<RcColorCell> {
-        let index = self.coord_to_idx(coord)?;
-        Some(self.0.get_mut(index.x)?.get_mut(index.y)?.clone())
+    pub fn world_center(&self) -> Vector2f {
+        self.world_size() / 2.
+    }
+
+    /// Changes every time a cell or the size of the grid changes.
+    ///
+    /// Revisions are unique across every grid, so caches keyed on them stay valid when
+    /// switching between grids.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Smallest grid size that still holds every filled cell
+    pub fn used_size(&self) -> Vector2<usize> {
+        self.cells
+            .keys()
+            .fold(Vector2::new(0, 0), |used_size, &(x, y)| {
+                Vector2::new(used_size.x.max(x + 1), used_size.y.max(y + 1))
+            })
     }
 
+    /// World bounds of every filled cell. None if the grid is empty
+    pub fn used_bounds(&self) -> Option<FloatRect> {
+        let mut idxs = self.cells.keys();
+        let &(x, y) = idxs.next()?;
+        let (min, max) = idxs.fold(
+            (Vector2::new(x, y), Vector2::new(x, y)),
+            |(min, max), &(x, y)| {
+                (
+                    Vector2::new(min.x.min(x), min.y.min(y)),
+                    Vector2::new(max.x.max(x), max.y.max(y)),
+                )
+            },
+        );
+
+        let position = Self::idx_to_coord(min);
+        Some(FloatRect::from_vecs(
+            position,
+            Self::idx_to_coord(max + Vector2::new(1, 1)) - position,
+        ))
+    }
+
+    /// Resizes the grid, keeping every existing cell.
+    ///
+    /// The new size is clamped between [`MIN_GRID_SIZE`] and [`MAX_GRID_SIZE`], and never
+    /// shrinks past the [`used_size`](Self::used_size), so no colors are lost.
+    pub fn resize(&mut self, new_size: Vector2<usize>) {
+        let used_size = self.used_size();
+        self.size = Vector2::new(
+            new_size
+                .x
+                .max(used_size.x)
+                .clamp(MIN_GRID_SIZE.x, MAX_GRID_SIZE.x),
+            new_size
+                .y
+                .max(used_size.y)
+                .clamp(MIN_GRID_SIZE.y, MAX_GRID_SIZE.y),
+        );
+        self.revision = next_revision();
+    }
+
+    /// Only ever grows the grid so that it contains `idx`.
+    pub fn grow_to_fit_idx(&mut self, idx: Vector2<usize>) {
+        if self.is_idx_valid(idx) {
+            return;
+        }
+
+        self.resize(Vector2::new(
+            self.size.x.max(idx.x.saturating_add(1)),
+            self.size.y.max(idx.y.saturating_add(1)),
+        ));
+    }
+
+    /// Converts a world coordinate into a cell index. None if it lands outside of the grid.
     pub fn coord_to_idx(&self, coord: Vector2i) -> Option<Vector2<usize>> {
-        let top_left_cell_position = self[0][0].borrow().global_bounds().position();
-        let cell_size = self[0][0].borrow().global_bounds().size();
+        let idx = Vector2::new(
+            usize::try_from(coord.x).ok()? / usize::try_from(CELL_SIZE.x).ok()?,
+            usize::try_from(coord.y).ok()? / usize::try_from(CELL_SIZE.y).ok()?,
+        );
 
-        let offset_mouse_pos = coord - top_left_cell_position;
+        self.is_idx_valid(idx).then_some(idx)
+    }
 
-        (offset_mouse_pos.cwise_div(cell_size))
-            .try_into_other()
-            .ok()
+    /// World coordinate of the top left corner of a cell
+    pub fn idx_to_coord(idx: Vector2<usize>) -> Vector2f {
+        Vector2::new(
+            idx.x as f32 * CELL_SIZE.x as f32,
+            idx.y as f32 * CELL_SIZE.y as f32,
+        )
     }
 
     pub fn is_idx_valid(&self, idx: Vector2<usize>) -> bool {
-        fn is_idx_vald_option(self_: &ColorGrid, idx: Vector2<usize>) -> Option<()> {
-            self_.0.get(idx.x)?.get(idx.y)?;
+        idx.x < self.size.x && idx.y < self.size.y
+    }
+
+    /// Returns the color of a cell. None if the cell is empty
+    pub fn get(&self, idx: Vector2<usize>) -> Option<Hsv> {
+        self.cells.get(&(idx.x, idx.y)).copied()
+    }
+
+    pub fn is_cell_filled(&self, idx: Vector2<usize>) -> bool {
+        self.cells.contains_key(&(idx.x, idx.y))
+    }
+
+    /// Iterates every filled cell, column by column
+    pub fn iter(&self) -> impl Iterator<Item = (Vector2<usize>, Hsv)> + '_ {
+        self.cells
+            .iter()
+            .map(|(&(x, y), &hsv)| (Vector2::new(x, y), hsv))
+    }
 
-            Some(())
+    /// Every cell as row by row RGBA pixels. Empty cells are transparent
+    pub fn to_rgba_pixels(&self) -> Vec<u8> {
+        let mut pixels = vec![0; self.size.x * self.size.y * 4];
+        for (idx, hsv) in self.iter() {
+            let color = Color::from(hsv);
+            let pixel_idx = (idx.y * self.size.x + idx.x) * 4;
+            pixels[pixel_idx..pixel_idx + 4].copy_from_slice(&[color.r, color.g, color.b, color.a]);
         }
 
-        is_idx_vald_option(self, idx).is_some()
+        pixels
+    }
+
+    pub fn fill_cell(&mut self, idx: Vector2<usize>, new_color: Hsv, undo_redo: &mut UndoRedoCell) {
+        self.change_cell(idx, Some(new_color), undo_redo)
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = &RcColorCell> {
-        self.0.iter().flatten()
+    pub fn empty_cell(&mut self, idx: Vector2<usize>, undo_redo: &mut UndoRedoCell) {
+        self.change_cell(idx, None, undo_redo)
     }
 
-    pub fn update(&mut self) {
-        for cell in self.iter() {
-            cell.borrow_mut().update();
+    /// Empties every cell in the grid
+    pub fn clear(&mut self, undo_redo: &mut UndoRedoCell) {
+        let filled_cells: Vec<_> = self.iter().map(|(idx, _)| idx).collect();
+        for idx in filled_cells {
+            self.empty_cell(idx, undo_redo);
         }
     }
 
-    pub fn render(&self, window: &mut RenderWindow) {
-        for cell in self.iter() {
-            cell.borrow().render(window);
+    fn change_cell(
+        &mut self,
+        idx: Vector2<usize>,
+        new_color: Option<Hsv>,
+        undo_redo: &mut UndoRedoCell,
+    ) {
+        if !self.is_idx_valid(idx) {
+            return;
         }
+        let previous_color = self.get(idx);
+        self.set_cell(idx, new_color);
+        undo_redo.change_made(idx, previous_color, new_color);
+    }
+
+    /// Changes a cell without recording it in the undo history.
+    ///
+    /// Resizes are not undoable, so a cell brought back by undo or redo may be outside of a
+    /// grid that shrunk since. The grid grows back to hold it.
+    fn set_cell(&mut self, idx: Vector2<usize>, new_color: Option<Hsv>) {
+        match new_color {
+            Some(new_color) => {
+                self.grow_to_fit_idx(idx);
+                self.cells.insert((idx.x, idx.y), new_color)
+            }
+            None => self.cells.remove(&(idx.x, idx.y)),
+        };
+        self.revision = next_revision();
     }
 }
 
-impl Index<usize> for ColorGrid {
-    type Output = [RcColorCell; GRID_SIZE];
-    fn index(&self, idx: usize) -> &Self::Output {
-        &self.0[idx]
+impl Default for ColorGrid {
+    fn default() -> Self {
+        Self::new(DEFAULT_GRID_SIZE)
     }
 }
 
-impl IndexMut<usize> for ColorGrid {
-    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
-        &mut self.0[idx]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fill_and_empty_cell() {
+        let mut color_grid = ColorGrid::default();
+        let undo_redo = &mut UndoRedoCell::default();
+        let hsv = Hsv::new(120, 50, 200);
+
+        color_grid.fill_cell(Vector2::new(3, 4), hsv, undo_redo);
+        assert_eq!(color_grid.get(Vector2::new(3, 4)), Some(hsv));
+        assert_eq!(color_grid.iter().count(), 1);
+
+        color_grid.empty_cell(Vector2::new(3, 4), undo_redo);
+        assert_eq!(color_grid.get(Vector2::new(3, 4)), None);
+        assert_eq!(color_grid.iter().count(), 0);
+
+        // Out of bounds cells are ignored
+        color_grid.fill_cell(DEFAULT_GRID_SIZE, hsv, undo_redo);
+        assert_eq!(color_grid.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_resize_keeps_cells() {
+        let mut color_grid = ColorGrid::default();
+        let undo_redo = &mut UndoRedoCell::default();
+        color_grid.fill_cell(Vector2::new(10, 20), Default::default(), undo_redo);
+
+        color_grid.resize(Vector2::new(4, 4));
+        assert_eq!(color_grid.size(), Vector2::new(11, 21));
+        assert!(color_grid.is_cell_filled(Vector2::new(10, 20)));
+
+        color_grid.resize(Vector2::new(100, 30));
+        assert_eq!(color_grid.size(), Vector2::new(100, 30));
+        assert!(color_grid.is_cell_filled(Vector2::new(10, 20)));
+
+        color_grid.resize(Vector2::new(usize::MAX, 0));
+        assert_eq!(color_grid.size(), Vector2::new(MAX_GRID_SIZE.x, 21));
+    }
+
+    #[test]
+    fn test_undo_after_shrinking_grows_the_grid_back() {
+        let mut color_grid = ColorGrid::default();
+        let undo_redo = &mut UndoRedoCell::default();
+        let idx = Vector2::new(50, 50);
+        color_grid.fill_cell(idx, Hsv::new(0, 255, 255), undo_redo);
+        color_grid.empty_cell(idx, undo_redo);
+        color_grid.resize(Vector2::new(10, 10));
+        assert_eq!(color_grid.size(), Vector2::new(10, 10));
+
+        undo_redo.undo(&mut color_grid);
+        assert!(color_grid.is_cell_filled(idx));
+        assert!(color_grid.is_idx_valid(idx));
+        assert_eq!(color_grid.size(), Vector2::new(51, 51));
+        assert_eq!(color_grid.to_rgba_pixels().len(), 51 * 51 * 4);
+    }
+
+    #[test]
+    fn test_used_bounds() {
+        let mut color_grid = ColorGrid::default();
+        let undo_redo = &mut UndoRedoCell::default();
+        assert_eq!(color_grid.used_bounds(), None);
+
+        color_grid.fill_cell(Vector2::new(2, 5), Default::default(), undo_redo);
+        color_grid.fill_cell(Vector2::new(4, 3), Default::default(), undo_redo);
+        let cell_size: Vector2f = CELL_SIZE.as_other();
+        assert_eq!(
+            color_grid.used_bounds(),
+            Some(FloatRect::new(
+                cell_size.x * 2.,
+                cell_size.y * 3.,
+                cell_size.x * 3.,
+                cell_size.y * 3.,
+            ))
+        );
+    }
+
+    #[test]
+    fn test_to_rgba_pixels() {
+        let mut color_grid = ColorGrid::new(Vector2::new(3, 2));
+        let undo_redo = &mut UndoRedoCell::default();
+        let hsv = Hsv::new(0, 255, 255);
+        color_grid.fill_cell(Vector2::new(1, 1), hsv, undo_redo);
+
+        let pixels = color_grid.to_rgba_pixels();
+        let color = Color::from(hsv);
+        assert_eq!(pixels.len(), 3 * 2 * 4);
+        assert_eq!(pixels[16..20], [color.r, color.g, color.b, color.a]);
+        assert_eq!(pixels.iter().filter(|&&channel| channel != 0).count(), 2);
+    }
+
+    #[test]
+    fn test_grow_to_fit_idx() {
+        let mut color_grid = ColorGrid::new(Vector2::new(2, 2));
+        color_grid.grow_to_fit_idx(Vector2::new(1, 1));
+        assert_eq!(color_grid.size(), Vector2::new(2, 2));
+
+        color_grid.grow_to_fit_idx(Vector2::new(5, 0));
+        assert_eq!(color_grid.size(), Vector2::new(6, 2));
+    }
+
+    #[test]
+    fn test_coord_to_idx() {
+        let color_grid = ColorGrid::new(Vector2::new(2, 2));
+        let cell_size: Vector2i = CELL_SIZE.as_other();
+
+        assert_eq!(
+            color_grid.coord_to_idx(Vector2::new(0, 0)),
+            Some(Vector2::new(0, 0))
+        );
+        assert_eq!(
+            color_grid.coord_to_idx(cell_size + Vector2::new(1, 1)),
+            Some(Vector2::new(1, 1))
+        );
+        assert_eq!(color_grid.coord_to_idx(Vector2::new(-1, 0)), None);
+        assert_eq!(color_grid.coord_to_idx(cell_size * 2), None);
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut color_grid = ColorGrid::default();
+        let undo_redo = &mut UndoRedoCell::default();
+        let first_hsv = Hsv::new(0, 255, 255);
+        let second_hsv = Hsv::new(200, 255, 255);
+        let idx = Vector2::new(1, 1);
+
+        color_grid.fill_cell(idx, first_hsv, undo_redo);
+        color_grid.fill_cell(idx, second_hsv, undo_redo);
+
+        undo_redo.undo(&mut color_grid);
+        assert_eq!(color_grid.get(idx), Some(first_hsv));
+
+        undo_redo.redo(&mut color_grid);
+        assert_eq!(color_grid.get(idx), Some(second_hsv));
     }
 }
@@ -6,15 +6,19 @@ use std::{
 };
 
 use sfml::{
-    graphics::{Color, Image},
+    graphics::{Color, Image, Texture},
     system::Vector2,
+    SfBox,
 };
 use tracing::error;
 use utils::{simple_error::SimpleError, string_util_functions::get_tuple_list_from_string};
 
-use crate::pallete_builder::{color_grid::GRID_SIZE, ui_components::config_selector::Config};
+use crate::pallete_builder::{
+    grid_view::ViewState, reference_image::ReferenceImageState,
+    ui_components::config_selector::Config,
+};
 
-use super::{undo_redo::UndoRedoCell, ColorGrid};
+use super::{undo_redo::UndoRedoCell, ColorGrid, DEFAULT_GRID_SIZE, MAX_GRID_SIZE};
 
 const FILE_DIR: &str = "files";
 
@@ -73,8 +77,22 @@ pub fn remove_pacrb_file(file_name: &str) {
 /// Then the file will have a line that has:
 /// *************************
 /// h:-1,s:1,v:2,n:4
+/// width:64,height:64
+/// center_x:1024,center_y:1024,zoom:1.5
+/// reference_x:2080,reference_y:0,reference_scale:32,reference_opacity:0.5
+/// reference_file:sprite.png
+/// note:Shaded from the top left
 ///
-/// The last line describes the color ramp configuration used.
+/// The line after the partition describes the color ramp configuration used.
+/// The next line describes the dimensions of the color grid. Files without it are
+/// loaded with the [`DEFAULT_GRID_SIZE`], grown to fit every cell.
+/// The next line describes where the view was left off. Files without it are
+/// opened fitted to their cells.
+/// The next two lines are only there if the pallete has a reference image. They describe
+/// where it is, and the png it was loaded from. The file name is last so it can contain
+/// commas and colons.
+/// The notes of the pallete come last, one line of them per line. Files without them are
+/// loaded without notes.
 ///
 /// if x/y comboniation does not have an rgb value, mark it empty
 /// my_file.pacrb:
@@ -82,32 +100,63 @@ pub fn remove_pacrb_file(file_name: &str) {
 /// 2 (26,25):(250,250,250)
 /// 3 **************************
 /// 4 h:-1,s:0,v:8,n:20
+/// 5 width:64,height:48
+/// 6 center_x:832,center_y:800,zoom:2
+/// 7 reference_x:2080,reference_y:0,reference_scale:32,reference_opacity:0.5
+/// 8 reference_file:sprite.png
+/// 9 note:Shaded from the top left
 ////////////////////////////////////////////////////////////////////////////////////////////
 
 const SECTION_PARTITION_STRING: &str = "*******************";
+const REFERENCE_FILE_PREFIX: &str = "reference_file:";
+const NOTE_PREFIX: &str = "note:";
 
 pub fn save_color_grid(
     color_grid: &ColorGrid,
     ramp_config: &Config,
+    view_state: &ViewState,
+    reference_image: Option<&ReferenceImageState>,
+    notes: &str,
     file_name: &str,
 ) -> Result<(), Box<dyn Error>> {
     ensure_folder_exists()?;
 
     let mut data = String::new();
-    for x in 0..color_grid.0.len() {
-        for y in 0..color_grid.0[x].len() {
-            if color_grid[x][y].borrow().draw_full_cell {
-                let color: Color = color_grid[x][y].borrow().full_cell_current_color().into();
-                let (r, g, b) = (color.r, color.g, color.b);
-                data.push_str(&format!("({x},{y}):({r},{g},{b})\n"));
-            }
-        }
+    for (idx, hsv) in color_grid.iter() {
+        let color: Color = hsv.into();
+        let (x, y, r, g, b) = (idx.x, idx.y, color.r, color.g, color.b);
+        data.push_str(&format!("({x},{y}):({r},{g},{b})\n"));
     }
     data.push_str(&format!("{SECTION_PARTITION_STRING}\n"));
     data.push_str(&format!(
         "h:{},s:{},v:{}\n",
         ramp_config.hue_shift, ramp_config.saturation_shift, ramp_config.value_shift,
     ));
+    data.push_str(&format!(
+        "width:{},height:{}\n",
+        color_grid.size().x,
+        color_grid.size().y
+    ));
+    data.push_str(&format!(
+        "center_x:{},center_y:{},zoom:{}\n",
+        view_state.center.x, view_state.center.y, view_state.zoom
+    ));
+    if let Some(reference_image) = reference_image {
+        data.push_str(&format!(
+            "reference_x:{},reference_y:{},reference_scale:{},reference_opacity:{}\n",
+            reference_image.position.x,
+            reference_image.position.y,
+            reference_image.scale,
+            reference_image.opacity
+        ));
+        data.push_str(&format!(
+            "{REFERENCE_FILE_PREFIX}{}\n",
+            reference_image.file_name
+        ));
+    }
+    for line in notes.lines() {
+        data.push_str(&format!("{NOTE_PREFIX}{line}\n"));
+    }
 
     Ok(fs::write(format!("{}/{}", FILE_DIR, file_name), data)?)
 }
@@ -115,32 +164,53 @@ pub fn save_color_grid(
 pub fn load_color_grid(
     color_grid: &mut ColorGrid,
     ramp_config: &mut Config,
+    view_state: &mut Option<ViewState>,
+    reference_image: &mut Option<ReferenceImageState>,
+    notes: &mut String,
     file_name: &str,
     undo_redo: &mut UndoRedoCell,
 ) -> Result<(), Box<dyn Error>> {
     ensure_folder_exists()?;
 
-    color_grid.0.iter_mut().for_each(|array| {
-        array
-            .iter_mut()
-            .for_each(|color_cell| color_cell.borrow_mut().empty_the_cell(undo_redo))
-    });
+    color_grid.clear(undo_redo);
 
     let file = File::open(format!("{}/{}", FILE_DIR, file_name))?;
     let reader = BufReader::new(file);
 
+    let mut grid_size = DEFAULT_GRID_SIZE;
+    let (mut center_x, mut center_y, mut zoom) = (None, None, None);
+    let mut reference_file = None;
+    let mut note_lines = Vec::new();
+    let mut reference_state = ReferenceImageState::beside_color_grid("", Default::default());
+    let mut cells = Vec::new();
     let mut ramp_config_section = false;
     for line in reader.lines() {
         let line = line?;
         if ramp_config_section {
+            if let Some(file_name) = line.strip_prefix(REFERENCE_FILE_PREFIX) {
+                reference_file = Some(file_name.to_string());
+                continue;
+            }
+            if let Some(note_line) = line.strip_prefix(NOTE_PREFIX) {
+                note_lines.push(note_line.to_string());
+                continue;
+            }
             let tuple_list = get_tuple_list_from_string(&line);
             for tuple in tuple_list {
                 let tuple = tuple?;
-                let amt = tuple.1.parse::<i8>()?;
                 match tuple.0 {
-                    "h" => ramp_config.hue_shift = amt,
-                    "s" => ramp_config.saturation_shift = amt,
-                    "v" => ramp_config.value_shift = amt,
+                    "h" => ramp_config.hue_shift = tuple.1.parse::<i8>()?,
+                    "s" => ramp_config.saturation_shift = tuple.1.parse::<i8>()?,
+                    "v" => ramp_config.value_shift = tuple.1.parse::<i8>()?,
+                    "width" => grid_size.x = tuple.1.parse::<usize>()?,
+                    "height" => grid_size.y = tuple.1.parse::<usize>()?,
+                    "center_x" => center_x = Some(tuple.1.parse::<f32>()?),
+                    "center_y" => center_y = Some(tuple.1.parse::<f32>()?),
+                    "zoom" => zoom = Some(tuple.1.parse::<f32>()?),
+                    "reference_x" => reference_state.position.x = tuple.1.parse::<f32>()?,
+                    "reference_y" => reference_state.position.y = tuple.1.parse::<f32>()?,
+                    "reference_scale" => reference_state.scale = tuple.1.parse::<f32>()?,
+                    "reference_opacity" => reference_state.opacity = tuple.1.parse::<f32>()?,
                     string => {
                         return Err(Box::new(io::Error::new(
                             io::ErrorKind::InvalidData,
@@ -216,14 +286,32 @@ pub fn load_color_grid(
                 .parse::<u8>()?,
         );
 
+        cells.push((coordinates, color));
+    }
+
+    *view_state = if let (Some(x), Some(y), Some(zoom)) = (center_x, center_y, zoom) {
+        Some(ViewState {
+            center: Vector2::new(x, y),
+            zoom,
+        })
+    } else {
+        None
+    };
+    *reference_image = reference_file.map(|file_name| ReferenceImageState {
+        file_name,
+        ..reference_state
+    });
+    *notes = note_lines.join("\n");
+
+    color_grid.resize(grid_size);
+    for (coordinates, color) in cells {
+        color_grid.grow_to_fit_idx(coordinates);
         if color_grid.is_idx_valid(coordinates) {
-            color_grid[coordinates.x][coordinates.y]
-                .borrow_mut()
-                .fill_the_cell(undo_redo, color.into());
+            color_grid.fill_cell(coordinates, color.into(), undo_redo);
         } else {
             error!(
                 "Coordinate values are not valid! {:#?}\nCoordinate values must be less than: {:#?}",
-                coordinates, GRID_SIZE
+                coordinates, MAX_GRID_SIZE
             );
         }
     }
@@ -231,27 +319,23 @@ pub fn load_color_grid(
 }
 
 pub fn export_color_grid(color_grid: &ColorGrid, file_name: &str) -> Result<(), Box<dyn Error>> {
-    let mut pixels: Vec<u8> = Vec::with_capacity(GRID_SIZE * GRID_SIZE * 4);
-    for color_cell in color_grid.iter() {
-        let color_cell = color_cell.borrow();
-        if color_cell.draw_full_cell {
-            let color: Color = color_cell.full_cell_current_color().into();
-            pixels.extend([color.r, color.g, color.b, color.a]);
-        } else {
-            pixels.extend([0, 0, 0, 0]);
-        }
-    }
+    let size = color_grid.size();
+    export_rgba_pixels(
+        Vector2::new(u32::try_from(size.x)?, u32::try_from(size.y)?),
+        &color_grid.to_rgba_pixels(),
+        file_name,
+    )
+}
+
+/// Saves row by row RGBA `pixels` as an image in the files folder
+pub fn export_rgba_pixels(
+    size: Vector2<u32>,
+    pixels: &[u8],
+    file_name: &str,
+) -> Result<(), Box<dyn Error>> {
     let image = unsafe {
-        Image::create_from_pixels(
-            GRID_SIZE
-                .try_into()
-                .expect("Fails if GRID_SIZE is greater than u32::MAX"),
-            GRID_SIZE
-                .try_into()
-                .expect("Fails if GRID_SIZE is greater than u32::MAX"),
-            &pixels,
-        )
-        .ok_or("Failed to create image from pixels!")?
+        Image::create_from_pixels(size.x, size.y, pixels)
+            .ok_or("Failed to create image from pixels!")?
     };
     if image.save_to_file(&format!("{}/{}", FILE_DIR, file_name)) {
         Ok(())
@@ -262,6 +346,19 @@ pub fn export_color_grid(color_grid: &ColorGrid, file_name: &str) -> Result<(),
     }
 }
 
+/// Loads a png from the files folder as its size and row by row RGBA pixels
+pub fn load_rgba_pixels(file_name: &str) -> Result<(Vector2<u32>, Vec<u8>), Box<dyn Error>> {
+    ensure_folder_exists()?;
+    let image = Image::from_file(&format!("{}/{}", FILE_DIR, file_name))?;
+    Ok((image.size(), image.pixel_data().to_vec()))
+}
+
+/// Loads a png from the files folder
+pub fn load_reference_texture(file_name: &str) -> Result<SfBox<Texture>, Box<dyn Error>> {
+    ensure_folder_exists()?;
+    Ok(Texture::from_file(&format!("{}/{}", FILE_DIR, file_name))?)
+}
+
 pub fn full_file_path() -> Result<PathBuf, Box<dyn Error>> {
     let mut dir = std::env::current_dir()?;
     dir.push(FILE_DIR);
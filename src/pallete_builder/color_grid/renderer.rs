@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use sfml::{
+    graphics::{Color, FloatRect, PrimitiveType, RenderStates, RenderTarget, RenderWindow, Vertex},
+    system::Vector2,
+};
+use utils::quads::Quad;
+
+use self::empty_cell::EmptyCell;
+
+use super::{ColorGrid, CELL_SIZE};
+
+mod empty_cell;
+
+/// Draws a [`ColorGrid`] in as few draw calls as possible.
+///
+/// Filled cells are cached in a single vertex buffer, which is only rebuilt when the
+/// [`revision`](ColorGrid::revision) of the grid changes. Empty cells are not drawn at
+/// all, unless they are animating a hover.
+#[derive(Debug, Clone, Default)]
+pub struct ColorGridRenderer {
+    rendered_revision: Option<u64>,
+    filled_cells: Vec<Vertex>,
+    hover_animations: HashMap<(usize, usize), EmptyCell>,
+    hover_vertices: Vec<Vertex>,
+}
+
+impl ColorGridRenderer {
+    pub fn update(&mut self, color_grid: &ColorGrid, hovered_cells: &[Vector2<usize>]) {
+        if self.rendered_revision != Some(color_grid.revision()) {
+            self.rebuild_filled_cells(color_grid);
+        }
+        self.update_hover_animations(color_grid, hovered_cells);
+    }
+
+    fn rebuild_filled_cells(&mut self, color_grid: &ColorGrid) {
+        let cell_size = CELL_SIZE.as_other::<f32>();
+        self.filled_cells.clear();
+        for (idx, hsv) in color_grid.iter() {
+            let mut quad = Quad::from(FloatRect::from_vecs(
+                ColorGrid::idx_to_coord(idx),
+                cell_size,
+            ));
+            quad.set_quad_to_one_color(Color::from(hsv));
+            self.filled_cells.extend_from_slice(&quad.as_triangles());
+        }
+        self.rendered_revision = Some(color_grid.revision());
+    }
+
+    fn update_hover_animations(
+        &mut self,
+        color_grid: &ColorGrid,
+        hovered_cells: &[Vector2<usize>],
+    ) {
+        for empty_cell in self.hover_animations.values_mut() {
+            empty_cell.is_hover = false;
+        }
+        for &idx in hovered_cells {
+            self.hover_animations
+                .entry((idx.x, idx.y))
+                .or_default()
+                .is_hover = true;
+        }
+
+        self.hover_animations.retain(|&(x, y), empty_cell| {
+            let idx = Vector2::new(x, y);
+            color_grid.is_idx_valid(idx)
+                && !color_grid.is_cell_filled(idx)
+                && !empty_cell.is_finished()
+        });
+
+        self.hover_vertices.clear();
+        for (&(x, y), empty_cell) in &mut self.hover_animations {
+            empty_cell.update();
+            empty_cell.append_vertices(Vector2::new(x, y), &mut self.hover_vertices);
+        }
+    }
+
+    pub fn render(&self, window: &mut RenderWindow) {
+        for vertices in [&self.filled_cells, &self.hover_vertices] {
+            if !vertices.is_empty() {
+                window.draw_primitives(
+                    vertices,
+                    PrimitiveType::TRIANGLES,
+                    &RenderStates::default(),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::time::{Duration, Instant};
+
+use sfml::{
+    graphics::{Color, FloatRect, Vertex},
+    system::Vector2,
+};
+use utils::quads::Quad;
+
+use crate::pallete_builder::color_grid::{ColorGrid, CELL_SIZE};
+
+const OUTLINE_THICKNESS: f32 = 1f32;
+const PLUS_THICKNESS: f32 = 1f32;
+const PLUS_LENGTH: f32 = 16f32;
+const BASE_OUTLINE_COLOR: Color = Color::rgba(0xf7, 0xe5, 0xe4, 0x00);
+const DURATION_BETWEEN_FRAMES: Duration = Duration::from_millis(6);
+const MIN_ALPHA_VALUE: u8 = 64;
+const MAX_ALPHA_VALUE: u8 = 250;
+
+/// Pulsing outline and plus sign drawn over a hovered empty cell.
+///
+/// Fades out once the cell is no longer hovered.
+#[derive(Debug, Clone)]
+pub struct EmptyCell {
+    increment_direction: i8,
+    last_animation_frame: Instant,
+    color: Color,
+    pub is_hover: bool,
+}
+
+impl EmptyCell {
+    /// True once the cell is no longer hovered and has fully faded out
+    pub fn is_finished(&self) -> bool {
+        !self.is_hover && self.color.a == u8::MIN
+    }
+
+    pub fn update(&mut self) {
+        if !self.is_hover {
+            if self.last_animation_frame.elapsed() > DURATION_BETWEEN_FRAMES
+                && self.color.a > u8::MIN
+            {
+                self.last_animation_frame = Instant::now();
+                self.color.a -= 1;
+            }
+
+            return;
+        }
+
+        if self.last_animation_frame.elapsed() > DURATION_BETWEEN_FRAMES {
+            self.last_animation_frame = Instant::now();
+            if self.increment_direction.is_positive() && self.color.a != u8::MAX {
+                self.color.a += 1;
+            } else if self.increment_direction.is_negative() && self.color.a != u8::MIN {
+                self.color.a -= 1;
+            }
+            if self.color.a <= MIN_ALPHA_VALUE {
+                self.increment_direction = 1;
+            } else if self.color.a >= MAX_ALPHA_VALUE {
+                self.increment_direction = -1;
+            }
+        }
+    }
+
+    /// Appends the outline and plus of the cell at `idx` as [`PrimitiveType::TRIANGLES`]
+    ///
+    /// [`PrimitiveType::TRIANGLES`]: sfml::graphics::PrimitiveType::TRIANGLES
+    pub fn append_vertices(&self, idx: Vector2<usize>, vertices: &mut Vec<Vertex>) {
+        if self.color.a == u8::MIN {
+            return;
+        }
+
+        let position = ColorGrid::idx_to_coord(idx);
+        let size = CELL_SIZE.as_other::<f32>();
+        let center = position + size / 2.;
+        let rects = [
+            // Outline
+            FloatRect::new(position.x, position.y, size.x, OUTLINE_THICKNESS),
+            FloatRect::new(
+                position.x,
+                position.y + size.y - OUTLINE_THICKNESS,
+                size.x,
+                OUTLINE_THICKNESS,
+            ),
+            FloatRect::new(
+                position.x,
+                position.y + OUTLINE_THICKNESS,
+                OUTLINE_THICKNESS,
+                size.y - OUTLINE_THICKNESS * 2.,
+            ),
+            FloatRect::new(
+                position.x + size.x - OUTLINE_THICKNESS,
+                position.y + OUTLINE_THICKNESS,
+                OUTLINE_THICKNESS,
+                size.y - OUTLINE_THICKNESS * 2.,
+            ),
+            // Plus
+            FloatRect::new(
+                center.x - PLUS_THICKNESS / 2.,
+                center.y - PLUS_LENGTH / 2.,
+                PLUS_THICKNESS,
+                PLUS_LENGTH,
+            ),
+            FloatRect::new(
+                center.x - PLUS_LENGTH / 2.,
+                center.y - PLUS_THICKNESS / 2.,
+                PLUS_LENGTH,
+                PLUS_THICKNESS,
+            ),
+        ];
+
+        for rect in rects {
+            let mut quad = Quad::from(rect);
+            quad.set_quad_to_one_color(self.color);
+            vertices.extend_from_slice(&quad.as_triangles());
+        }
+    }
+}
+
+impl Default for EmptyCell {
+    fn default() -> Self {
+        Self {
+            increment_direction: 1,
+            last_animation_frame: Instant::now(),
+            color: BASE_OUTLINE_COLOR,
+            is_hover: false,
+        }
+    }
+}
@@ -1,14 +1,25 @@
 use std::time::{Duration, Instant};
 
-use utils::center_of_rect;
+use sfml::system::Vector2;
 
-use super::{color_cell::ColorCell, ColorGrid};
+use crate::pallete_builder::hsv_color::Hsv;
+
+use super::ColorGrid;
 
 const NUM_EVENTS_INCREASE_DURATION: Duration = Duration::from_millis(100);
 const NUM_EVENTS_INCREASE_STEPPER: u8 = 8;
+
+/// A single change made to a cell. None represents an empty cell
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CellChange {
+    idx: Vector2<usize>,
+    previous_color: Option<Hsv>,
+    new_color: Option<Hsv>,
+}
+
 #[derive(Debug)]
 pub struct UndoRedoCell {
-    cell_changes: Vec<(ColorCell, ColorCell)>,
+    cell_changes: Vec<CellChange>,
     current_idx: usize,
     last_undo_redo_event: Instant,
     num_of_events: u8,
@@ -41,12 +52,11 @@ impl UndoRedoCell {
             return;
         }
 
-        let center_of_current_cell =
-            center_of_rect!(i32, self.cell_changes[self.current_idx].0.global_bounds);
-        if let Some(color_cell) = color_grid.coord_to_cell_mut(center_of_current_cell) {
-            *color_cell.borrow_mut() = self.cell_changes[self.current_idx].0.clone();
-            self.current_idx = self.current_idx.checked_sub(1).unwrap_or(self.current_idx);
-        }
+        let Some(cell_change) = self.cell_changes.get(self.current_idx) else {
+            return;
+        };
+        color_grid.set_cell(cell_change.idx, cell_change.previous_color);
+        self.current_idx = self.current_idx.checked_sub(1).unwrap_or(self.current_idx);
 
         self.undo_action(color_grid, repititions - 1);
     }
@@ -57,7 +67,7 @@ impl UndoRedoCell {
     }
 
     pub fn redo_action(&mut self, color_grid: &mut ColorGrid, repititions: u8) {
-        if repititions == 0 {
+        if repititions == 0 || self.cell_changes.is_empty() {
             return;
         }
         self.current_idx = if self.current_idx + 1 >= self.cell_changes.len() {
@@ -65,12 +75,8 @@ impl UndoRedoCell {
         } else {
             self.current_idx + 1
         };
-        let center_of_current_cell =
-            center_of_rect!(i32, self.cell_changes[self.current_idx].1.global_bounds);
-        if let Some(color_cell) = color_grid.coord_to_cell_mut(center_of_current_cell) {
-            *color_cell.borrow_mut() = self.cell_changes[self.current_idx].1.clone();
-            color_cell.borrow_mut().empty_cell.is_hover = false;
-        }
+        let cell_change = self.cell_changes[self.current_idx];
+        color_grid.set_cell(cell_change.idx, cell_change.new_color);
 
         self.redo_action(color_grid, repititions - 1);
     }
@@ -80,17 +86,25 @@ impl UndoRedoCell {
         self.redo_action(color_grid, repititions)
     }
 
-    /// MUST provide a clone to the previous color cell before the change occurs
-    pub fn change_made(&mut self, previous_color_cell: ColorCell, new_color_cell: ColorCell) {
+    /// Records a change made to the cell at `idx`. None represents an empty cell
+    pub fn change_made(
+        &mut self,
+        idx: Vector2<usize>,
+        previous_color: Option<Hsv>,
+        new_color: Option<Hsv>,
+    ) {
         // If erasing an already erased cell, do not insert into cell_changes
-        if !previous_color_cell.draw_full_cell && !new_color_cell.draw_full_cell {
+        if previous_color.is_none() && new_color.is_none() {
             return;
         }
         // get rid of redos
         self.cell_changes.truncate(self.current_idx + 1);
 
-        self.cell_changes
-            .push((previous_color_cell, new_color_cell));
+        self.cell_changes.push(CellChange {
+            idx,
+            previous_color,
+            new_color,
+        });
         self.current_idx = self.cell_changes.len() - 1;
 
         self.simplify_last_four_repititions();
@@ -106,12 +120,10 @@ impl UndoRedoCell {
         }
         let start = self.current_idx - 3;
         let end = self.current_idx;
-        let last_four_colors = self.cell_changes[start..=end].to_vec();
+        let last_four_changes = &self.cell_changes[start..=end];
 
-        if last_four_colors[0].0.coords == last_four_colors[2].0.coords
-            && last_four_colors[0].1.coords == last_four_colors[2].1.coords
-            && last_four_colors[1].0.coords == last_four_colors[3].0.coords
-            && last_four_colors[1].1.coords == last_four_colors[3].1.coords
+        if last_four_changes[0].idx == last_four_changes[2].idx
+            && last_four_changes[1].idx == last_four_changes[3].idx
         {
             self.cell_changes.truncate(self.current_idx - 2);
             self.current_idx = self.cell_changes.len() - 1;
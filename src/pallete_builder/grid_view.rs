@@ -0,0 +1,163 @@
+use sfml::{
+    graphics::{FloatRect, View},
+    system::{Vector2, Vector2f, Vector2i},
+    SfBox,
+};
+
+const VIEW_MIN_SIZE: Vector2f = Vector2f::new(100f32, 100f32);
+const VIEW_MAX_SIZE: Vector2f = Vector2f::new(1920f32, 1080f32);
+/// Leaves a little room around the content when fitting the view to it
+const FIT_PADDING: f32 = 1.1;
+
+/// What is saved alongside a palette file, so it opens where it was left off.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewState {
+    pub center: Vector2f,
+    pub zoom: f32,
+}
+
+/// Camera over the color grid.
+///
+/// The zoom is stored relative to the window size, so resizing the window keeps the
+/// same zoom level instead of resetting it.
+#[derive(Debug)]
+pub struct GridView {
+    view: SfBox<View>,
+    window_size: Vector2f,
+    zoom: f32,
+}
+
+impl GridView {
+    pub fn new(center: Vector2f, window_size: Vector2f) -> Self {
+        Self {
+            view: View::new(center, window_size),
+            window_size,
+            zoom: 1.,
+        }
+    }
+
+    pub fn view(&self) -> &View {
+        &self.view
+    }
+
+    pub fn center(&self) -> Vector2f {
+        self.view.center()
+    }
+
+    pub fn set_center(&mut self, center: Vector2f) {
+        self.view.set_center(center);
+    }
+
+    /// Area of the world that is currently visible
+    pub fn world_rect(&self) -> FloatRect {
+        FloatRect::from_vecs(self.center() - self.view.size() / 2., self.view.size())
+    }
+
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    pub fn state(&self) -> ViewState {
+        ViewState {
+            center: self.center(),
+            zoom: self.zoom,
+        }
+    }
+
+    pub fn set_state(&mut self, view_state: ViewState) {
+        self.set_center(view_state.center);
+        self.set_zoom(view_state.zoom);
+    }
+
+    pub fn set_window_size(&mut self, window_size: Vector2f) {
+        self.window_size = window_size;
+        self.set_zoom(self.zoom);
+    }
+
+    /// Zoom is clamped so the view stays between `VIEW_MIN_SIZE` and `VIEW_MAX_SIZE`
+    pub fn set_zoom(&mut self, zoom: f32) {
+        let min_zoom =
+            (self.window_size.x / VIEW_MAX_SIZE.x).max(self.window_size.y / VIEW_MAX_SIZE.y);
+        let max_zoom =
+            (self.window_size.x / VIEW_MIN_SIZE.x).min(self.window_size.y / VIEW_MIN_SIZE.y);
+        self.zoom = if zoom.is_nan() {
+            1.
+        } else {
+            zoom.max(min_zoom).min(max_zoom)
+        };
+        self.view.set_size(self.window_size / self.zoom);
+    }
+
+    /// Multiplies the zoom, keeping the world position under `window_position` in place.
+    pub fn zoom_at(&mut self, factor: f32, window_position: Vector2i) {
+        let world_position_before = self.window_to_world(window_position);
+        self.set_zoom(self.zoom * factor);
+        let world_position_after = self.window_to_world(window_position);
+        self.set_center(self.center() + world_position_before - world_position_after);
+    }
+
+    /// Moves the view by a fraction of its current size
+    pub fn pan(&mut self, fraction: Vector2f) {
+        self.set_center(self.center() + fraction.cwise_mul(self.view.size()));
+    }
+
+    /// Centers on `rect` and zooms so it fits inside of the window
+    pub fn fit_rect(&mut self, rect: FloatRect) {
+        let size = rect.size() * FIT_PADDING;
+        self.set_zoom((self.window_size.x / size.x).min(self.window_size.y / size.y));
+        self.set_center(rect.position() + rect.size() / 2.);
+    }
+
+    /// Keeps the center of the view inside of `bounds`
+    pub fn clamp_center(&mut self, bounds: FloatRect) {
+        let center = self.center();
+        self.set_center(Vector2::new(
+            center.x.max(bounds.left).min(bounds.left + bounds.width),
+            center.y.max(bounds.top).min(bounds.top + bounds.height),
+        ));
+    }
+
+    /// Converts a position in the window into a world coordinate
+    pub fn window_to_world(&self, window_position: Vector2i) -> Vector2f {
+        let ratio = window_position
+            .as_other::<f32>()
+            .cwise_div(self.window_size);
+        self.center() + (ratio - Vector2::new(0.5, 0.5)).cwise_mul(self.view.size())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_zoom_at_keeps_position_under_cursor() {
+        let mut grid_view = GridView::new(Vector2::new(500., 500.), Vector2::new(800., 600.));
+        let cursor = Vector2::new(100, 450);
+        let world_position = grid_view.window_to_world(cursor);
+
+        grid_view.zoom_at(2., cursor);
+        assert_eq!(grid_view.zoom(), 2.);
+        let difference = grid_view.window_to_world(cursor) - world_position;
+        assert!(difference.x.abs() < 0.01 && difference.y.abs() < 0.01);
+    }
+
+    #[test]
+    fn test_zoom_is_clamped() {
+        let mut grid_view = GridView::new(Vector2::new(0., 0.), Vector2::new(800., 600.));
+        grid_view.set_zoom(100.);
+        assert!((grid_view.view().size().y - VIEW_MIN_SIZE.y).abs() < 0.01);
+
+        grid_view.set_zoom(0.01);
+        assert!((grid_view.view().size().y - VIEW_MAX_SIZE.y).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_fit_rect() {
+        let mut grid_view = GridView::new(Vector2::new(0., 0.), Vector2::new(800., 600.));
+        grid_view.fit_rect(FloatRect::new(100., 100., 200., 100.));
+
+        assert_eq!(grid_view.center(), Vector2::new(200., 150.));
+        assert!(grid_view.view().size().x >= 200. && grid_view.view().size().y >= 100.);
+    }
+}
@@ -1,53 +1,53 @@
 use sfml::{system::Vector2, window::Event};
 
-use super::color_grid::{color_cell::RcColorCell, ColorGrid};
+use super::color_grid::ColorGrid;
 
 #[derive(Debug, Clone, Default)]
 pub struct HoverHandler {
-    cells_in_hover_state: Vec<RcColorCell>,
+    hovered_cells: Vec<Vector2<usize>>,
 }
 
 impl HoverHandler {
+    pub fn hovered_cells(&self) -> &[Vector2<usize>] {
+        &self.hovered_cells
+    }
+
     pub fn unhover_all_cells(&mut self) {
-        for cell in &mut self.cells_in_hover_state {
-            cell.borrow_mut().set_hover(false);
-        }
-        self.cells_in_hover_state.clear();
-        if self.cells_in_hover_state.capacity() > usize::from(u8::MAX) {
-            self.cells_in_hover_state = Vec::with_capacity(usize::from(u8::MAX))
+        self.hovered_cells.clear();
+        if self.hovered_cells.capacity() > usize::from(u8::MAX) {
+            self.hovered_cells = Vec::with_capacity(usize::from(u8::MAX))
         }
     }
 
-    pub fn event_handler(&mut self, event: Event, color_grid: &mut ColorGrid) {
+    pub fn event_handler(&mut self, event: Event, color_grid: &ColorGrid) {
         self.unhover_all_cells();
         fn set_mouse_hover(
-            color_grid: &mut ColorGrid,
+            color_grid: &ColorGrid,
             x: i32,
             y: i32,
-            cells_in_hover_state: &mut Vec<RcColorCell>,
+            hovered_cells: &mut Vec<Vector2<usize>>,
         ) {
-            if let Some(color_cell) = color_grid.coord_to_cell_mut(Vector2::new(x, y)) {
-                cells_in_hover_state.push(color_cell.clone());
-                color_cell.borrow_mut().set_hover(true);
+            if let Some(idx) = color_grid.coord_to_idx(Vector2::new(x, y)) {
+                hovered_cells.push(idx);
             }
         }
 
         match event {
             Event::MouseMoved { x, y } => {
-                set_mouse_hover(color_grid, x, y, &mut self.cells_in_hover_state)
+                set_mouse_hover(color_grid, x, y, &mut self.hovered_cells)
             }
             Event::MouseButtonPressed { button: _, x, y } => {
-                set_mouse_hover(color_grid, x, y, &mut self.cells_in_hover_state)
+                set_mouse_hover(color_grid, x, y, &mut self.hovered_cells)
             }
             Event::MouseButtonReleased { button: _, x, y } => {
-                set_mouse_hover(color_grid, x, y, &mut self.cells_in_hover_state)
+                set_mouse_hover(color_grid, x, y, &mut self.hovered_cells)
             }
             Event::MouseWheelScrolled {
                 wheel: _,
                 delta: _,
                 x,
                 y,
-            } => set_mouse_hover(color_grid, x, y, &mut self.cells_in_hover_state),
+            } => set_mouse_hover(color_grid, x, y, &mut self.hovered_cells),
             _ => {}
         }
     }
@@ -4,7 +4,7 @@ use sfml::{
 };
 
 use super::{
-    color_grid::{color_cell::RcColorCell, undo_redo::UndoRedoCell, ColorGrid},
+    color_grid::{undo_redo::UndoRedoCell, ColorGrid},
     hover_handler::HoverHandler,
     hsv_color::Hsv,
     ui_components::{erase_mode::EraseMode, hsv_selector::HSVSelector},
@@ -43,10 +43,15 @@ pub struct NormalMode {
     hover_handler: HoverHandler,
     is_dragging_cell: bool,
     color_being_dragged: Hsv,
-    cells_dragged_over: Vec<(Hsv, bool, RcColorCell)>,
+    /// Cells the drag has painted over, and the color they held before. None is an empty cell
+    cells_dragged_over: Vec<(Vector2<usize>, Option<Hsv>)>,
 }
 
 impl NormalMode {
+    pub fn hovered_cells(&self) -> &[Vector2<usize>] {
+        self.hover_handler.hovered_cells()
+    }
+
     pub fn event_handler(&mut self, args: &mut NormalModeEventHandlerArguments) {
         self.hover_handler
             .event_handler(args.event, args.color_grid);
@@ -56,25 +61,16 @@ impl NormalMode {
     }
 
     fn mouse_actions_handler(&mut self, args: &mut NormalModeEventHandlerArguments) {
-        fn is_draw_full_cell_enabled(color_cell: Option<RcColorCell>) -> bool {
-            if let Some(color_cell) = color_cell {
-                color_cell.borrow().draw_full_cell()
-            } else {
-                false
-            }
-        }
-
         fn empty_cells_dragged_over(
-            cells_dragged_over: &mut Vec<(Hsv, bool, RcColorCell)>,
+            cells_dragged_over: &mut Vec<(Vector2<usize>, Option<Hsv>)>,
+            color_grid: &mut ColorGrid,
             undo_redo: &mut UndoRedoCell,
         ) {
-            for (hsv, is_full_cell, cell) in &mut *cells_dragged_over {
-                let cell = &mut cell.borrow_mut();
-
-                if *is_full_cell {
-                    cell.fill_the_cell(undo_redo, *hsv);
+            for &(idx, previous_color) in &*cells_dragged_over {
+                if let Some(previous_color) = previous_color {
+                    color_grid.fill_cell(idx, previous_color, undo_redo);
                 } else {
-                    cell.empty_the_cell(undo_redo);
+                    color_grid.empty_cell(idx, undo_redo);
                 }
             }
 
@@ -88,64 +84,59 @@ impl NormalMode {
             Event::MouseButtonReleased { button, x, y }
                 if !self.is_dragging_cell && button == Button::Left =>
             {
-                if let Some(color_cell) = args.color_grid.coord_to_cell_mut(Vector2::new(x, y)) {
-                    color_cell
-                        .borrow_mut()
-                        .fill_the_cell(args.undo_redo, args.hsv_selector.curr_color())
+                if let Some(idx) = args.color_grid.coord_to_idx(Vector2::new(x, y)) {
+                    args.color_grid
+                        .fill_cell(idx, args.hsv_selector.curr_color(), args.undo_redo)
                 }
             }
 
             // Start dragging color
-            Event::MouseButtonPressed { button, x, y }
-                if button == Button::Left
-                    && is_draw_full_cell_enabled(
-                        args.color_grid.coord_to_cell_mut(Vector2::new(x, y)),
-                    ) =>
-            {
+            Event::MouseButtonPressed { button, x, y } if button == Button::Left => {
+                let Some(idx) = args.color_grid.coord_to_idx(Vector2::new(x, y)) else {
+                    return;
+                };
+                let Some(color) = args.color_grid.get(idx) else {
+                    return;
+                };
                 self.is_dragging_cell = true;
-                if let Some(color_cell) = args.color_grid.coord_to_cell_mut(Vector2::new(x, y)) {
-                    self.color_being_dragged = color_cell.borrow().full_cell_current_color();
-                    self.cells_dragged_over.push((
-                        color_cell.borrow().full_cell_current_color(),
-                        false,
-                        color_cell.clone(),
-                    ));
-                    color_cell
-                        .borrow_mut()
-                        .fill_the_cell(args.undo_redo, self.color_being_dragged);
-                }
+                self.color_being_dragged = color;
+                // The cell being dragged is emptied once the drag finishes
+                self.cells_dragged_over.push((idx, None));
+                args.color_grid
+                    .fill_cell(idx, self.color_being_dragged, args.undo_redo);
             }
             // Dragging color
             Event::MouseMoved { x, y } if self.is_dragging_cell => {
-                empty_cells_dragged_over(&mut self.cells_dragged_over, args.undo_redo);
-                if let Some(color_cell) = args.color_grid.coord_to_cell_mut(Vector2::new(x, y)) {
-                    self.cells_dragged_over.push((
-                        color_cell.borrow().full_cell_current_color(),
-                        color_cell.borrow().draw_full_cell(),
-                        color_cell.clone(),
-                    ));
-                    color_cell
-                        .borrow_mut()
-                        .fill_the_cell(args.undo_redo, self.color_being_dragged);
+                empty_cells_dragged_over(
+                    &mut self.cells_dragged_over,
+                    args.color_grid,
+                    args.undo_redo,
+                );
+                if let Some(idx) = args.color_grid.coord_to_idx(Vector2::new(x, y)) {
+                    self.cells_dragged_over
+                        .push((idx, args.color_grid.get(idx)));
+                    args.color_grid
+                        .fill_cell(idx, self.color_being_dragged, args.undo_redo);
                 }
             }
             // Finish dragging color
             Event::MouseButtonReleased { button: _, x, y } if self.is_dragging_cell => {
-                let last_dragged_over_cell_color =
-                    if let Some(tuple) = self.cells_dragged_over.last() {
-                        tuple.0
-                    } else {
-                        Default::default()
-                    };
-                empty_cells_dragged_over(&mut self.cells_dragged_over, args.undo_redo);
+                let last_dragged_over_cell_color = self
+                    .cells_dragged_over
+                    .last()
+                    .and_then(|&(_, previous_color)| previous_color)
+                    .unwrap_or_default();
+                empty_cells_dragged_over(
+                    &mut self.cells_dragged_over,
+                    args.color_grid,
+                    args.undo_redo,
+                );
 
-                if let Some(color_cell) = args.color_grid.coord_to_cell_mut(Vector2::new(x, y)) {
-                    color_cell
-                        .borrow_mut()
-                        .fill_the_cell(args.undo_redo, last_dragged_over_cell_color);
-                    color_cell
-                        .borrow_mut()
-                        .fill_the_cell(args.undo_redo, self.color_being_dragged);
+                if let Some(idx) = args.color_grid.coord_to_idx(Vector2::new(x, y)) {
+                    args.color_grid
+                        .fill_cell(idx, last_dragged_over_cell_color, args.undo_redo);
+                    args.color_grid
+                        .fill_cell(idx, self.color_being_dragged, args.undo_redo);
                 }
 
                 self.is_dragging_cell = false;
@@ -154,9 +145,3 @@ impl NormalMode {
         }
     }
 }
-
-impl Drop for NormalMode {
-    fn drop(&mut self) {
-        self.hover_handler.unhover_all_cells();
-    }
-}
@@ -0,0 +1,148 @@
+use super::{
+    color_grid::{undo_redo::UndoRedoCell, ColorGrid},
+    grid_view::ViewState,
+    reference_image::{ReferenceImage, ReferenceImageState},
+    ui_components::config_selector::Config,
+};
+
+/// Longest file name shown on a tab before it is cut off
+const MAX_TAB_TITLE_LEN: usize = 10;
+const SAVE_FILE_EXTENSION: &str = ".pacrb";
+
+/// One open palette. Every tab keeps its own grid, undo history, ramp config and view.
+///
+/// The active tab is unpacked into [`PalleteBuilder`](super::PalleteBuilder) while it is
+/// being edited, so only the inactive tabs hold their state in here.
+#[derive(Debug)]
+pub struct PalleteTab {
+    pub color_grid: ColorGrid,
+    pub undo_redo: UndoRedoCell,
+    pub ramp_config: Config,
+    /// None until the tab has been viewed, the view is then fit to the content
+    pub view_state: Option<ViewState>,
+    pub reference_image: Option<ReferenceImage>,
+    pub save_file: String,
+    pub notes: String,
+    /// What the tab looked like the last time it was opened, saved or loaded
+    pub saved_state: SavedState,
+}
+
+/// Everything of a tab that is written to its save file
+#[derive(Clone, Debug, PartialEq)]
+pub struct SavedState {
+    /// Changes with the cells and the grid size
+    pub grid_revision: u64,
+    pub ramp_config: Config,
+    pub view_state: Option<ViewState>,
+    pub reference_image: Option<ReferenceImageState>,
+    pub notes: String,
+}
+
+impl PalleteTab {
+    /// Whether `state`, the current state of this tab, differs from what was last saved
+    pub fn has_unsaved_changes(&self, state: &SavedState) -> bool {
+        *state != self.saved_state
+    }
+
+    /// A tab that can be replaced by a loaded file without losing anything
+    pub fn is_untouched(&self, color_grid: &ColorGrid, state: &SavedState) -> bool {
+        self.save_file.is_empty()
+            && color_grid.used_bounds().is_none()
+            && !self.has_unsaved_changes(state)
+    }
+
+    /// The state of an inactive tab, from what was stashed in it
+    pub fn stashed_state(&self) -> SavedState {
+        SavedState {
+            grid_revision: self.color_grid.revision(),
+            ramp_config: self.ramp_config,
+            view_state: self.view_state,
+            reference_image: self
+                .reference_image
+                .as_ref()
+                .map(|reference_image| reference_image.state().clone()),
+            notes: self.notes.clone(),
+        }
+    }
+}
+
+impl Default for PalleteTab {
+    fn default() -> Self {
+        let color_grid = ColorGrid::default();
+        Self {
+            saved_state: SavedState {
+                grid_revision: color_grid.revision(),
+                ramp_config: Default::default(),
+                view_state: None,
+                reference_image: None,
+                notes: String::new(),
+            },
+            color_grid,
+            undo_redo: Default::default(),
+            ramp_config: Default::default(),
+            view_state: None,
+            reference_image: None,
+            save_file: String::new(),
+            notes: String::new(),
+        }
+    }
+}
+
+/// Text shown on a tab, marked with a `*` when it has unsaved changes
+pub fn tab_title(save_file: &str, has_unsaved_changes: bool) -> String {
+    let name = save_file
+        .strip_suffix(SAVE_FILE_EXTENSION)
+        .unwrap_or(save_file);
+    let mut title = if name.is_empty() {
+        "Untitled".to_string()
+    } else if name.chars().count() > MAX_TAB_TITLE_LEN {
+        name.chars().take(MAX_TAB_TITLE_LEN - 2).collect::<String>() + ".."
+    } else {
+        name.to_string()
+    };
+    if has_unsaved_changes {
+        title.push('*');
+    }
+
+    title
+}
+
+#[cfg(test)]
+mod test {
+    use sfml::system::Vector2;
+
+    use super::*;
+
+    #[test]
+    fn test_tab_title() {
+        assert_eq!(tab_title("", false), "Untitled");
+        assert_eq!(tab_title("skin.pacrb", false), "skin");
+        assert_eq!(tab_title("skin.pacrb", true), "skin*");
+        assert_eq!(tab_title("environment_tiles.pacrb", false), "environm..");
+    }
+
+    #[test]
+    fn test_every_saved_field_marks_the_tab_unsaved() {
+        let mut tab = PalleteTab::default();
+        assert!(!tab.has_unsaved_changes(&tab.stashed_state()));
+
+        tab.ramp_config.hue_shift += 1;
+        assert!(tab.has_unsaved_changes(&tab.stashed_state()));
+        tab.saved_state = tab.stashed_state();
+
+        tab.view_state = Some(ViewState {
+            center: Default::default(),
+            zoom: 2.,
+        });
+        assert!(tab.has_unsaved_changes(&tab.stashed_state()));
+        tab.saved_state = tab.stashed_state();
+
+        tab.notes = "Shaded from the top left".to_string();
+        assert!(tab.has_unsaved_changes(&tab.stashed_state()));
+        tab.saved_state = tab.stashed_state();
+
+        let grid_size = tab.color_grid.size();
+        tab.color_grid.resize(grid_size + Vector2::new(1, 1));
+        assert!(tab.has_unsaved_changes(&tab.stashed_state()));
+    }
+}
@@ -6,7 +6,7 @@ use sfml::{
 use self::color_ramper::ColorRamper;
 
 use super::{
-    color_grid::{color_cell::RcColorCell, undo_redo::UndoRedoCell, ColorGrid},
+    color_grid::{undo_redo::UndoRedoCell, ColorGrid},
     hover_handler::HoverHandler,
     hsv_color::Hsv,
     ui_components::{
@@ -80,7 +80,7 @@ pub struct RampMode {
     ramp: ColorRamper,
     previous_config: Config,
     previous_color: Hsv,
-    middle_cell: Option<RcColorCell>,
+    middle_cell: Option<Vector2<usize>>,
     original_middle_cell_color: Option<Hsv>,
 }
 
@@ -89,13 +89,17 @@ impl RampMode {
         if self.previous_config != args.config_selector.current_config()
             || self.previous_color != args.hsv_selector.curr_color()
         {
-            self.ramp.create_ramp(self.ramp.ramp_start_coord(), args);
+            self.ramp.create_ramp(self.ramp.ramp_start_idx(), args);
         }
 
         self.previous_color = args.hsv_selector.curr_color();
         self.previous_config = args.config_selector.current_config();
     }
 
+    pub fn hovered_cells(&self) -> &[Vector2<usize>] {
+        self.hover_handler.hovered_cells()
+    }
+
     pub fn event_handler(&mut self, args: &mut RampModeEventHandlerArguments) {
         if !self.ramp.ramp_being_shown() {
             self.no_ramp_event_handler(args);
@@ -114,24 +118,14 @@ impl RampMode {
 
         match args.event {
             Event::MouseButtonPressed { button, x, y } if button == Button::Left => {
-                let coord = Vector2::new(x, y);
-                if let Some(starting_idx) = args.color_grid.coord_to_idx(coord) {
-                    if args.color_grid[starting_idx.x][starting_idx.y]
-                        .borrow()
-                        .draw_full_cell()
-                    {
-                        self.middle_cell =
-                            Some(args.color_grid[starting_idx.x][starting_idx.y].clone());
-                        self.original_middle_cell_color = Some(
-                            self.middle_cell
-                                .clone()
-                                .unwrap()
-                                .borrow()
-                                .full_cell_current_color(),
-                        );
-                    }
+                let Some(starting_idx) = args.color_grid.coord_to_idx(Vector2::new(x, y)) else {
+                    return;
+                };
+                if let Some(color) = args.color_grid.get(starting_idx) {
+                    self.middle_cell = Some(starting_idx);
+                    self.original_middle_cell_color = Some(color);
                 }
-                self.ramp.create_ramp(coord, args);
+                self.ramp.create_ramp(starting_idx, args);
                 self.previous_config = args.config_selector.current_config();
                 self.previous_color = args.hsv_selector.curr_color();
                 args.confirm_color_ramp.set_enable(true);
@@ -161,18 +155,12 @@ impl RampMode {
         }
     }
 
-    pub fn clear_the_ramp(&mut self, undo_redo: &mut UndoRedoCell) {
-        self.ramp.clear_ramp(undo_redo);
+    pub fn clear_the_ramp(&mut self, color_grid: &mut ColorGrid, undo_redo: &mut UndoRedoCell) {
+        self.ramp.clear_ramp(color_grid, undo_redo);
         if let (Some(middle_cell), Some(color)) =
-            (&mut self.middle_cell, self.original_middle_cell_color)
+            (self.middle_cell, self.original_middle_cell_color)
         {
-            middle_cell.borrow_mut().fill_the_cell(undo_redo, color);
+            color_grid.fill_cell(middle_cell, color, undo_redo);
         }
     }
 }
-
-impl Drop for RampMode {
-    fn drop(&mut self) {
-        self.hover_handler.unhover_all_cells();
-    }
-}
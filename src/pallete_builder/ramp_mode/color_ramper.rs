@@ -1,9 +1,9 @@
-use sfml::system::{Vector2, Vector2i};
+use sfml::system::Vector2;
 use tracing::{error, warn};
-use utils::{center_of_rect, clamp_to_primitive_bounds};
+use utils::clamp_to_primitive_bounds;
 
 use crate::pallete_builder::{
-    color_grid::{color_cell::RcColorCell, undo_redo::UndoRedoCell},
+    color_grid::{undo_redo::UndoRedoCell, ColorGrid},
     hsv_color::Hsv,
     ui_components::confirm_color_ramp::Orientation,
 };
@@ -12,17 +12,17 @@ use super::RampModeEventHandlerArguments;
 
 #[derive(Clone, Debug, Default)]
 pub struct ColorRamper {
-    min_ramp: Vec<RcColorCell>,
-    max_ramp: Vec<RcColorCell>,
+    min_ramp: Vec<Vector2<usize>>,
+    max_ramp: Vec<Vector2<usize>>,
     current_orientation: Orientation,
 }
 
 impl ColorRamper {
-    pub fn ramp_start_coord(&self) -> Vector2i {
-        if let Some(ramp_start_cell) = self.min_ramp.get(0) {
-            center_of_rect!(i32, ramp_start_cell.borrow().global_bounds())
-        } else if let Some(ramp_start_cell) = self.max_ramp.get(0) {
-            center_of_rect!(i32, ramp_start_cell.borrow().global_bounds())
+    pub fn ramp_start_idx(&self) -> Vector2<usize> {
+        if let Some(&ramp_start_idx) = self.min_ramp.get(0) {
+            ramp_start_idx
+        } else if let Some(&ramp_start_idx) = self.max_ramp.get(0) {
+            ramp_start_idx
         } else {
             error!("Unable to get the first/starting ramp coordinate!");
             Default::default()
@@ -37,9 +37,9 @@ impl ColorRamper {
         !self.min_ramp.is_empty() || !self.max_ramp.is_empty()
     }
 
-    pub fn clear_ramp(&mut self, undo_redo: &mut UndoRedoCell) {
-        for color_cell in self.min_ramp.iter_mut().chain(self.max_ramp.iter_mut()) {
-            color_cell.borrow_mut().empty_the_cell(undo_redo);
+    pub fn clear_ramp(&mut self, color_grid: &mut ColorGrid, undo_redo: &mut UndoRedoCell) {
+        for &idx in self.min_ramp.iter().chain(self.max_ramp.iter()) {
+            color_grid.empty_cell(idx, undo_redo);
         }
 
         self.min_ramp = Vec::new();
@@ -51,35 +51,32 @@ impl ColorRamper {
     /// Returns None on failure
     pub fn create_ramp(
         &mut self,
-        coord: Vector2i,
+        starting_idx: Vector2<usize>,
         args: &mut RampModeEventHandlerArguments,
     ) -> Option<()> {
         self.current_orientation = args.confirm_color_ramp.orientation();
         let color_grid = &mut args.color_grid;
         let num_of_shades_per_direction = args.config_selector.current_config().num_of_shades / 2;
-        let starting_idx = color_grid.coord_to_idx(coord)?;
+        if !color_grid.is_idx_valid(starting_idx) {
+            return None;
+        }
         let starting_color = args.hsv_selector.curr_color();
-        self.min_ramp
-            .push(color_grid[starting_idx.x][starting_idx.y].clone());
-        self.clear_ramp(args.undo_redo);
+        self.min_ramp.push(starting_idx);
+        self.clear_ramp(color_grid, args.undo_redo);
 
         match self.current_orientation {
             Orientation::Horizontal => {
                 for i in 0..=num_of_shades_per_direction {
                     let idx =
                         Vector2::new(starting_idx.x.wrapping_sub(usize::from(i)), starting_idx.y);
-                    if !color_grid.is_idx_valid(idx)
-                        || color_grid[idx.x][idx.y].borrow().draw_full_cell()
-                    {
+                    if !color_grid.is_idx_valid(idx) || color_grid.is_cell_filled(idx) {
                         break;
                     }
                     self.min_ramp.push(color_grid[idx.x][idx.y].clone());
                 }
                 for i in 0..=num_of_shades_per_direction {
                     let idx = Vector2::new(starting_idx.x + usize::from(i), starting_idx.y);
-                    if !color_grid.is_idx_valid(idx)
-                        || color_grid[idx.x][idx.y].borrow().draw_full_cell()
-                    {
+                    if !color_grid.is_idx_valid(idx) || color_grid.is_cell_filled(idx) {
                         break;
                     }
                     self.max_ramp.push(color_grid[idx.x][idx.y].clone());
@@ -89,32 +86,22 @@ impl ColorRamper {
                 for i in 0..=num_of_shades_per_direction {
                     let idx =
                         Vector2::new(starting_idx.x, starting_idx.y.wrapping_sub(usize::from(i)));
-                    if !color_grid.is_idx_valid(idx)
-                        || color_grid[idx.x][idx.y].borrow().draw_full_cell()
-                    {
+                    if !color_grid.is_idx_valid(idx) || color_grid.is_cell_filled(idx) {
                         break;
                     }
                     self.min_ramp.push(color_grid[idx.x][idx.y].clone());
                 }
                 for i in 0..=num_of_shades_per_direction {
                     let idx = Vector2::new(starting_idx.x, starting_idx.y + usize::from(i));
-                    if !color_grid.is_idx_valid(idx)
-                        || color_grid[idx.x][idx.y].borrow().draw_full_cell()
-                    {
+                    if !color_grid.is_idx_valid(idx) || color_grid.is_cell_filled(idx) {
                         break;
                     }
                     self.max_ramp.push(color_grid[idx.x][idx.y].clone());
                 }
             }
         }
-        self.min_ramp
-            .first_mut()?
-            .borrow_mut()
-            .fill_the_cell(args.undo_redo, starting_color);
-        self.max_ramp
-            .first_mut()?
-            .borrow_mut()
-            .fill_the_cell(args.undo_redo, starting_color);
+        color_grid.fill_cell(*self.min_ramp.first()?, starting_color, args.undo_redo);
+        color_grid.fill_cell(*self.max_ramp.first()?, starting_color, args.undo_redo);
 
         self.color_the_ramp(args);
 
@@ -123,10 +110,15 @@ impl ColorRamper {
 
     pub fn color_the_ramp(&mut self, args: &mut RampModeEventHandlerArguments) {
         let config = args.config_selector.current_config();
-        let Some(first_cell) = self.min_ramp.first() else { return; };
-        let starting_color = first_cell.borrow().full_cell_current_color();
+        let Some(starting_color) = self
+            .min_ramp
+            .first()
+            .and_then(|&first_idx| args.color_grid.get(first_idx))
+        else {
+            return;
+        };
 
-        for (i, color_cell) in self.min_ramp.iter_mut().enumerate() {
+        for (i, &idx) in self.min_ramp.iter().enumerate() {
             let i = i as i16;
             let h = starting_color
                 .h
@@ -139,11 +131,9 @@ impl ColorRamper {
                 u8,
                 i16::from(starting_color.v).saturating_sub(i * i16::from(config.value_shift))
             );
-            color_cell
-                .borrow_mut()
-                .fill_the_cell(args.undo_redo, Hsv { h, s, v })
+            args.color_grid.fill_cell(idx, Hsv { h, s, v }, args.undo_redo);
         }
-        for (i, color_cell) in self.max_ramp.iter_mut().enumerate() {
+        for (i, &idx) in self.max_ramp.iter().enumerate() {
             let i = i as i16;
             let h = starting_color
                 .h
@@ -156,14 +146,14 @@ impl ColorRamper {
                 u8,
                 i16::from(starting_color.v).saturating_add(i * i16::from(config.value_shift))
             );
-            color_cell
-                .borrow_mut()
-                .fill_the_cell(args.undo_redo, Hsv { h, s, v })
+            args.color_grid.fill_cell(idx, Hsv { h, s, v }, args.undo_redo);
         }
     }
 
     pub fn change_orientation(&mut self, args: &mut RampModeEventHandlerArguments) {
-        let coord = if let (Some(min), Some(max)) = (self.min_ramp.get(0), self.min_ramp.get(0)) {
+        let starting_idx = if let (Some(&min), Some(&max)) =
+            (self.min_ramp.get(0), self.min_ramp.get(0))
+        {
             if min != max {
                 warn!(
                     "min and max location is not the same! Possible orientation errors may ensure {:?} {:?}",
@@ -171,12 +161,12 @@ impl ColorRamper {
                 )
             }
 
-            center_of_rect!(i32, min.borrow().global_bounds())
+            min
         } else {
             error!("Unable to get zeroth element from min and max ramp. Skipping reorientation");
             return;
         };
 
-        self.create_ramp(coord, args);
+        self.create_ramp(starting_idx, args);
     }
 }
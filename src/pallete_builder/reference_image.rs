@@ -0,0 +1,154 @@
+use std::error::Error;
+
+use sfml::{
+    graphics::{Color, FloatRect, RenderTarget, RenderWindow, Sprite, Texture, Transformable},
+    system::{Vector2, Vector2f, Vector2u},
+    SfBox,
+};
+
+use super::color_grid::{load_save::load_reference_texture, CELL_SIZE};
+
+pub const MIN_REFERENCE_SCALE: f32 = 1.;
+pub const MAX_REFERENCE_SCALE: f32 = 64.;
+
+/// What is saved alongside a palette file about its reference image.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReferenceImageState {
+    /// Name of a png in the files folder
+    pub file_name: String,
+    /// Top left corner, in world coordinates
+    pub position: Vector2f,
+    /// World units per pixel of the image
+    pub scale: f32,
+    /// From 0, invisible, to 1
+    pub opacity: f32,
+}
+
+impl ReferenceImageState {
+    /// One pixel of the image per cell, just right of a grid of `color_grid_world_size`
+    pub fn beside_color_grid(file_name: &str, color_grid_world_size: Vector2f) -> Self {
+        Self {
+            file_name: file_name.to_string(),
+            position: Vector2::new(color_grid_world_size.x + CELL_SIZE.x as f32, 0.),
+            scale: CELL_SIZE.x as f32,
+            opacity: 1.,
+        }
+    }
+}
+
+/// A png drawn beside the color grid, to design the palette against the sprite it is for.
+///
+/// It lives in world space, so it pans and zooms along with the grid.
+#[derive(Debug)]
+pub struct ReferenceImage {
+    state: ReferenceImageState,
+    texture: SfBox<Texture>,
+}
+
+impl ReferenceImage {
+    pub fn load(state: ReferenceImageState) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            texture: load_reference_texture(&state.file_name)?,
+            state,
+        })
+    }
+
+    pub fn state(&self) -> &ReferenceImageState {
+        &self.state
+    }
+
+    pub fn world_bounds(&self) -> FloatRect {
+        FloatRect::from_vecs(
+            self.state.position,
+            self.texture.size().as_other::<f32>() * self.state.scale,
+        )
+    }
+
+    pub fn move_by(&mut self, offset: Vector2f) {
+        self.state.position += offset;
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.state.scale = scale.clamp(MIN_REFERENCE_SCALE, MAX_REFERENCE_SCALE);
+    }
+
+    pub fn set_opacity(&mut self, opacity: f32) {
+        self.state.opacity = opacity.clamp(0., 1.);
+    }
+
+    /// Color of the pixel at `world_position`. None outside of the image, or on a fully
+    /// transparent pixel
+    pub fn color_at(&self, world_position: Vector2f) -> Option<Color> {
+        let pixel = pixel_at_world_position(&self.state, self.texture.size(), world_position)?;
+        let image = self.texture.copy_to_image()?;
+        let idx = (pixel.y as usize * image.size().x as usize + pixel.x as usize) * 4;
+        let rgba = image.pixel_data().get(idx..idx + 4)?;
+        if rgba[3] == 0 {
+            return None;
+        }
+
+        Some(Color::rgb(rgba[0], rgba[1], rgba[2]))
+    }
+
+    /// Expects the window to be using the grid view
+    pub fn render(&self, window: &mut RenderWindow) {
+        let mut sprite = Sprite::with_texture(&self.texture);
+        sprite.set_position(self.state.position);
+        sprite.set_scale(Vector2::new(self.state.scale, self.state.scale));
+        sprite.set_color(Color::rgba(
+            255,
+            255,
+            255,
+            (self.state.opacity * 255.).round() as u8,
+        ));
+        window.draw(&sprite);
+    }
+}
+
+/// Which pixel of an image of `image_size` is at `world_position`
+fn pixel_at_world_position(
+    state: &ReferenceImageState,
+    image_size: Vector2u,
+    world_position: Vector2f,
+) -> Option<Vector2u> {
+    let pixel = (world_position - state.position) / state.scale;
+    if pixel.x < 0. || pixel.y < 0. {
+        return None;
+    }
+
+    let pixel = Vector2::new(pixel.x as u32, pixel.y as u32);
+    (pixel.x < image_size.x && pixel.y < image_size.y).then_some(pixel)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pixel_at_world_position() {
+        let state = ReferenceImageState {
+            file_name: String::new(),
+            position: Vector2::new(100., 50.),
+            scale: 10.,
+            opacity: 1.,
+        };
+        let image_size = Vector2::new(4, 2);
+
+        assert_eq!(
+            pixel_at_world_position(&state, image_size, Vector2::new(100., 50.)),
+            Some(Vector2::new(0, 0))
+        );
+        assert_eq!(
+            pixel_at_world_position(&state, image_size, Vector2::new(139.9, 69.9)),
+            Some(Vector2::new(3, 1))
+        );
+        assert_eq!(
+            pixel_at_world_position(&state, image_size, Vector2::new(140., 60.)),
+            None
+        );
+        assert_eq!(
+            pixel_at_world_position(&state, image_size, Vector2::new(99., 60.)),
+            None
+        );
+    }
+}
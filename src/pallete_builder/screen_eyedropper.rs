@@ -0,0 +1,182 @@
+use sfml::{
+    graphics::{
+        Color, FloatRect, Image, IntRect, RectangleShape, RenderTarget, RenderWindow, Shape,
+        Sprite, Texture, Transformable, View,
+    },
+    system::{Vector2, Vector2f, Vector2i, Vector2u},
+    window::{mouse::Button, Event},
+    SfBox,
+};
+use tracing::error;
+use ui::ui_settings::controls::{possible_binds::PossibleBinds, Bindings};
+
+/// Pixels of the window shown in the loupe, across. Odd so the sampled pixel is the center one
+const LOUPE_PIXELS: i32 = 11;
+const LOUPE_ZOOM: f32 = 8.;
+/// Space between the cursor and the loupe
+const LOUPE_OFFSET: f32 = 20.;
+const LOUPE_OUTLINE_COLOR: Color = Color::rgb(0xf7, 0xe5, 0xe4);
+const LOUPE_OUTLINE_THICKNESS: f32 = 2.;
+const SAMPLED_PIXEL_OUTLINE_COLOR: Color = Color::rgb(255, 214, 10);
+
+/// Eyedropper that samples any pixel of the window, instead of only filled cells. Shows a
+/// loupe of the pixels around the cursor while it is on.
+///
+/// The window is captured in [`ScreenEyedropper::render`], once everything else has been
+/// drawn. A clicked pixel is polled with [`ScreenEyedropper::take_sampled_color`].
+#[derive(Debug, Default)]
+pub struct ScreenEyedropper {
+    is_active: bool,
+    capture: Option<SfBox<Texture>>,
+    is_sample_requested: bool,
+    sampled_color: Option<Color>,
+}
+
+impl ScreenEyedropper {
+    /// The shortcut turns the eyedropper on and off. While it is on, it takes every input:
+    /// clicking samples the pixel under the cursor, escape or the right mouse button cancel.
+    ///
+    /// Returns true if the event was used
+    pub fn event_handler(&mut self, event: &Event, binds: &Bindings) -> bool {
+        if binds.is_shortcut_pressed(*event, PossibleBinds::ScreenEyedrop) {
+            self.is_active = !self.is_active;
+            return true;
+        }
+        if !self.is_active {
+            return false;
+        }
+
+        match *event {
+            Event::Resized { .. } | Event::Closed => return false,
+            Event::MouseButtonPressed {
+                button: Button::Left,
+                ..
+            } => self.is_sample_requested = true,
+            Event::MouseButtonPressed {
+                button: Button::Right,
+                ..
+            } => self.is_active = false,
+            _ if binds.is_bind_just_pressed(PossibleBinds::Escape) => self.is_active = false,
+            _ => {}
+        }
+
+        true
+    }
+
+    pub fn take_sampled_color(&mut self) -> Option<Color> {
+        self.sampled_color.take()
+    }
+
+    /// Expects to be rendered last
+    pub fn render(&mut self, window: &mut RenderWindow) {
+        if !self.is_active {
+            return;
+        }
+
+        let cursor_position = window.mouse_position();
+        self.capture_window(window);
+        let Some(capture) = &self.capture else {
+            return;
+        };
+        if std::mem::take(&mut self.is_sample_requested) {
+            self.sampled_color = pixel_at(capture, cursor_position);
+            self.is_active = false;
+            return;
+        }
+
+        render_loupe(window, capture, cursor_position);
+    }
+
+    /// Copies what has been drawn to the window so far
+    fn capture_window(&mut self, window: &RenderWindow) {
+        let window_size = window.size();
+        if self.capture.as_ref().map(|capture| capture.size()) != Some(window_size) {
+            self.capture = blank_texture(window_size);
+        }
+        if let Some(capture) = &mut self.capture {
+            // SAFETY: The texture is the size of the window, so the window fits inside of it
+            unsafe { capture.update_from_render_window(window, 0, 0) };
+        }
+    }
+}
+
+fn blank_texture(size: Vector2u) -> Option<SfBox<Texture>> {
+    let pixels = vec![0; size.x as usize * size.y as usize * 4];
+    let Some(image) = (unsafe { Image::create_from_pixels(size.x, size.y, &pixels) }) else {
+        error!(
+            "Failed to create screen eyedropper image of size {:?}!",
+            size
+        );
+        return None;
+    };
+    match Texture::from_image(&image, IntRect::default()) {
+        Ok(texture) => Some(texture),
+        Err(err) => {
+            error!("Failed to create screen eyedropper texture! {}", err);
+            None
+        }
+    }
+}
+
+fn pixel_at(texture: &Texture, position: Vector2i) -> Option<Color> {
+    let image = texture.copy_to_image()?;
+    let size = image.size();
+    let (Ok(x), Ok(y)) = (u32::try_from(position.x), u32::try_from(position.y)) else {
+        return None;
+    };
+    if x >= size.x || y >= size.y {
+        return None;
+    }
+
+    let idx = (y as usize * size.x as usize + x as usize) * 4;
+    let rgba = image.pixel_data().get(idx..idx + 4)?;
+    Some(Color::rgb(rgba[0], rgba[1], rgba[2]))
+}
+
+/// Draws the pixels around the cursor, magnified. The loupe sits below and to the right of
+/// the cursor, unless that would put it off the window
+fn render_loupe(window: &mut RenderWindow, capture: &Texture, cursor_position: Vector2i) {
+    let window_size: Vector2f = window.size().as_other();
+    window.set_view(&View::from_rect(FloatRect::from_vecs(
+        Default::default(),
+        window_size,
+    )));
+
+    let loupe_size = LOUPE_PIXELS as f32 * LOUPE_ZOOM;
+    let cursor: Vector2f = cursor_position.as_other();
+    let mut position = cursor + Vector2::new(LOUPE_OFFSET, LOUPE_OFFSET);
+    if position.x + loupe_size > window_size.x {
+        position.x = cursor.x - LOUPE_OFFSET - loupe_size;
+    }
+    if position.y + loupe_size > window_size.y {
+        position.y = cursor.y - LOUPE_OFFSET - loupe_size;
+    }
+
+    let half = LOUPE_PIXELS / 2;
+    let mut loupe = Sprite::with_texture_and_rect(
+        capture,
+        IntRect::new(
+            cursor_position.x - half,
+            cursor_position.y - half,
+            LOUPE_PIXELS,
+            LOUPE_PIXELS,
+        ),
+    );
+    loupe.set_scale(Vector2::new(LOUPE_ZOOM, LOUPE_ZOOM));
+    loupe.set_position(position);
+    window.draw(&loupe);
+
+    let mut frame = RectangleShape::with_size(Vector2::new(loupe_size, loupe_size));
+    frame.set_position(position);
+    frame.set_fill_color(Color::TRANSPARENT);
+    frame.set_outline_color(LOUPE_OUTLINE_COLOR);
+    frame.set_outline_thickness(LOUPE_OUTLINE_THICKNESS);
+    window.draw(&frame);
+
+    let mut sampled_pixel = RectangleShape::with_size(Vector2::new(LOUPE_ZOOM, LOUPE_ZOOM));
+    sampled_pixel.set_position(position + Vector2::new(half as f32, half as f32) * LOUPE_ZOOM);
+    sampled_pixel.set_fill_color(Color::TRANSPARENT);
+    sampled_pixel.set_outline_color(SAMPLED_PIXEL_OUTLINE_COLOR);
+    sampled_pixel.set_outline_thickness(1.);
+    window.draw(&sampled_pixel);
+}
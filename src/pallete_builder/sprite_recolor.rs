@@ -0,0 +1,230 @@
+use std::{error::Error, path::Path};
+
+use sfml::{
+    graphics::Color,
+    system::{Vector2, Vector2u},
+};
+use utils::simple_error::SimpleError;
+
+use super::color_grid::{
+    load_save::{export_rgba_pixels, load_rgba_pixels},
+    ColorGrid,
+};
+
+/// Sprites with more colors than this are not limited color sprites, and mapping their
+/// colors one by one would be pointless
+pub const MAX_SOURCE_COLORS: usize = 256;
+
+/// A limited color sprite, and which cell of the color grid each of its colors is drawn
+/// with.
+///
+/// Colors that were not mapped by hand are drawn with the nearest filled cell.
+#[derive(Debug, Clone)]
+pub struct SpriteRecolor {
+    file_name: String,
+    size: Vector2u,
+    /// Index into `source_colors` of every pixel, row by row. None for transparent pixels
+    pixel_colors: Vec<Option<usize>>,
+    /// Alpha of every pixel, row by row
+    alphas: Vec<u8>,
+    /// Every distinct color of the sprite, in order of appearance
+    source_colors: Vec<Color>,
+    /// Cell each source color was mapped to by hand, parallel to `source_colors`
+    manual_mapping: Vec<Option<Vector2<usize>>>,
+}
+
+impl SpriteRecolor {
+    /// Loads a png from the files folder
+    pub fn load(file_name: &str) -> Result<Self, Box<dyn Error>> {
+        let (size, pixels) = load_rgba_pixels(file_name)?;
+        Self::from_rgba_pixels(file_name, size, &pixels)
+    }
+
+    fn from_rgba_pixels(
+        file_name: &str,
+        size: Vector2u,
+        pixels: &[u8],
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut source_colors: Vec<Color> = Vec::new();
+        let mut pixel_colors = Vec::with_capacity(pixels.len() / 4);
+        let mut alphas = Vec::with_capacity(pixels.len() / 4);
+        for rgba in pixels.chunks_exact(4) {
+            alphas.push(rgba[3]);
+            if rgba[3] == 0 {
+                pixel_colors.push(None);
+                continue;
+            }
+
+            let color = Color::rgb(rgba[0], rgba[1], rgba[2]);
+            let idx = match source_colors.iter().position(|&c| c == color) {
+                Some(idx) => idx,
+                None => {
+                    if source_colors.len() == MAX_SOURCE_COLORS {
+                        return Err(Box::new(SimpleError::new(format!(
+                            "{} has more than {} colors!",
+                            file_name, MAX_SOURCE_COLORS
+                        ))));
+                    }
+                    source_colors.push(color);
+                    source_colors.len() - 1
+                }
+            };
+            pixel_colors.push(Some(idx));
+        }
+
+        Ok(Self {
+            file_name: file_name.to_string(),
+            size,
+            pixel_colors,
+            alphas,
+            manual_mapping: vec![None; source_colors.len()],
+            source_colors,
+        })
+    }
+
+    pub fn size(&self) -> Vector2u {
+        self.size
+    }
+
+    /// Index of the source color of `pixel`. None outside of the sprite, or on a
+    /// transparent pixel
+    pub fn source_color_at(&self, pixel: Vector2u) -> Option<usize> {
+        if pixel.x >= self.size.x || pixel.y >= self.size.y {
+            return None;
+        }
+
+        *self
+            .pixel_colors
+            .get(pixel.y as usize * self.size.x as usize + pixel.x as usize)?
+    }
+
+    /// Draws the source color at `source_idx` with the color of `cell`, for as long as the
+    /// cell is filled
+    pub fn map_color(&mut self, source_idx: usize, cell: Vector2<usize>) {
+        if let Some(mapping) = self.manual_mapping.get_mut(source_idx) {
+            *mapping = Some(cell);
+        }
+    }
+
+    pub fn clear_mappings(&mut self) {
+        self.manual_mapping.fill(None);
+    }
+
+    /// The sprite drawn with the colors of `color_grid`, as row by row RGBA pixels
+    pub fn recolored_pixels(&self, color_grid: &ColorGrid) -> Vec<u8> {
+        let mut cells: Vec<_> = color_grid.iter().collect();
+        // Cells are stored unordered. Sorting keeps ties between nearest colors stable
+        cells.sort_by_key(|(idx, _)| (idx.y, idx.x));
+        let cell_colors: Vec<Color> = cells.into_iter().map(|(_, hsv)| Color::from(hsv)).collect();
+
+        let palette: Vec<Color> = self
+            .source_colors
+            .iter()
+            .zip(&self.manual_mapping)
+            .map(|(&source_color, mapping)| {
+                mapping
+                    .and_then(|cell| color_grid.get(cell))
+                    .map(Color::from)
+                    .or_else(|| nearest_color(source_color, &cell_colors))
+                    .unwrap_or(source_color)
+            })
+            .collect();
+
+        self.pixel_colors
+            .iter()
+            .zip(&self.alphas)
+            .flat_map(|(source_idx, &alpha)| match source_idx {
+                Some(source_idx) => {
+                    let color = palette[*source_idx];
+                    [color.r, color.g, color.b, alpha]
+                }
+                None => [0, 0, 0, 0],
+            })
+            .collect()
+    }
+
+    /// Saves the recolored sprite next to the original, as <name>_recolored.png. Returns the
+    /// name of the exported file
+    pub fn export(&self, color_grid: &ColorGrid) -> Result<String, Box<dyn Error>> {
+        let file_stem = Path::new(&self.file_name)
+            .file_stem()
+            .and_then(|file_stem| file_stem.to_str())
+            .unwrap_or(&self.file_name);
+        let export_file_name = format!("{}_recolored.png", file_stem);
+        export_rgba_pixels(
+            self.size,
+            &self.recolored_pixels(color_grid),
+            &export_file_name,
+        )?;
+
+        Ok(export_file_name)
+    }
+}
+
+/// Color of `candidates` closest to `color`, as a distance in rgb
+fn nearest_color(color: Color, candidates: &[Color]) -> Option<Color> {
+    let distance = |candidate: &&Color| {
+        let dr = i32::from(color.r) - i32::from(candidate.r);
+        let dg = i32::from(color.g) - i32::from(candidate.g);
+        let db = i32::from(color.b) - i32::from(candidate.b);
+        dr * dr + dg * dg + db * db
+    };
+    candidates.iter().min_by_key(distance).copied()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pallete_builder::{color_grid::undo_redo::UndoRedoCell, hsv_color::Hsv};
+
+    #[test]
+    fn test_recolored_pixels() {
+        #[rustfmt::skip]
+        let pixels = [
+            200, 10, 10, 255,   10, 10, 200, 255,
+            0, 0, 0, 0,         200, 10, 10, 128,
+        ];
+        let mut sprite_recolor =
+            SpriteRecolor::from_rgba_pixels("", Vector2::new(2, 2), &pixels).expect("unit-test");
+        assert_eq!(sprite_recolor.source_color_at(Vector2::new(1, 1)), Some(0));
+        assert_eq!(sprite_recolor.source_color_at(Vector2::new(0, 1)), None);
+
+        let mut color_grid = ColorGrid::default();
+        let undo_redo = &mut UndoRedoCell::default();
+        let red = Hsv::new(0, 255, 255);
+        let blue = Hsv::new(240, 255, 255);
+        color_grid.fill_cell(Vector2::new(0, 0), red, undo_redo);
+        color_grid.fill_cell(Vector2::new(1, 0), blue, undo_redo);
+
+        let (red, blue) = (Color::from(red), Color::from(blue));
+        assert_eq!(
+            sprite_recolor.recolored_pixels(&color_grid),
+            vec![
+                red.r, red.g, red.b, 255, blue.r, blue.g, blue.b, 255, 0, 0, 0, 0, red.r, red.g,
+                red.b, 128,
+            ]
+        );
+
+        // Red is now drawn blue, even though it is not the nearest color
+        sprite_recolor.map_color(0, Vector2::new(1, 0));
+        assert_eq!(
+            sprite_recolor.recolored_pixels(&color_grid)[..4],
+            [blue.r, blue.g, blue.b, 255]
+        );
+    }
+
+    #[test]
+    fn test_nearest_color() {
+        let candidates = [Color::rgb(0, 0, 0), Color::rgb(250, 250, 250)];
+
+        assert_eq!(
+            nearest_color(Color::rgb(200, 180, 220), &candidates),
+            Some(Color::rgb(250, 250, 250))
+        );
+        assert_eq!(
+            nearest_color(Color::rgb(40, 60, 20), &candidates),
+            Some(Color::rgb(0, 0, 0))
+        );
+        assert_eq!(nearest_color(Color::rgb(40, 60, 20), &[]), None);
+    }
+}
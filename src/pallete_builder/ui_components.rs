@@ -1,6 +1,22 @@
+pub mod color_swatches;
 pub mod config_selector;
 pub mod confirm_color_ramp;
 pub mod current_quick_save_file;
 pub mod erase_mode;
 pub mod hsv_selector;
+pub mod minimap;
+pub mod recolor_preview;
+pub mod reference_panel;
 pub mod settings;
+pub mod tab_strip;
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+    use ui::dom_loader::validation::assert_xml_layouts_are_valid;
+
+    #[test]
+    fn test_xml_layouts_are_valid() {
+        assert_xml_layouts_are_valid(Path::new(env!("CARGO_MANIFEST_DIR")).join("src"));
+    }
+}
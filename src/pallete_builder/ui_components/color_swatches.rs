@@ -0,0 +1,191 @@
+use sfml::{
+    graphics::{Color, RenderWindow},
+    window::Event as SFMLEvent,
+};
+use std::str::FromStr;
+use ui::{
+    dom_controller::{DomController, DomControllerInterface},
+    events::Event,
+    ui_settings::UISettings,
+    ui_state::UIState,
+    xml_layout,
+};
+use utils::resource_manager::ResourceManager;
+
+use crate::pallete_builder::hsv_color::Hsv;
+
+use self::color_swatches_content::{perform_events, sync_events, sync_pin_text};
+
+mod color_swatches_content;
+
+/// Number of swatches in each row of color_swatches_content.xml
+pub const SWATCHES_PER_ROW: usize = 8;
+
+/// Strip of the most recently used colors, and a row of favorite colors that is saved in the
+/// ui settings.
+///
+/// Colors are pushed in by the pallete builder. A clicked swatch is polled with
+/// [`ColorSwatches::take_color_to_pick`].
+#[derive(Debug)]
+pub struct ColorSwatches {
+    color_swatches_dom: DomController,
+    /// Most recent first
+    recent_colors: Vec<Hsv>,
+    /// Most recently pinned first
+    favorite_colors: Vec<Hsv>,
+    current_color: Hsv,
+    color_to_pick: Option<Hsv>,
+    pin_requested: bool,
+}
+
+impl ColorSwatches {
+    pub fn new(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
+        let mut favorite_colors: Vec<Hsv> = ui_settings
+            .favorite_colors
+            .iter()
+            .filter_map(|hex| Hsv::from_str(hex).ok())
+            .collect();
+        favorite_colors.truncate(SWATCHES_PER_ROW);
+
+        let mut color_swatches = Self {
+            color_swatches_dom: DomController::from_layout(
+                resource_manager,
+                ui_settings,
+                xml_layout!("color_swatches/color_swatches_content.xml"),
+            ),
+            recent_colors: Vec::new(),
+            favorite_colors,
+            current_color: Default::default(),
+            color_to_pick: None,
+            pin_requested: false,
+        };
+        color_swatches.sync();
+
+        color_swatches
+    }
+
+    /// Moves `hsv` to the front of the recent colors
+    pub fn push_recent_color(&mut self, hsv: Hsv) {
+        push_distinct_color(&mut self.recent_colors, hsv);
+        self.sync();
+    }
+
+    /// The color the pin button pins or unpins
+    pub fn set_current_color(&mut self, hsv: Hsv) {
+        if self.current_color == hsv {
+            return;
+        }
+
+        self.current_color = hsv;
+        sync_pin_text(&mut self.color_swatches_dom, self.is_current_color_pinned());
+    }
+
+    pub fn take_color_to_pick(&mut self) -> Option<Hsv> {
+        self.color_to_pick.take()
+    }
+
+    fn is_current_color_pinned(&self) -> bool {
+        position_of_color(&self.favorite_colors, self.current_color).is_some()
+    }
+
+    /// Pins the current color, or unpins it if it already is. Favorites are saved right away
+    fn toggle_current_color_pin(&mut self, ui_settings: &mut UISettings) {
+        match position_of_color(&self.favorite_colors, self.current_color) {
+            Some(idx) => {
+                self.favorite_colors.remove(idx);
+            }
+            None => push_distinct_color(&mut self.favorite_colors, self.current_color),
+        }
+        ui_settings.favorite_colors = self
+            .favorite_colors
+            .iter()
+            .map(|hsv| hsv.to_string())
+            .collect();
+        ui_settings.save_settings();
+        self.sync();
+    }
+
+    fn sync(&mut self) {
+        sync_events(
+            &mut self.color_swatches_dom,
+            &self.recent_colors,
+            &self.favorite_colors,
+        );
+        sync_pin_text(&mut self.color_swatches_dom, self.is_current_color_pinned());
+    }
+}
+
+/// Colors are compared as rgb, since greys with different hues look the same
+fn position_of_color(colors: &[Hsv], hsv: Hsv) -> Option<usize> {
+    colors
+        .iter()
+        .position(|&color| Color::from(color) == Color::from(hsv))
+}
+
+/// Puts `hsv` in front of `colors`, removing its older copy. The oldest color falls off
+/// once there is no swatch left for it
+fn push_distinct_color(colors: &mut Vec<Hsv>, hsv: Hsv) {
+    if let Some(idx) = position_of_color(colors, hsv) {
+        colors.remove(idx);
+    }
+    colors.insert(0, hsv);
+    colors.truncate(SWATCHES_PER_ROW);
+}
+
+impl DomControllerInterface for ColorSwatches {
+    fn event_handler(
+        &mut self,
+        window: &mut RenderWindow,
+        ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
+        event: SFMLEvent,
+    ) -> Vec<Event> {
+        let events = self
+            .color_swatches_dom
+            .event_handler(window, ui_settings, ui_state, event);
+        perform_events(&events, self);
+        if std::mem::take(&mut self.pin_requested) {
+            self.toggle_current_color_pin(ui_settings);
+        }
+        events
+    }
+
+    fn update(&mut self, resource_manager: &ResourceManager) -> Vec<Event> {
+        let events = self.color_swatches_dom.update(resource_manager);
+        if self.color_swatches_dom.take_reloaded() {
+            self.sync();
+        }
+        events
+    }
+
+    fn render(&mut self, window: &mut RenderWindow) {
+        self.color_swatches_dom.render(window);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_push_distinct_color() {
+        let red = Hsv::new(0, 255, 255);
+        let grey = Hsv::new(0, 0, 128);
+        let mut colors = vec![];
+
+        push_distinct_color(&mut colors, red);
+        push_distinct_color(&mut colors, grey);
+        // Same grey, but with a different hue
+        push_distinct_color(&mut colors, Hsv::new(120, 0, 128));
+        assert_eq!(colors, vec![Hsv::new(120, 0, 128), red]);
+
+        push_distinct_color(&mut colors, red);
+        assert_eq!(colors, vec![red, Hsv::new(120, 0, 128)]);
+
+        for v in 0..SWATCHES_PER_ROW as u8 {
+            push_distinct_color(&mut colors, Hsv::new(0, 0, v * 20));
+        }
+        assert_eq!(colors.len(), SWATCHES_PER_ROW);
+        assert_eq!(colors.last(), Some(&Hsv::new(0, 0, 0)));
+    }
+}
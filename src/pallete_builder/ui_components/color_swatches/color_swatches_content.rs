@@ -0,0 +1,91 @@
+use tracing::warn;
+use ui::{
+    dom_controller::DomController,
+    elements::traits::Element as ElementTrait,
+    events::{Event, Events},
+    syncs::Syncs,
+};
+
+use super::{ColorSwatches, SWATCHES_PER_ROW};
+use crate::pallete_builder::hsv_color::Hsv;
+
+const PIN_TEXT_SYNC_ID: u16 = 1;
+const RECENT_SWATCH_ID_OFFSET: u16 = 11;
+const FAVORITE_SWATCH_ID_OFFSET: u16 = 21;
+
+pub fn perform_events(events: &Vec<Event>, color_swatches: &mut ColorSwatches) {
+    for event in events {
+        perform_event(event, color_swatches);
+    }
+}
+
+fn perform_event(event: &Event, color_swatches: &mut ColorSwatches) {
+    match event.id {
+        0 => {}
+        1 => event1(color_swatches),
+        id if swatch_idx(id, RECENT_SWATCH_ID_OFFSET).is_some() => {
+            let hsv = swatch_idx(id, RECENT_SWATCH_ID_OFFSET)
+                .and_then(|swatch| color_swatches.recent_colors.get(swatch).copied());
+            pick_swatch_event(event, hsv, color_swatches)
+        }
+        id if swatch_idx(id, FAVORITE_SWATCH_ID_OFFSET).is_some() => {
+            let hsv = swatch_idx(id, FAVORITE_SWATCH_ID_OFFSET)
+                .and_then(|swatch| color_swatches.favorite_colors.get(swatch).copied());
+            pick_swatch_event(event, hsv, color_swatches)
+        }
+        _ => {
+            warn!("Event: {:#?} is not yet implemented", event)
+        }
+    }
+}
+
+/// Which swatch of the row starting at `offset` the id belongs to
+fn swatch_idx(id: u16, offset: u16) -> Option<usize> {
+    (offset..offset + SWATCHES_PER_ROW as u16)
+        .contains(&id)
+        .then(|| usize::from(id - offset))
+}
+
+/// Pin or unpin the current color
+fn event1(color_swatches: &mut ColorSwatches) {
+    color_swatches.pin_requested = true;
+}
+
+fn pick_swatch_event(event: &Event, hsv: Option<Hsv>, color_swatches: &mut ColorSwatches) {
+    // Empty swatches have nothing to pick
+    let (Events::BooleanEvent(true), Some(hsv)) = (&event.event, hsv) else {
+        return;
+    };
+    color_swatches.color_to_pick = Some(hsv);
+}
+
+pub fn sync_events(
+    dom_controller: &mut DomController,
+    recent_colors: &[Hsv],
+    favorite_colors: &[Hsv],
+) {
+    dom_controller.root_node.traverse_dom_mut(&mut |ele| {
+        let sync_id = ele.sync_id();
+        let color = if let Some(swatch) = swatch_idx(sync_id, RECENT_SWATCH_ID_OFFSET) {
+            recent_colors.get(swatch)
+        } else if let Some(swatch) = swatch_idx(sync_id, FAVORITE_SWATCH_ID_OFFSET) {
+            favorite_colors.get(swatch)
+        } else {
+            return;
+        };
+        ele.sync(Syncs::Color(color.map(|&hsv| hsv.into())));
+    });
+}
+
+pub fn sync_pin_text(dom_controller: &mut DomController, is_current_color_pinned: bool) {
+    let pin_text = if is_current_color_pinned {
+        "Unpin"
+    } else {
+        "Pin"
+    };
+    dom_controller.root_node.traverse_dom_mut(&mut |ele| {
+        if ele.sync_id() == PIN_TEXT_SYNC_ID {
+            ele.sync(Syncs::String(pin_text.to_string()));
+        }
+    });
+}
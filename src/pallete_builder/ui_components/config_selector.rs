@@ -3,9 +3,13 @@ use ui::{
     dom_controller::{DomController, DomControllerInterface},
     events::Event,
     ui_settings::UISettings,
+    ui_state::UIState,
+    xml_layout,
 };
 use utils::resource_manager::ResourceManager;
 
+use self::config_selector_content::EVENT_ROUTES;
+
 mod config_selector_content;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -37,11 +41,12 @@ pub struct ConfigSelector {
 
 impl ConfigSelector {
     pub fn new(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
-        let mut config_selector_dom = DomController::new(
+        let mut config_selector_dom = DomController::from_layout(
             resource_manager,
             ui_settings,
-            include_str!("config_selector/config_selector_content.xml"),
+            xml_layout!("config_selector/config_selector_content.xml"),
         );
+        EVENT_ROUTES.check(&config_selector_dom);
         let new_config = Default::default();
         config_selector_content::sync_events(&mut config_selector_dom, &new_config);
 
@@ -71,17 +76,25 @@ impl DomControllerInterface for ConfigSelector {
         &mut self,
         window: &mut RenderWindow,
         ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
         event: SFMLEvent,
     ) -> Vec<Event> {
         let events = self
             .config_selector_dom
-            .event_handler(window, ui_settings, event);
+            .event_handler(window, ui_settings, ui_state, event);
         config_selector_content::perform_events(&events, self);
         events
     }
 
     fn update(&mut self, resource_manager: &ResourceManager) -> Vec<Event> {
         let events = self.config_selector_dom.update(resource_manager);
+        if self.config_selector_dom.take_reloaded() {
+            EVENT_ROUTES.check(&self.config_selector_dom);
+            config_selector_content::sync_events(
+                &mut self.config_selector_dom,
+                &self.current_config,
+            );
+        }
         config_selector_content::perform_events(&events, self);
         events
     }
@@ -29,10 +29,10 @@ pub struct ConfirmColorRamp {
 
 impl ConfirmColorRamp {
     pub fn new(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
-        let mut confirm_color_ramp_dom = DomController::new(
+        let mut confirm_color_ramp_dom = DomController::from_layout(
             resource_manager,
             ui_settings,
-            include_str!("confirm_color_ramp/confirm_color_ramp_content.xml"),
+            xml_layout!("confirm_color_ramp/confirm_color_ramp_content.xml"),
         );
         confirm_color_ramp_content::sync_events(&mut confirm_color_ramp_dom, false);
 
@@ -67,6 +67,8 @@ use sfml::window::Event as SFMLEvent;
 use ui::dom_controller::{DomController, DomControllerInterface};
 use ui::events::Event;
 use ui::ui_settings::UISettings;
+use ui::ui_state::UIState;
+use ui::xml_layout;
 use utils::resource_manager::ResourceManager;
 
 impl DomControllerInterface for ConfirmColorRamp {
@@ -74,14 +76,15 @@ impl DomControllerInterface for ConfirmColorRamp {
         &mut self,
         window: &mut RenderWindow,
         ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
         event: SFMLEvent,
     ) -> Vec<Event> {
         if !matches!(event, SFMLEvent::Resized { .. }) && !self.enable {
             return Default::default();
         }
-        let events = self
-            .confirm_color_ramp_dom
-            .event_handler(window, ui_settings, event);
+        let events =
+            self.confirm_color_ramp_dom
+                .event_handler(window, ui_settings, ui_state, event);
         confirm_color_ramp_content::perform_events(
             &events,
             &mut self.enable,
@@ -95,7 +98,11 @@ impl DomControllerInterface for ConfirmColorRamp {
         if !self.enable {
             return Default::default();
         }
-        self.confirm_color_ramp_dom.update(resource_manager)
+        let events = self.confirm_color_ramp_dom.update(resource_manager);
+        if self.confirm_color_ramp_dom.take_reloaded() {
+            confirm_color_ramp_content::sync_events(&mut self.confirm_color_ramp_dom, self.enable);
+        }
+        events
     }
 
     fn render(&mut self, window: &mut RenderWindow) {
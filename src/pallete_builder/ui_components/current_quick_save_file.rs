@@ -1,6 +1,8 @@
 use ui::{
     dom_controller::{DomController, DomControllerInterface},
     ui_settings::UISettings,
+    ui_state::UIState,
+    xml_layout,
 };
 use utils::resource_manager::ResourceManager;
 
@@ -13,10 +15,10 @@ pub struct CurrentQuickSaveFile(DomController);
 
 impl CurrentQuickSaveFile {
     pub fn new(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
-        CurrentQuickSaveFile(DomController::new(
+        CurrentQuickSaveFile(DomController::from_layout(
             resource_manager,
             ui_settings,
-            include_str!("current_quick_save_file/current_quick_save_file_content.xml"),
+            xml_layout!("current_quick_save_file/current_quick_save_file_content.xml"),
         ))
     }
 
@@ -35,15 +37,22 @@ impl DomControllerInterface for CurrentQuickSaveFile {
     }
 
     fn update(&mut self, resource_manager: &ResourceManager) -> Vec<ui::events::Event> {
-        self.0.update(resource_manager)
+        // The file name is only kept in the dom, so it has to be read before a reload
+        let current_save_file = self.current_quick_save_file();
+        let events = self.0.update(resource_manager);
+        if self.0.take_reloaded() {
+            sync_events(&mut self.0, &current_save_file);
+        }
+        events
     }
 
     fn event_handler(
         &mut self,
         window: &mut sfml::graphics::RenderWindow,
         ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
         event: sfml::window::Event,
     ) -> Vec<ui::events::Event> {
-        self.0.event_handler(window, ui_settings, event)
+        self.0.event_handler(window, ui_settings, ui_state, event)
     }
 }
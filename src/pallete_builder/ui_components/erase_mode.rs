@@ -3,6 +3,8 @@ use ui::{
     dom_controller::{DomController, DomControllerInterface},
     events::Event,
     ui_settings::UISettings,
+    ui_state::UIState,
+    xml_layout,
 };
 use utils::resource_manager::ResourceManager;
 
@@ -20,10 +22,10 @@ impl EraseMode {
     pub fn new(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
         let mut em = Self {
             erase_disabled: true,
-            erase_mode_dom: DomController::new(
+            erase_mode_dom: DomController::from_layout(
                 resource_manager,
                 ui_settings,
-                include_str!("erase_mode/erase_mode_content.xml"),
+                xml_layout!("erase_mode/erase_mode_content.xml"),
             ),
         };
         sync_events(&mut em.erase_mode_dom, em.erase_disabled);
@@ -47,17 +49,22 @@ impl DomControllerInterface for EraseMode {
         &mut self,
         window: &mut RenderWindow,
         ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
         event: SFMLEvent,
     ) -> Vec<Event> {
         let events = self
             .erase_mode_dom
-            .event_handler(window, ui_settings, event);
+            .event_handler(window, ui_settings, ui_state, event);
         perform_events(&events, &mut self.erase_disabled);
         events
     }
 
     fn update(&mut self, resource_manager: &ResourceManager) -> Vec<Event> {
-        self.erase_mode_dom.update(resource_manager)
+        let events = self.erase_mode_dom.update(resource_manager);
+        if self.erase_mode_dom.take_reloaded() {
+            sync_events(&mut self.erase_mode_dom, self.erase_disabled);
+        }
+        events
     }
 
     fn render(&mut self, window: &mut RenderWindow) {
@@ -8,13 +8,15 @@ use ui::{
     dom_controller::{DomController, DomControllerInterface},
     events::Event,
     ui_settings::UISettings,
+    ui_state::UIState,
     utils::positioning::UIPosition,
+    xml_layout,
 };
 use utils::{quads::Quad, resource_manager::ResourceManager};
 
 use crate::pallete_builder::hsv_color::Hsv;
 
-use self::hsv_selector_content::{perform_events, sync_events};
+use self::hsv_selector_content::{perform_events, sync_events, EVENT_ROUTES};
 
 mod hsv_selector_content;
 
@@ -25,15 +27,19 @@ pub struct HSVSelector {
     current_color: Hsv,
     hex_string: String,
     current_aspect_ratio: Vector2i,
+    /// Set when the color changes. The color only settles once the input is let go of, so a
+    /// drag across the picker settles on one color
+    is_color_changing: bool,
+    settled_color: Option<Hsv>,
 }
 
 impl HSVSelector {
     pub fn new(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
         let mut hsis = Self {
-            hsi_selector_dom: DomController::new(
+            hsi_selector_dom: DomController::from_layout(
                 resource_manager,
                 ui_settings,
-                include_str!("hsv_selector/hsv_selector_content.xml"),
+                xml_layout!("hsv_selector/hsv_selector_content.xml"),
             ),
             current_color: Hsv {
                 h: 0,
@@ -44,7 +50,10 @@ impl HSVSelector {
             current_color_rect: Quad::from(FloatRect::new(0., 0., 32., 32.)),
             hex_string: Default::default(),
             current_aspect_ratio: ui_settings.aspect_ratio.computed_resolution().as_other(),
+            is_color_changing: false,
+            settled_color: None,
         };
+        EVENT_ROUTES.check(&hsis.hsi_selector_dom);
         sync_events(
             &mut hsis.hsi_selector_dom,
             hsis.current_color,
@@ -73,6 +82,7 @@ impl HSVSelector {
 
     pub fn set_hsv_color(&mut self, hsv: Hsv) {
         self.current_color = hsv;
+        self.settled_color = Some(hsv);
         self.color_current_color_rect();
 
         self.hex_string = hsv.to_string();
@@ -86,6 +96,11 @@ impl HSVSelector {
     pub fn curr_color(&self) -> Hsv {
         self.current_color
     }
+
+    /// The last color that was set or picked, if it has not been taken yet
+    pub fn take_settled_color(&mut self) -> Option<Hsv> {
+        self.settled_color.take()
+    }
 }
 
 impl DomControllerInterface for HSVSelector {
@@ -93,18 +108,27 @@ impl DomControllerInterface for HSVSelector {
         &mut self,
         window: &mut RenderWindow,
         ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
         event: SFMLEvent,
     ) -> Vec<Event> {
         self.current_aspect_ratio = ui_settings.aspect_ratio.computed_resolution().as_other();
         let events = self
             .hsi_selector_dom
-            .event_handler(window, ui_settings, event);
-        perform_events(
-            &events,
-            &mut self.hsi_selector_dom,
-            &mut self.current_color,
-            &mut self.hex_string,
-        );
+            .event_handler(window, ui_settings, ui_state, event);
+        let previous_color = self.current_color;
+        perform_events(&events, self);
+        self.is_color_changing |= previous_color != self.current_color;
+        if self.is_color_changing
+            && matches!(
+                event,
+                SFMLEvent::MouseButtonReleased { .. }
+                    | SFMLEvent::KeyReleased { .. }
+                    | SFMLEvent::JoystickButtonReleased { .. }
+            )
+        {
+            self.is_color_changing = false;
+            self.settled_color = Some(self.current_color);
+        }
         self.color_current_color_rect();
         self.display_current_color = true;
         if events.is_empty() {
@@ -114,7 +138,16 @@ impl DomControllerInterface for HSVSelector {
     }
 
     fn update(&mut self, resource_manager: &ResourceManager) -> Vec<Event> {
-        self.hsi_selector_dom.update(resource_manager)
+        let events = self.hsi_selector_dom.update(resource_manager);
+        if self.hsi_selector_dom.take_reloaded() {
+            EVENT_ROUTES.check(&self.hsi_selector_dom);
+            sync_events(
+                &mut self.hsi_selector_dom,
+                self.current_color,
+                &self.hex_string,
+            );
+        }
+        events
     }
 
     fn render(&mut self, window: &mut RenderWindow) {
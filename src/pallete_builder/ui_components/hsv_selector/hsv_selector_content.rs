@@ -1,109 +1,119 @@
 use std::str::FromStr;
 
-use sfml::system::Vector2;
-use tracing::{error, warn};
+use sfml::{graphics::Color, system::Vector2};
+use tracing::error;
 use ui::{
     dom_controller::DomController,
     elements::{
         slider::quad_color_picker::QuadColorPickerSync, textbox::traits::TextBoxTriggeredEvent,
         traits::Element as ElementTrait, Element,
     },
-    events::{Event, Events},
+    events::{
+        event_routes::{EventHandler, EventRoutes},
+        Event, Events,
+    },
     syncs::Syncs,
 };
 use utils::sfml_util_functions::try_from_color_hash_string_to_sfml_color;
 
 use crate::pallete_builder::hsv_color::Hsv;
 
-pub fn perform_events(
-    events: &Vec<Event>,
-    dom_controller: &mut DomController,
-    hsv: &mut Hsv,
-    hex_str: &mut String,
-) {
-    for event in events {
-        perform_event(event, dom_controller, hsv, hex_str);
-    }
-}
+use super::HSVSelector;
 
-fn perform_event(
-    event: &Event,
-    dom_controller: &mut DomController,
-    hsv: &mut Hsv,
-    hex_str: &mut String,
-) {
-    match event.id {
-        0 => {}
-        1 => event1(event, dom_controller, hsv, hex_str),
-        2 => event2(event, dom_controller, hsv, hex_str),
-        3 => event3(event, dom_controller, hsv, hex_str),
-        _ => {
-            warn!("Event: {:#?} is not yet implemented", event)
-        }
+pub const EVENT_ROUTES: EventRoutes<EventHandler<HSVSelector>> = EventRoutes(&[
+    ("saturation_value", saturation_value_picked),
+    ("hue", hue_picked),
+    ("hex", hex_typed),
+    ("h_field", h_field_changed),
+    ("s_field", s_field_changed),
+    ("v_field", v_field_changed),
+    ("r_field", r_field_changed),
+    ("g_field", g_field_changed),
+    ("b_field", b_field_changed),
+]);
+
+pub fn perform_events(events: &[Event], hsv_selector: &mut HSVSelector) {
+    for (handler, event) in EVENT_ROUTES.resolve(&hsv_selector.hsi_selector_dom, events) {
+        handler(event, hsv_selector);
     }
 }
 
-fn event1(event: &Event, dom_controller: &mut DomController, hsv: &mut Hsv, hex_str: &mut String) {
+fn saturation_value_picked(event: &Event, hsv_selector: &mut HSVSelector) {
     let Events::Vector2fEvent(sat_val) = event.event else {
-        error!("event1: Event is not a Vector2fEvent");
+        error!("saturation_value_picked: Event is not a Vector2fEvent");
         return;
     };
 
-    dom_controller.root_node.traverse_dom_mut(&mut |ele| {
-        if let 1 = ele.sync_id() {
-            if let Element::Slider(ele) = ele {
-                let slider_size = ele.max_slider_value() - ele.min_slider_value();
-                hsv.s = ((sat_val.x / slider_size.x) * 255f32) as u8;
-                hsv.v = 255u8 - ((sat_val.y / slider_size.y) * 255f32) as u8;
-            }
-        }
-    });
-    *hex_str = hsv.to_string();
-    sync_events(dom_controller, *hsv, hex_str)
+    let hsv = &mut hsv_selector.current_color;
+    if let Some(Element::Slider(ele)) = hsv_selector
+        .hsi_selector_dom
+        .get_element_by_id("saturation_value")
+    {
+        let slider_size = ele.max_slider_value() - ele.min_slider_value();
+        hsv.s = ((sat_val.x / slider_size.x) * 255f32) as u8;
+        hsv.v = 255u8 - ((sat_val.y / slider_size.y) * 255f32) as u8;
+    }
+    hsv_selector.hex_string = hsv.to_string();
+    sync_events(
+        &mut hsv_selector.hsi_selector_dom,
+        hsv_selector.current_color,
+        &hsv_selector.hex_string,
+    )
 }
 
-fn event2(event: &Event, dom_controller: &mut DomController, hsv: &mut Hsv, hex_str: &mut String) {
+fn hue_picked(event: &Event, hsv_selector: &mut HSVSelector) {
     let Events::NumericalEvent(hue_event) = event.event else {
-        error!("event2: Event is not a NumericalEvent");
+        error!("hue_picked: Event is not a NumericalEvent");
         return;
     };
-    hsv.h = hue_event as i16;
-    *hex_str = hsv.to_string();
-    sync_events(dom_controller, *hsv, hex_str)
+    hsv_selector.current_color.h = hue_event as i16;
+    hsv_selector.hex_string = hsv_selector.current_color.to_string();
+    sync_events(
+        &mut hsv_selector.hsi_selector_dom,
+        hsv_selector.current_color,
+        &hsv_selector.hex_string,
+    )
 }
 
-fn event3(event: &Event, dom_controller: &mut DomController, hsv: &mut Hsv, hex_str: &mut String) {
+fn hex_typed(event: &Event, hsv_selector: &mut HSVSelector) {
     let Events::TextBoxEvent(text_box_event) = &event.event else {
-        error!("event3: Event is not a StringEvent!");
+        error!("hex_typed: Event is not a StringEvent!");
         return;
     };
 
     fn set_hex_string_back_to_valid_state(
         text_box_event: &TextBoxTriggeredEvent,
-        dom_controller: &mut DomController,
-        hsv: Hsv,
-        hex_str: &mut String,
+        hsv_selector: &mut HSVSelector,
     ) {
         if text_box_event.selected {
             return;
         }
-        *hex_str = hsv.to_string();
+        hsv_selector.hex_string = hsv_selector.current_color.to_string();
 
-        sync_events(dom_controller, hsv, hex_str)
+        sync_events(
+            &mut hsv_selector.hsi_selector_dom,
+            hsv_selector.current_color,
+            &hsv_selector.hex_string,
+        )
     }
 
     let Ok(rgb) = try_from_color_hash_string_to_sfml_color(&text_box_event.string) else {
-        set_hex_string_back_to_valid_state(text_box_event, dom_controller, *hsv, hex_str);
+        set_hex_string_back_to_valid_state(text_box_event, hsv_selector);
         return;
     };
     if rgb.a != 0xff {
-        set_hex_string_back_to_valid_state(text_box_event, dom_controller, *hsv, hex_str);
+        set_hex_string_back_to_valid_state(text_box_event, hsv_selector);
         return;
     }
+    let hex_str = &mut hsv_selector.hex_string;
     *hex_str = text_box_event.string.clone();
     if !hex_str.starts_with('#') {
         *hex_str = format!("#{}", hex_str);
-        sync_events(dom_controller, *hsv, hex_str);
+        sync_events(
+            &mut hsv_selector.hsi_selector_dom,
+            hsv_selector.current_color,
+            hex_str,
+        );
     }
     let hsv_from_str = match Hsv::from_str(hex_str) {
         Ok(hsv) => hsv,
@@ -115,8 +125,84 @@ fn event3(event: &Event, dom_controller: &mut DomController, hsv: &mut Hsv, hex_
             return;
         }
     };
-    *hsv = hsv_from_str;
-    sync_events_specific_sync(dom_controller, *hsv, hex_str, true, true, false)
+    hsv_selector.current_color = hsv_from_str;
+    sync_events_specific_sync(
+        &mut hsv_selector.hsi_selector_dom,
+        hsv_selector.current_color,
+        hex_str,
+        true,
+        true,
+        false,
+    )
+}
+
+fn h_field_changed(event: &Event, hsv_selector: &mut HSVSelector) {
+    hsv_field_event(event, hsv_selector, |hsv, value| hsv.h = value as i16)
+}
+fn s_field_changed(event: &Event, hsv_selector: &mut HSVSelector) {
+    hsv_field_event(event, hsv_selector, |hsv, value| hsv.s = value as u8)
+}
+fn v_field_changed(event: &Event, hsv_selector: &mut HSVSelector) {
+    hsv_field_event(event, hsv_selector, |hsv, value| hsv.v = value as u8)
+}
+fn r_field_changed(event: &Event, hsv_selector: &mut HSVSelector) {
+    rgb_field_event(event, hsv_selector, |rgb, value| rgb.r = value)
+}
+fn g_field_changed(event: &Event, hsv_selector: &mut HSVSelector) {
+    rgb_field_event(event, hsv_selector, |rgb, value| rgb.g = value)
+}
+fn b_field_changed(event: &Event, hsv_selector: &mut HSVSelector) {
+    rgb_field_event(event, hsv_selector, |rgb, value| rgb.b = value)
+}
+
+/// H, S or V was typed in, or stepped with the arrows
+fn hsv_field_event(event: &Event, hsv_selector: &mut HSVSelector, set: fn(&mut Hsv, f32)) {
+    let Events::NumericalEvent(value) = event.event else {
+        error!("hsv_field_event: Event is not a NumericalEvent");
+        return;
+    };
+
+    set(&mut hsv_selector.current_color, value.round());
+    hsv_selector.hex_string = hsv_selector.current_color.to_string();
+    sync_events(
+        &mut hsv_selector.hsi_selector_dom,
+        hsv_selector.current_color,
+        &hsv_selector.hex_string,
+    )
+}
+
+/// R, G or B was typed in, or stepped with the arrows
+fn rgb_field_event(event: &Event, hsv_selector: &mut HSVSelector, set: fn(&mut Color, u8)) {
+    let Events::NumericalEvent(value) = event.event else {
+        error!("rgb_field_event: Event is not a NumericalEvent");
+        return;
+    };
+
+    let hsv = &mut hsv_selector.current_color;
+    let mut rgb = Color::from(*hsv);
+    set(&mut rgb, value.round() as u8);
+    let mut new_hsv = Hsv::from(rgb);
+    // Greys have no hue, so keep the one the user had picked
+    if new_hsv.s == 0 {
+        new_hsv.h = hsv.h;
+    }
+    *hsv = new_hsv;
+    hsv_selector.hex_string = hsv.to_string();
+    sync_events(
+        &mut hsv_selector.hsi_selector_dom,
+        hsv_selector.current_color,
+        &hsv_selector.hex_string,
+    );
+    // Going through HSV can be off by one, which would stop the arrows from stepping
+    sync_rgb_fields(&mut hsv_selector.hsi_selector_dom, rgb);
+}
+
+fn sync_rgb_fields(dom_controller: &mut DomController, rgb: Color) {
+    for (id, value) in [("r_field", rgb.r), ("g_field", rgb.g), ("b_field", rgb.b)] {
+        if let Some(ele) = dom_controller.get_element_by_id(id) {
+            ele.sync(Syncs::Numerical(value.into()));
+        }
+    }
 }
 
 pub fn sync_events(dom_controller: &mut DomController, hsv: Hsv, hex_str: &str) {
@@ -127,55 +213,64 @@ fn sync_events_specific_sync(
     dom_controller: &mut DomController,
     hsv: Hsv,
     hex_str: &str,
-    one: bool,
-    two: bool,
-    three: bool,
+    saturation_value: bool,
+    hue: bool,
+    hex: bool,
 ) {
-    dom_controller
-        .root_node
-        .traverse_dom_mut(&mut |ele| match ele.sync_id() {
-            0 => {}
-            1 if one => {
-                let full_bright_hsv = Hsv::new(hsv.h, u8::MAX, u8::MAX);
-                ele.sync(Syncs::QuadColorPicker(QuadColorPickerSync {
-                    top_right_color: Some(full_bright_hsv.into()),
-                    bottom_right_color: Some(full_bright_hsv.into()),
-                    hover_element_position_percentage: Some(Vector2::new(
-                        (f32::from(hsv.s) / 255. * 65535.) as u16,
-                        (65535. - f32::from(hsv.v) / 255. * 65535.) as u16,
-                    )),
-                    ..Default::default()
-                }));
-            }
-            2 if two => {
-                ele.sync(Syncs::Numerical(hsv.h.into()));
-            }
-            3 => {
-                let Element::TextBox(text_box) = ele else {
-                    error!("element is not a textbox!");
-                    return;
-                };
-                if three && !text_box.is_selected() {
-                    text_box.sync(Syncs::String(hex_str.to_owned()));
-                    for _ in 0..hex_str.len() {
-                        text_box.move_cursor_left();
-                    }
-                }
-            }
-            4 => {
-                ele.sync(Syncs::QuadColorPicker(QuadColorPickerSync {
-                    hover_element_position_percentage: Some(Vector2::new(
-                        (f32::from(hsv.s) / 255. * 65535.) as u16,
-                        (65535. - f32::from(hsv.v) / 255. * 65535.) as u16,
-                    )),
-                    ..Default::default()
-                }));
-            }
-            sync_id => {
-                warn!(
-                    "Synchronization with sync_id {} has not yet been implemented!",
-                    sync_id
-                );
+    let hover_element_position_percentage = Some(Vector2::new(
+        (f32::from(hsv.s) / 255. * 65535.) as u16,
+        (65535. - f32::from(hsv.v) / 255. * 65535.) as u16,
+    ));
+    if saturation_value {
+        if let Some(ele) = dom_controller.get_element_by_id("saturation_value") {
+            let full_bright_hsv = Hsv::new(hsv.h, u8::MAX, u8::MAX);
+            ele.sync(Syncs::QuadColorPicker(QuadColorPickerSync {
+                top_right_color: Some(full_bright_hsv.into()),
+                bottom_right_color: Some(full_bright_hsv.into()),
+                hover_element_position_percentage,
+                ..Default::default()
+            }));
+        }
+    }
+    if hue {
+        if let Some(ele) = dom_controller.get_element_by_id("hue") {
+            ele.sync(Syncs::Numerical(hsv.h.into()));
+        }
+    }
+    if let Some(Element::TextBox(text_box)) = dom_controller.get_element_by_id("hex") {
+        if hex && !text_box.is_selected() {
+            text_box.sync(Syncs::String(hex_str.to_owned()));
+            for _ in 0..hex_str.len() {
+                text_box.move_cursor_left();
             }
-        });
+        }
+    }
+    if let Some(ele) = dom_controller.get_element_by_id("value_shade") {
+        ele.sync(Syncs::QuadColorPicker(QuadColorPickerSync {
+            hover_element_position_percentage,
+            ..Default::default()
+        }));
+    }
+
+    let hsv_fields: [(&str, f32); 3] = [
+        ("h_field", hsv.h.rem_euclid(360).into()),
+        ("s_field", hsv.s.into()),
+        ("v_field", hsv.v.into()),
+    ];
+    for (id, value) in hsv_fields {
+        if let Some(ele) = dom_controller.get_element_by_id(id) {
+            ele.sync(Syncs::Numerical(value));
+        }
+    }
+    sync_rgb_fields(dom_controller, Color::from(hsv));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_event_routes_match_layout() {
+        EVENT_ROUTES.assert_matches_layout(include_str!("hsv_selector_content.xml"));
+    }
 }
@@ -0,0 +1,242 @@
+use sfml::{
+    graphics::{
+        Color, FloatRect, Image, IntRect, RectangleShape, RenderTarget, RenderWindow, Shape,
+        Sprite, Texture, Transformable, View,
+    },
+    system::{Vector2, Vector2f, Vector2i},
+    window::{mouse::Button, Event as SFMLEvent},
+    SfBox,
+};
+use tracing::error;
+use ui::{
+    dom_controller::{DomController, DomControllerInterface},
+    elements::{traits::Element as ElementTrait, Element},
+    events::{Event, Events},
+    ui_settings::UISettings,
+    ui_state::UIState,
+    xml_layout,
+};
+use utils::resource_manager::ResourceManager;
+
+use crate::pallete_builder::color_grid::ColorGrid;
+
+/// Space between the frame of the minimap and the grid drawn inside of it
+const MAP_PADDING: f32 = 8.;
+const VIEW_RECT_COLOR: Color = Color::rgb(0xf7, 0xe5, 0xe4);
+const VIEW_RECT_THICKNESS: f32 = 1.;
+
+/// Overview of the whole color grid, one pixel per cell.
+///
+/// Shows the area the main view is looking at. Clicking or dragging inside of it
+/// requests the main view to move, see [`Minimap::take_view_center`].
+#[derive(Debug)]
+pub struct Minimap {
+    minimap_dom: DomController,
+    ui_view: SfBox<View>,
+    grid_texture: Option<SfBox<Texture>>,
+    rendered_revision: Option<u64>,
+    grid_size: Vector2<usize>,
+    world_size: Vector2f,
+    view_rect: FloatRect,
+    is_dragging: bool,
+    view_center_to_apply: Option<Vector2f>,
+}
+
+impl Minimap {
+    pub fn new(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
+        Self {
+            minimap_dom: DomController::from_layout(
+                resource_manager,
+                ui_settings,
+                xml_layout!("minimap/minimap_content.xml"),
+            ),
+            ui_view: ui_view(ui_settings),
+            grid_texture: None,
+            rendered_revision: None,
+            grid_size: Default::default(),
+            world_size: Default::default(),
+            view_rect: Default::default(),
+            is_dragging: false,
+            view_center_to_apply: None,
+        }
+    }
+
+    /// Rebuilds the minimap texture, but only if a cell or the grid size has changed.
+    pub fn update_color_grid(&mut self, color_grid: &ColorGrid) {
+        if self.rendered_revision == Some(color_grid.revision()) {
+            return;
+        }
+        self.rendered_revision = Some(color_grid.revision());
+        self.grid_size = color_grid.size();
+        self.world_size = color_grid.world_size();
+
+        let (Ok(width), Ok(height)) = (
+            u32::try_from(self.grid_size.x),
+            u32::try_from(self.grid_size.y),
+        ) else {
+            error!(
+                "Grid size is too large for the minimap: {:?}",
+                self.grid_size
+            );
+            return;
+        };
+        let pixels = color_grid.to_rgba_pixels();
+        let Some(image) = (unsafe { Image::create_from_pixels(width, height, &pixels) }) else {
+            error!("Failed to create minimap image from pixels!");
+            return;
+        };
+        self.grid_texture = match Texture::from_image(&image, IntRect::default()) {
+            Ok(texture) => Some(texture),
+            Err(err) => {
+                error!("Failed to create minimap texture! {}", err);
+                None
+            }
+        };
+    }
+
+    /// Sets the area of the world the main view is looking at
+    pub fn set_view_rect(&mut self, view_rect: FloatRect) {
+        self.view_rect = view_rect;
+    }
+
+    /// World coordinate the main view was asked to center on, if any.
+    pub fn take_view_center(&mut self) -> Option<Vector2f> {
+        self.view_center_to_apply.take()
+    }
+
+    /// Where the grid is drawn, in ui coordinates. Keeps the aspect ratio of the grid
+    fn map_rect(&self) -> Option<FloatRect> {
+        let Element::RootNode(root_node) = &self.minimap_dom.root_node else {
+            return None;
+        };
+        let frame = root_node
+            .children()
+            .next()?
+            .global_bounds()
+            .as_other::<f32>();
+        let available_size = frame.size() - Vector2::new(MAP_PADDING, MAP_PADDING) * 2.;
+        if self.grid_size.x == 0 || self.grid_size.y == 0 {
+            return None;
+        }
+
+        let scale = (available_size.x / self.grid_size.x as f32)
+            .min(available_size.y / self.grid_size.y as f32);
+        let size = Vector2::new(
+            self.grid_size.x as f32 * scale,
+            self.grid_size.y as f32 * scale,
+        );
+        let position = frame.position() + (frame.size() - size) / 2.;
+
+        Some(FloatRect::from_vecs(position, size))
+    }
+
+    fn world_to_map(&self, map_rect: FloatRect, world_position: Vector2f) -> Vector2f {
+        map_rect.position()
+            + world_position
+                .cwise_div(self.world_size)
+                .cwise_mul(map_rect.size())
+    }
+
+    fn map_to_world(&self, map_rect: FloatRect, map_position: Vector2f) -> Vector2f {
+        (map_position - map_rect.position())
+            .cwise_div(map_rect.size())
+            .cwise_mul(self.world_size)
+    }
+
+    fn request_view_center(&mut self, cursor_position: Vector2i) -> Vec<Event> {
+        let Some(map_rect) = self.map_rect() else {
+            return vec![];
+        };
+        let world_position = self.map_to_world(map_rect, cursor_position.as_other());
+        self.view_center_to_apply = Some(world_position);
+
+        vec![Event::new(1, Events::Vector2fEvent(world_position))]
+    }
+}
+
+fn ui_view(ui_settings: &UISettings) -> SfBox<View> {
+    View::from_rect(FloatRect::from_vecs(
+        Default::default(),
+        ui_settings.aspect_ratio.computed_resolution(),
+    ))
+}
+
+impl DomControllerInterface for Minimap {
+    fn event_handler(
+        &mut self,
+        window: &mut RenderWindow,
+        ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
+        event: SFMLEvent,
+    ) -> Vec<Event> {
+        let mut events = self
+            .minimap_dom
+            .event_handler(window, ui_settings, ui_state, event);
+        let cursor_position = ui_settings.cursor_position;
+        let is_cursor_on_map = self
+            .map_rect()
+            .is_some_and(|map_rect| map_rect.contains(cursor_position.as_other()));
+
+        match event {
+            SFMLEvent::Resized { .. } => self.ui_view = ui_view(ui_settings),
+            SFMLEvent::MouseButtonPressed { button, .. }
+                if button == Button::Left && is_cursor_on_map =>
+            {
+                self.is_dragging = true;
+                events.append(&mut self.request_view_center(cursor_position));
+            }
+            SFMLEvent::MouseMoved { .. } if self.is_dragging && !Button::Left.is_pressed() => {
+                self.is_dragging = false;
+            }
+            SFMLEvent::MouseMoved { .. } if self.is_dragging => {
+                events.append(&mut self.request_view_center(cursor_position));
+            }
+            SFMLEvent::MouseButtonReleased { button, .. }
+                if button == Button::Left && self.is_dragging =>
+            {
+                self.is_dragging = false;
+                events.append(&mut self.request_view_center(cursor_position));
+            }
+            _ => {}
+        }
+
+        events
+    }
+
+    fn update(&mut self, resource_manager: &ResourceManager) -> Vec<Event> {
+        self.minimap_dom.update(resource_manager)
+    }
+
+    fn render(&mut self, window: &mut RenderWindow) {
+        self.minimap_dom.render(window);
+
+        let (Some(map_rect), Some(grid_texture)) = (self.map_rect(), &self.grid_texture) else {
+            return;
+        };
+        window.set_view(&self.ui_view);
+
+        let mut sprite = Sprite::with_texture(grid_texture);
+        sprite.set_position(map_rect.position());
+        sprite.set_scale(Vector2::new(
+            map_rect.width / self.grid_size.x as f32,
+            map_rect.height / self.grid_size.y as f32,
+        ));
+        window.draw(&sprite);
+
+        let view_rect = FloatRect::from_vecs(
+            self.world_to_map(map_rect, self.view_rect.position()),
+            self.view_rect
+                .size()
+                .cwise_div(self.world_size)
+                .cwise_mul(map_rect.size()),
+        );
+        let Some(view_rect) = view_rect.intersection(&map_rect) else {
+            return;
+        };
+        let mut view_rect_shape = RectangleShape::from_rect(view_rect);
+        view_rect_shape.set_fill_color(Color::TRANSPARENT);
+        view_rect_shape.set_outline_color(VIEW_RECT_COLOR);
+        view_rect_shape.set_outline_thickness(-VIEW_RECT_THICKNESS);
+        window.draw(&view_rect_shape);
+    }
+}
@@ -0,0 +1,340 @@
+use sfml::{
+    graphics::{
+        Color, FloatRect, Image, IntRect, RectangleShape, RenderTarget, RenderWindow, Shape,
+        Sprite, Texture, Transformable, View,
+    },
+    system::{Vector2, Vector2f, Vector2i},
+    window::{mouse::Button, Event as SFMLEvent},
+    SfBox,
+};
+use tracing::{error, info};
+use ui::{
+    dom_controller::{DomController, DomControllerInterface},
+    elements::{traits::Element as ElementTrait, Element},
+    events::{Event, EventId, Events},
+    ui_settings::UISettings,
+    ui_state::UIState,
+    xml_layout,
+};
+use utils::resource_manager::ResourceManager;
+
+use crate::pallete_builder::{color_grid::ColorGrid, sprite_recolor::SpriteRecolor};
+
+use self::recolor_preview_content::{perform_events, sync_events, EVENT_ROUTES};
+
+mod recolor_preview_content;
+
+/// Space taken by the text box, buttons and status text above the preview
+const PREVIEW_TOP: f32 = 92.;
+/// Space between the frame of the panel and the sprite drawn inside of it
+const PREVIEW_PADDING: f32 = 8.;
+const SELECTED_COLOR_OUTLINE_COLOR: Color = Color::rgb(255, 214, 10);
+/// Emitted when a color of the preview is clicked, so the click does not reach the grid
+const PREVIEW_CLICKED_EVENT_ID: EventId = 5;
+
+/// Shows a sprite from the files folder drawn with the colors of the pallete, and keeps it
+/// up to date as the pallete is edited.
+///
+/// Every color of the sprite is drawn with the nearest filled cell, unless it was mapped to
+/// a cell by hand: click a color of the preview, then the cell to draw it with. The cell is
+/// given by the pallete builder, see [`RecolorPreview::is_choosing_cell`].
+#[derive(Debug)]
+pub struct RecolorPreview {
+    recolor_preview_dom: DomController,
+    ui_view: SfBox<View>,
+    display: bool,
+    file_name: String,
+    sprite_recolor: Option<SpriteRecolor>,
+    recolored_texture: Option<SfBox<Texture>>,
+    rendered_revision: Option<u64>,
+    /// Set when the sprite or its mappings change, since the grid revision does not
+    is_stale: bool,
+    /// Source color waiting for the cell it is drawn with
+    source_color_to_map: Option<usize>,
+    export_requested: bool,
+}
+
+impl RecolorPreview {
+    pub fn new(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
+        let mut recolor_preview = Self {
+            recolor_preview_dom: DomController::from_layout(
+                resource_manager,
+                ui_settings,
+                xml_layout!("recolor_preview/recolor_preview_content.xml"),
+            ),
+            ui_view: ui_view(ui_settings),
+            display: false,
+            file_name: String::new(),
+            sprite_recolor: None,
+            recolored_texture: None,
+            rendered_revision: None,
+            is_stale: false,
+            source_color_to_map: None,
+            export_requested: false,
+        };
+        EVENT_ROUTES.check(&recolor_preview.recolor_preview_dom);
+        recolor_preview.sync();
+
+        recolor_preview
+    }
+
+    pub fn toggle_display(&mut self) {
+        self.display = !self.display;
+        self.source_color_to_map = None;
+        // The preview is not kept up to date while it is hidden
+        self.is_stale = true;
+        self.sync();
+    }
+
+    /// True once a color of the preview was clicked, until the cell it is drawn with is
+    /// given to [`RecolorPreview::map_selected_color`]
+    pub fn is_choosing_cell(&self) -> bool {
+        self.display && self.source_color_to_map.is_some()
+    }
+
+    pub fn map_selected_color(&mut self, cell: Vector2<usize>) {
+        let (Some(sprite_recolor), Some(source_idx)) =
+            (&mut self.sprite_recolor, self.source_color_to_map.take())
+        else {
+            return;
+        };
+
+        sprite_recolor.map_color(source_idx, cell);
+        self.is_stale = true;
+        self.sync();
+    }
+
+    /// Recolors the sprite, but only if a cell, the sprite or its mappings have changed.
+    /// Also exports the sprite, if it was asked to
+    pub fn update_color_grid(&mut self, color_grid: &ColorGrid) {
+        let Some(sprite_recolor) = &self.sprite_recolor else {
+            return;
+        };
+
+        if std::mem::take(&mut self.export_requested) {
+            match sprite_recolor.export(color_grid) {
+                Ok(export_file_name) => info!("Exported recolored sprite to {}", export_file_name),
+                Err(err) => error!("Failed to export recolored sprite! {:#?}", err),
+            }
+        }
+
+        if !self.display
+            || (!self.is_stale && self.rendered_revision == Some(color_grid.revision()))
+        {
+            return;
+        }
+        self.is_stale = false;
+        self.rendered_revision = Some(color_grid.revision());
+
+        let size = sprite_recolor.size();
+        let pixels = sprite_recolor.recolored_pixels(color_grid);
+        let Some(image) = (unsafe { Image::create_from_pixels(size.x, size.y, &pixels) }) else {
+            error!("Failed to create recolor preview image from pixels!");
+            return;
+        };
+        self.recolored_texture = match Texture::from_image(&image, IntRect::default()) {
+            Ok(texture) => Some(texture),
+            Err(err) => {
+                error!("Failed to create recolor preview texture! {}", err);
+                None
+            }
+        };
+    }
+
+    fn load_sprite(&mut self) {
+        self.source_color_to_map = None;
+        self.recolored_texture = None;
+        self.sprite_recolor = match SpriteRecolor::load(&self.file_name) {
+            Ok(sprite_recolor) => Some(sprite_recolor),
+            Err(err) => {
+                error!("Failed to load sprite to recolor! {:#?}", err);
+                None
+            }
+        };
+        self.is_stale = true;
+        self.sync();
+    }
+
+    fn reset_mappings(&mut self) {
+        let Some(sprite_recolor) = &mut self.sprite_recolor else {
+            return;
+        };
+
+        sprite_recolor.clear_mappings();
+        self.source_color_to_map = None;
+        self.is_stale = true;
+        self.sync();
+    }
+
+    fn sync(&mut self) {
+        let status = match (&self.sprite_recolor, self.source_color_to_map) {
+            (None, _) => "No sprite loaded",
+            (Some(_), None) => "Click a color to map it",
+            (Some(_), Some(_)) => "Click a cell for it",
+        };
+        sync_events(&mut self.recolor_preview_dom, &self.file_name, status);
+    }
+
+    /// Where the sprite is drawn, in ui coordinates. Keeps the aspect ratio of the sprite
+    fn preview_rect(&self) -> Option<FloatRect> {
+        let Element::RootNode(root_node) = &self.recolor_preview_dom.root_node else {
+            return None;
+        };
+        let frame = root_node
+            .children()
+            .next()?
+            .global_bounds()
+            .as_other::<f32>();
+        let sprite_size = self.sprite_recolor.as_ref()?.size();
+        if sprite_size.x == 0 || sprite_size.y == 0 {
+            return None;
+        }
+
+        let area = FloatRect::new(
+            frame.left + PREVIEW_PADDING,
+            frame.top + PREVIEW_TOP,
+            frame.width - PREVIEW_PADDING * 2.,
+            frame.height - PREVIEW_TOP - PREVIEW_PADDING,
+        );
+        let scale = (area.width / sprite_size.x as f32).min(area.height / sprite_size.y as f32);
+        let size = Vector2::new(sprite_size.x as f32 * scale, sprite_size.y as f32 * scale);
+        let position = area.position() + (area.size() - size) / 2.;
+
+        Some(FloatRect::from_vecs(position, size))
+    }
+
+    /// Selects the color of the sprite under the cursor. Clicking the selected color again
+    /// deselects it
+    fn select_source_color(&mut self, preview_rect: FloatRect, cursor_position: Vector2i) {
+        let Some(sprite_recolor) = &self.sprite_recolor else {
+            return;
+        };
+        let pixel = (cursor_position.as_other::<f32>() - preview_rect.position())
+            .cwise_div(preview_rect.size())
+            .cwise_mul(sprite_recolor.size().as_other());
+        let source_idx =
+            sprite_recolor.source_color_at(Vector2::new(pixel.x as u32, pixel.y as u32));
+
+        self.source_color_to_map = if source_idx == self.source_color_to_map {
+            None
+        } else {
+            source_idx
+        };
+        self.sync();
+    }
+
+    /// Outlines every pixel of the sprite with the selected source color
+    fn render_selected_color(&self, window: &mut RenderWindow, preview_rect: FloatRect) {
+        let (Some(sprite_recolor), Some(source_idx)) =
+            (&self.sprite_recolor, self.source_color_to_map)
+        else {
+            return;
+        };
+
+        let size = sprite_recolor.size();
+        let pixel_size = preview_rect.size().cwise_div(size.as_other());
+        let mut pixel_shape = RectangleShape::with_size(pixel_size);
+        pixel_shape.set_fill_color(Color::TRANSPARENT);
+        pixel_shape.set_outline_color(SELECTED_COLOR_OUTLINE_COLOR);
+        pixel_shape.set_outline_thickness(-1.);
+        for y in 0..size.y {
+            for x in 0..size.x {
+                if sprite_recolor.source_color_at(Vector2::new(x, y)) != Some(source_idx) {
+                    continue;
+                }
+                pixel_shape.set_position(
+                    preview_rect.position()
+                        + Vector2f::new(x as f32, y as f32).cwise_mul(pixel_size),
+                );
+                window.draw(&pixel_shape);
+            }
+        }
+    }
+}
+
+fn ui_view(ui_settings: &UISettings) -> SfBox<View> {
+    View::from_rect(FloatRect::from_vecs(
+        Default::default(),
+        ui_settings.aspect_ratio.computed_resolution(),
+    ))
+}
+
+impl DomControllerInterface for RecolorPreview {
+    fn event_handler(
+        &mut self,
+        window: &mut RenderWindow,
+        ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
+        event: SFMLEvent,
+    ) -> Vec<Event> {
+        if let SFMLEvent::Resized { .. } = event {
+            self.ui_view = ui_view(ui_settings);
+        }
+        if !self.display && !matches!(event, SFMLEvent::Resized { .. }) {
+            return Default::default();
+        }
+
+        let mut events =
+            self.recolor_preview_dom
+                .event_handler(window, ui_settings, ui_state, event);
+        perform_events(&events, self);
+
+        let cursor_position = ui_settings.cursor_position;
+        if let SFMLEvent::MouseButtonPressed {
+            button: Button::Left,
+            ..
+        } = event
+        {
+            if let Some(preview_rect) = self
+                .preview_rect()
+                .filter(|preview_rect| preview_rect.contains(cursor_position.as_other()))
+            {
+                self.select_source_color(preview_rect, cursor_position);
+                events.push(Event::new(
+                    PREVIEW_CLICKED_EVENT_ID,
+                    Events::Vector2fEvent(cursor_position.as_other()),
+                ));
+            }
+        }
+
+        events
+    }
+
+    fn update(&mut self, resource_manager: &ResourceManager) -> Vec<Event> {
+        if !self.display {
+            return Default::default();
+        }
+
+        let events = self.recolor_preview_dom.update(resource_manager);
+        if self.recolor_preview_dom.take_reloaded() {
+            EVENT_ROUTES.check(&self.recolor_preview_dom);
+            self.sync();
+        }
+        events
+    }
+
+    fn render(&mut self, window: &mut RenderWindow) {
+        if !self.display {
+            return;
+        }
+        self.recolor_preview_dom.render(window);
+
+        let (Some(preview_rect), Some(recolored_texture)) =
+            (self.preview_rect(), &self.recolored_texture)
+        else {
+            return;
+        };
+        window.set_view(&self.ui_view);
+
+        let texture_size = recolored_texture.size();
+        let mut sprite = Sprite::with_texture(recolored_texture);
+        sprite.set_position(preview_rect.position());
+        sprite.set_scale(Vector2::new(
+            preview_rect.width / texture_size.x as f32,
+            preview_rect.height / texture_size.y as f32,
+        ));
+        window.draw(&sprite);
+
+        self.render_selected_color(window, preview_rect);
+    }
+}
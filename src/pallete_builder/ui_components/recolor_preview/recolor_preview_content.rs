@@ -0,0 +1,70 @@
+use tracing::error;
+use ui::{
+    dom_controller::DomController,
+    elements::{traits::Element as ElementTrait, Element},
+    events::{
+        event_routes::{EventHandler, EventRoutes},
+        Event, Events,
+    },
+    syncs::Syncs,
+};
+
+use super::RecolorPreview;
+
+pub const EVENT_ROUTES: EventRoutes<EventHandler<RecolorPreview>> = EventRoutes(&[
+    ("file_name", file_name_typed),
+    ("load", load),
+    ("reset", reset),
+    ("export", export),
+]);
+
+pub fn perform_events(events: &[Event], recolor_preview: &mut RecolorPreview) {
+    for (handler, event) in EVENT_ROUTES.resolve(&recolor_preview.recolor_preview_dom, events) {
+        handler(event, recolor_preview);
+    }
+}
+
+fn file_name_typed(event: &Event, recolor_preview: &mut RecolorPreview) {
+    let Events::TextBoxEvent(text_box_event) = &event.event else {
+        error!("file_name_typed: Event is not a TextBoxEvent!");
+        return;
+    };
+
+    recolor_preview.file_name = text_box_event.string.clone();
+}
+
+fn load(_: &Event, recolor_preview: &mut RecolorPreview) {
+    if !recolor_preview.file_name.is_empty() {
+        recolor_preview.load_sprite();
+    }
+}
+
+/// Reset the colors mapped by hand
+fn reset(_: &Event, recolor_preview: &mut RecolorPreview) {
+    recolor_preview.reset_mappings();
+}
+
+fn export(_: &Event, recolor_preview: &mut RecolorPreview) {
+    recolor_preview.export_requested = recolor_preview.sprite_recolor.is_some();
+}
+
+pub fn sync_events(dom_controller: &mut DomController, file_name: &str, status: &str) {
+    if let Some(Element::TextBox(text_box)) = dom_controller.get_element_by_id("file_name") {
+        if !text_box.is_selected() {
+            text_box.sync(Syncs::String(file_name.to_owned()));
+        }
+    }
+    if let Some(ele) = dom_controller.get_element_by_id("status") {
+        ele.sync(Syncs::String(status.to_owned()));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_event_routes_match_layout() {
+        EVENT_ROUTES.assert_matches_layout(include_str!("recolor_preview_content.xml"));
+    }
+}
@@ -0,0 +1,113 @@
+use sfml::{graphics::RenderWindow, window::Event as SFMLEvent};
+use ui::{
+    dom_controller::{DomController, DomControllerInterface},
+    events::Event,
+    ui_settings::UISettings,
+    ui_state::UIState,
+    xml_layout,
+};
+use utils::resource_manager::ResourceManager;
+
+use crate::pallete_builder::reference_image::ReferenceImageState;
+
+use self::reference_panel_content::{perform_events, sync_events};
+
+mod reference_panel_content;
+
+/// Loads a png from the files folder as the reference image of the active pallete, and sets
+/// its opacity and scale.
+///
+/// Does not own the reference image. Requests are polled by the pallete builder, which then
+/// calls [`ReferencePanel::set_reference`] with the result.
+#[derive(Debug)]
+pub struct ReferencePanel {
+    reference_panel_dom: DomController,
+    file_name: String,
+    reference_image: Option<ReferenceImageState>,
+    file_to_load: Option<String>,
+    clear_requested: bool,
+    opacity_to_apply: Option<f32>,
+    scale_to_apply: Option<f32>,
+}
+
+impl ReferencePanel {
+    pub fn new(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
+        let mut reference_panel = Self {
+            reference_panel_dom: DomController::from_layout(
+                resource_manager,
+                ui_settings,
+                xml_layout!("reference_panel/reference_panel_content.xml"),
+            ),
+            file_name: String::new(),
+            reference_image: None,
+            file_to_load: None,
+            clear_requested: false,
+            opacity_to_apply: None,
+            scale_to_apply: None,
+        };
+        reference_panel.sync();
+
+        reference_panel
+    }
+
+    /// Shows the reference image of the active pallete
+    pub fn set_reference(&mut self, reference_image: Option<&ReferenceImageState>) {
+        self.file_name = reference_image
+            .map(|reference_image| reference_image.file_name.clone())
+            .unwrap_or_default();
+        self.reference_image = reference_image.cloned();
+        self.sync();
+    }
+
+    pub fn take_file_to_load(&mut self) -> Option<String> {
+        self.file_to_load.take()
+    }
+
+    pub fn take_clear_request(&mut self) -> bool {
+        std::mem::take(&mut self.clear_requested)
+    }
+
+    pub fn take_opacity(&mut self) -> Option<f32> {
+        self.opacity_to_apply.take()
+    }
+
+    pub fn take_scale(&mut self) -> Option<f32> {
+        self.scale_to_apply.take()
+    }
+
+    fn sync(&mut self) {
+        sync_events(
+            &mut self.reference_panel_dom,
+            &self.file_name,
+            self.reference_image.as_ref(),
+        );
+    }
+}
+
+impl DomControllerInterface for ReferencePanel {
+    fn event_handler(
+        &mut self,
+        window: &mut RenderWindow,
+        ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
+        event: SFMLEvent,
+    ) -> Vec<Event> {
+        let events = self
+            .reference_panel_dom
+            .event_handler(window, ui_settings, ui_state, event);
+        perform_events(&events, self);
+        events
+    }
+
+    fn update(&mut self, resource_manager: &ResourceManager) -> Vec<Event> {
+        let events = self.reference_panel_dom.update(resource_manager);
+        if self.reference_panel_dom.take_reloaded() {
+            self.sync();
+        }
+        events
+    }
+
+    fn render(&mut self, window: &mut RenderWindow) {
+        self.reference_panel_dom.render(window);
+    }
+}
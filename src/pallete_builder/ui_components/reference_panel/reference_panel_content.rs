@@ -0,0 +1,104 @@
+use tracing::{error, warn};
+use ui::{
+    dom_controller::DomController,
+    elements::{traits::Element as ElementTrait, Element},
+    events::{Event, Events},
+    syncs::Syncs,
+};
+
+use super::ReferencePanel;
+use crate::pallete_builder::{color_grid::CELL_SIZE, reference_image::ReferenceImageState};
+
+pub fn perform_events(events: &Vec<Event>, reference_panel: &mut ReferencePanel) {
+    for event in events {
+        perform_event(event, reference_panel);
+    }
+}
+
+fn perform_event(event: &Event, reference_panel: &mut ReferencePanel) {
+    match event.id {
+        0 => {}
+        1 => event1(event, reference_panel),
+        2 => event2(reference_panel),
+        3 => event3(reference_panel),
+        4 => event4(event, reference_panel),
+        5 => event5(event, reference_panel),
+        _ => {
+            warn!("Event: {:#?} is not yet implemented", event)
+        }
+    }
+}
+
+/// File name typed in
+fn event1(event: &Event, reference_panel: &mut ReferencePanel) {
+    let Events::TextBoxEvent(text_box_event) = &event.event else {
+        error!("event1: Event is not a TextBoxEvent!");
+        return;
+    };
+
+    reference_panel.file_name = text_box_event.string.clone();
+}
+
+/// Load
+fn event2(reference_panel: &mut ReferencePanel) {
+    if !reference_panel.file_name.is_empty() {
+        reference_panel.file_to_load = Some(reference_panel.file_name.clone());
+    }
+}
+
+/// Clear
+fn event3(reference_panel: &mut ReferencePanel) {
+    reference_panel.clear_requested = true;
+}
+
+/// Opacity, in percent
+fn event4(event: &Event, reference_panel: &mut ReferencePanel) {
+    let Events::NumericalEvent(opacity) = event.event else {
+        error!("event4: Event is not a NumericalEvent");
+        return;
+    };
+
+    reference_panel.opacity_to_apply = Some(opacity / 100.);
+}
+
+/// Scale, in world units per pixel
+fn event5(event: &Event, reference_panel: &mut ReferencePanel) {
+    let Events::NumericalEvent(scale) = event.event else {
+        error!("event5: Event is not a NumericalEvent");
+        return;
+    };
+
+    reference_panel.scale_to_apply = Some(scale);
+}
+
+pub fn sync_events(
+    dom_controller: &mut DomController,
+    file_name: &str,
+    reference_image: Option<&ReferenceImageState>,
+) {
+    let (opacity, scale) = reference_image.map_or((1., CELL_SIZE.x as f32), |reference_image| {
+        (reference_image.opacity, reference_image.scale)
+    });
+    dom_controller
+        .root_node
+        .traverse_dom_mut(&mut |ele| match ele.sync_id() {
+            0 => {}
+            1 => {
+                let Element::TextBox(text_box) = ele else {
+                    error!("element is not a textbox!");
+                    return;
+                };
+                if !text_box.is_selected() {
+                    text_box.sync(Syncs::String(file_name.to_owned()));
+                }
+            }
+            4 => ele.sync(Syncs::Numerical((opacity * 100.).round())),
+            5 => ele.sync(Syncs::Numerical(scale)),
+            sync_id => {
+                warn!(
+                    "Synchronization with sync_id {} has not yet been implemented!",
+                    sync_id
+                );
+            }
+        });
+}
@@ -1,13 +1,15 @@
-use sfml::{graphics::RenderWindow, window::Event as SFMLEvent};
+use sfml::{graphics::RenderWindow, system::Vector2, window::Event as SFMLEvent};
 use ui::{
     dom_controller::{DomController, DomControllerInterface},
     events::Event,
     ui_settings::UISettings,
+    ui_state::UIState,
+    xml_layout,
 };
 use utils::resource_manager::ResourceManager;
 
 use self::{
-    settings_content::{perform_events, sync_events},
+    settings_content::{perform_events, EVENT_ROUTES},
     settings_menu::SettingsMenu,
 };
 
@@ -22,12 +24,12 @@ pub struct Settings {
 
 impl Settings {
     pub fn new(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
-        let mut settings_dom = DomController::new(
+        let settings_dom = DomController::from_layout(
             resource_manager,
             ui_settings,
-            include_str!("settings/settings_content.xml"),
+            xml_layout!("settings/settings_content.xml"),
         );
-        sync_events(&mut settings_dom);
+        EVENT_ROUTES.check(&settings_dom);
         Self {
             settings_dom,
             settings_menu: SettingsMenu::new(resource_manager, ui_settings),
@@ -62,8 +64,12 @@ impl Settings {
         self.settings_menu.save_file()
     }
 
-    pub fn is_displayed(&self) -> bool {
-        self.settings_menu.display
+    pub fn set_notes(&mut self, notes: &str) {
+        self.settings_menu.set_notes(notes)
+    }
+
+    pub fn notes(&self) -> &str {
+        self.settings_menu.notes()
     }
 
     pub fn file_to_load(&self) -> Option<&str> {
@@ -74,8 +80,20 @@ impl Settings {
         self.settings_menu.clear_file_to_load()
     }
 
-    pub fn open_save_menu(&mut self, ui_settings: &UISettings) {
-        self.settings_menu.open_save_menu(ui_settings);
+    pub fn grid_size_to_apply(&self) -> Option<Vector2<usize>> {
+        self.settings_menu.grid_size_to_apply()
+    }
+
+    pub fn clear_grid_size_to_apply(&mut self) {
+        self.settings_menu.clear_grid_size_to_apply()
+    }
+
+    pub fn set_grid_size(&mut self, grid_size: Vector2<usize>) {
+        self.settings_menu.set_grid_size(grid_size)
+    }
+
+    pub fn open_save_menu(&mut self, ui_settings: &UISettings, ui_state: &mut UIState) {
+        self.settings_menu.open_save_menu(ui_settings, ui_state);
     }
 }
 
@@ -84,17 +102,22 @@ impl DomControllerInterface for Settings {
         &mut self,
         window: &mut RenderWindow,
         ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
         event: SFMLEvent,
     ) -> Vec<Event> {
-        let previous_display_state = self.settings_menu.display;
-        let mut events = self.settings_dom.event_handler(window, ui_settings, event);
-        let mut settings_menu_events = self.settings_menu.event_handler(window, ui_settings, event);
+        let mut events = self
+            .settings_dom
+            .event_handler(window, ui_settings, ui_state, event);
+        let mut settings_menu_events =
+            self.settings_menu
+                .event_handler(window, ui_settings, ui_state, event);
         perform_events(
             &events,
+            &self.settings_dom,
             window,
             ui_settings,
+            ui_state,
             &mut self.settings_menu,
-            previous_display_state,
         );
         events.append(&mut settings_menu_events);
         events
@@ -105,10 +128,21 @@ impl DomControllerInterface for Settings {
         self.settings_menu.render(window);
     }
 
+    fn render_dialog(&mut self, window: &mut RenderWindow, dom_id: u64) {
+        self.settings_menu.render_dialog(window, dom_id);
+    }
+
     fn update(&mut self, resource_manager: &ResourceManager) -> Vec<Event> {
         let mut events = self.settings_dom.update(resource_manager);
+        if self.settings_dom.take_reloaded() {
+            EVENT_ROUTES.check(&self.settings_dom);
+        }
         events.append(&mut self.settings_menu.update(resource_manager));
 
         events
     }
+
+    fn has_open_dialog(&self) -> bool {
+        self.settings_menu.has_open_dialog()
+    }
 }
@@ -1,76 +1,47 @@
 use sfml::graphics::RenderWindow;
-use tracing::warn;
 use ui::{
     dom_controller::{DomController, DomControllerInterface},
-    elements::traits::Element,
-    events::Event,
+    events::{event_routes::EventRoutes, Event},
     ui_settings::UISettings,
+    ui_state::UIState,
     utils::consts::DUMMY_MOUSE_MOVED_EVENT,
 };
 
-use super::settings_menu::SettingsMenu;
+use super::settings_menu::{SettingsMenu, SettingsMenuEventHandler};
+
+pub const EVENT_ROUTES: EventRoutes<SettingsMenuEventHandler> =
+    EventRoutes(&[("open_settings", open_settings)]);
 
 pub fn perform_events(
-    events: &Vec<Event>,
+    events: &[Event],
+    settings_dom: &DomController,
     window: &mut RenderWindow,
     ui_settings: &mut UISettings,
+    ui_state: &mut UIState,
     settings_menu: &mut SettingsMenu,
-    previous_display_state: bool,
 ) {
-    for event in events {
-        perform_event(
-            event,
-            window,
-            ui_settings,
-            settings_menu,
-            previous_display_state,
-        );
+    for (handler, event) in EVENT_ROUTES.resolve(settings_dom, events) {
+        handler(event, settings_menu, ui_settings, ui_state, window);
     }
 }
 
-fn perform_event(
-    event: &Event,
-    window: &mut RenderWindow,
-    ui_settings: &mut UISettings,
+fn open_settings(
+    _: &Event,
     settings_menu: &mut SettingsMenu,
-    previous_display_state: bool,
-) {
-    match event.id {
-        0 => {}
-        1 => event1(
-            event,
-            window,
-            ui_settings,
-            settings_menu,
-            previous_display_state,
-        ),
-        _ => {
-            warn!("Event: {:#?} is not yet implemented", event)
-        }
-    }
-}
-
-fn event1(
-    _event: &Event,
-    window: &mut RenderWindow,
     ui_settings: &mut UISettings,
-    settings_menu: &mut SettingsMenu,
-    previous_display_state: bool,
+    ui_state: &mut UIState,
+    window: &mut RenderWindow,
 ) {
-    settings_menu.display = !previous_display_state;
-    settings_menu.event_handler(window, ui_settings, DUMMY_MOUSE_MOVED_EVENT);
+    settings_menu.open(ui_state);
+    settings_menu.event_handler(window, ui_settings, ui_state, DUMMY_MOUSE_MOVED_EVENT);
 }
 
-pub fn sync_events(dom_controller: &mut DomController) {
-    dom_controller
-        .root_node
-        .traverse_dom_mut(&mut |ele| match ele.sync_id() {
-            0 => {}
-            sync_id => {
-                warn!(
-                    "Synchronization with sync_id {} has not yet been implemented!",
-                    sync_id
-                );
-            }
-        })
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_event_routes_match_layout() {
+        EVENT_ROUTES.assert_matches_layout(include_str!("settings_content.xml"));
+    }
 }
@@ -1,24 +1,36 @@
-use sfml::{graphics::RenderWindow, window::Event as SFMLEvent};
+use sfml::{graphics::RenderWindow, system::Vector2, window::Event as SFMLEvent};
 use ui::{
+    dialogs::{Dialog, DialogResult},
     dom_controller::{DomController, DomControllerInterface},
     events::Event,
-    ui_settings::UISettings,
+    ui_settings::{
+        controls::{
+            chord::Chord,
+            possible_binds::{PossibleBinds, APPLICATION_BINDS, UI_BINDS},
+        },
+        UISettings,
+    },
+    ui_state::UIState,
+    xml_layout,
 };
 use utils::resource_manager::ResourceManager;
 
-use crate::pallete_builder::color_grid::load_save::list_of_files_with_pacrb_extension;
+use crate::pallete_builder::color_grid::{
+    load_save::{list_of_files_with_pacrb_extension, remove_pacrb_file},
+    DEFAULT_GRID_SIZE,
+};
 
-use self::{
-    confirm_file_deletion::ConfirmFileDeletion,
-    settings_menu_content::{
-        perform_events, refresh_event, reload_list_of_files, set_save_file_traverse_dom,
-        sync_events,
-    },
+use self::settings_menu_content::{
+    perform_events, rebind_event_handler, refresh_event, reload_list_of_binds,
+    reload_list_of_files, sync_events, sync_grid_size, sync_notes, sync_save_file, EVENT_ROUTES,
 };
 
-mod confirm_file_deletion;
 mod settings_menu_content;
 
+/// Settings menu handlers also change the ui settings, the dialogs and the window
+pub type SettingsMenuEventHandler =
+    fn(&Event, &mut SettingsMenu, &mut UISettings, &mut UIState, &mut RenderWindow);
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 enum TriggerFileStates {
     #[default]
@@ -27,6 +39,23 @@ enum TriggerFileStates {
     JustSaved,
 }
 
+/// Binds listed in the controls menu
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum BindGroup {
+    #[default]
+    Application,
+    UI,
+}
+
+impl BindGroup {
+    fn binds(self) -> &'static [PossibleBinds] {
+        match self {
+            BindGroup::Application => &APPLICATION_BINDS,
+            BindGroup::UI => &UI_BINDS,
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct SettingsMenu {
     export_file_name: String,
@@ -34,20 +63,30 @@ pub struct SettingsMenu {
     trigger_export_event: TriggerFileStates,
     save_file: String,
     trigger_save_event: TriggerFileStates,
+    /// Notes of the active tab, saved along with it
+    notes: String,
     file_to_load: Option<String>,
+    grid_size: Vector2<usize>,
+    grid_size_to_apply: Option<Vector2<usize>>,
     list_of_files: Vec<String>,
     current_list_of_files_idx: usize,
+    listed_bind_group: BindGroup,
+    /// Bind waiting for the user to press its new chord
+    bind_to_rebind: Option<PossibleBinds>,
+    /// Chord that already belongs to another bind. Pressing it again takes it over
+    conflicting_chord: Option<Chord>,
     settings_menu_dom: DomController,
-    pub display: bool,
-    confirm_file_deletion: ConfirmFileDeletion,
+    /// File waiting for `confirm_file_deletion` to be answered
+    file_to_delete: Option<String>,
+    confirm_file_deletion: Dialog,
 }
 
 impl SettingsMenu {
     pub fn new(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
-        let settings_menu_dom = DomController::new(
+        let settings_menu_dom = DomController::from_layout(
             resource_manager,
             ui_settings,
-            include_str!("settings_menu/settings_menu_content.xml"),
+            xml_layout!("settings_menu/settings_menu_content.xml"),
         );
         let list_of_files = list_of_files_with_pacrb_extension();
         let mut sm = Self {
@@ -56,15 +95,23 @@ impl SettingsMenu {
             trigger_export_event: Default::default(),
             save_file: Default::default(),
             trigger_save_event: Default::default(),
+            notes: Default::default(),
             file_to_load: None,
+            grid_size: DEFAULT_GRID_SIZE,
+            grid_size_to_apply: None,
             settings_menu_dom,
             current_list_of_files_idx: 0,
-            display: false,
+            listed_bind_group: Default::default(),
+            bind_to_rebind: None,
+            conflicting_chord: None,
             list_of_files,
-            confirm_file_deletion: ConfirmFileDeletion::new(resource_manager, ui_settings),
+            file_to_delete: None,
+            confirm_file_deletion: Dialog::confirm(resource_manager, ui_settings),
         };
+        EVENT_ROUTES.check(&sm.settings_menu_dom);
         sync_events(&mut sm, ui_settings);
         reload_list_of_files(&mut sm);
+        reload_list_of_binds(&mut sm, ui_settings);
 
         sm
     }
@@ -91,13 +138,22 @@ impl SettingsMenu {
 
     pub fn set_save_file(&mut self, new_save_file: &str) {
         self.save_file = new_save_file.to_string();
-        set_save_file_traverse_dom(self);
+        sync_save_file(self);
     }
 
     pub fn save_file(&self) -> &str {
         &self.save_file
     }
 
+    pub fn set_notes(&mut self, notes: &str) {
+        self.notes = notes.to_string();
+        sync_notes(self);
+    }
+
+    pub fn notes(&self) -> &str {
+        &self.notes
+    }
+
     pub fn file_to_load(&self) -> Option<&str> {
         self.file_to_load.as_deref()
     }
@@ -106,10 +162,41 @@ impl SettingsMenu {
         self.file_to_load = None;
     }
 
-    pub fn open_save_menu(&mut self, ui_settings: &UISettings) {
-        self.display = true;
+    pub fn grid_size_to_apply(&self) -> Option<Vector2<usize>> {
+        self.grid_size_to_apply
+    }
+
+    pub fn clear_grid_size_to_apply(&mut self) {
+        self.grid_size_to_apply = None;
+    }
+
+    pub fn set_grid_size(&mut self, grid_size: Vector2<usize>) {
+        self.grid_size = grid_size;
+        sync_grid_size(self);
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.settings_menu_dom.is_dialog_open()
+    }
+
+    pub fn open(&mut self, ui_state: &mut UIState) {
+        self.settings_menu_dom.open_dialog(ui_state);
+    }
+
+    pub fn open_save_menu(&mut self, ui_settings: &UISettings, ui_state: &mut UIState) {
+        self.open(ui_state);
         settings_menu_content::open_save_menu(self, ui_settings);
     }
+
+    fn delete_file_if_confirmed(&mut self, result: DialogResult) {
+        if let (DialogResult::Confirmed, Some(file_to_delete)) = (result, &self.file_to_delete) {
+            remove_pacrb_file(file_to_delete);
+        }
+        self.file_to_delete = None;
+        self.current_list_of_files_idx = 0;
+        self.list_of_files = list_of_files_with_pacrb_extension();
+        reload_list_of_files(self);
+    }
 }
 
 impl DomControllerInterface for SettingsMenu {
@@ -117,45 +204,51 @@ impl DomControllerInterface for SettingsMenu {
         &mut self,
         window: &mut RenderWindow,
         ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
         event: SFMLEvent,
     ) -> Vec<Event> {
-        if !self.display && !matches!(event, SFMLEvent::Resized { .. }) {
+        let is_resized = matches!(event, SFMLEvent::Resized { .. });
+        if !self.is_open() && !is_resized {
             return Default::default();
         }
+        // Syncing needs the ui settings, which update does not get
+        if self.settings_menu_dom.take_reloaded() {
+            EVENT_ROUTES.check(&self.settings_menu_dom);
+            sync_events(self, ui_settings);
+            reload_list_of_files(self);
+            reload_list_of_binds(self, ui_settings);
+        }
 
-        let mut events = self
-            .confirm_file_deletion
-            .event_handler(window, ui_settings, event);
-        if !events.is_empty() || self.confirm_file_deletion.is_displaying() {
-            match self.confirm_file_deletion.file_deletion_selection() {
-                confirm_file_deletion::ConfirmFileDeletionSelection::Delete
-                | confirm_file_deletion::ConfirmFileDeletionSelection::Cancel => {
-                    self.confirm_file_deletion.set_display(false);
-                    self.confirm_file_deletion.set_file_to_delete("");
-                    self.current_list_of_files_idx = 0;
-                    self.list_of_files = list_of_files_with_pacrb_extension();
-                    reload_list_of_files(self);
-                }
-                confirm_file_deletion::ConfirmFileDeletionSelection::None => {}
-            }
+        let mut events =
+            self.confirm_file_deletion
+                .event_handler(window, ui_settings, ui_state, event);
+        if let Some(result) = self.confirm_file_deletion.take_result() {
+            self.delete_file_if_confirmed(result);
+            return events;
+        }
+        if self.confirm_file_deletion.is_open() && !is_resized {
+            return events;
+        }
+        if rebind_event_handler(self, ui_settings, event) {
             return events;
         }
 
-        events.append(
-            &mut self
-                .settings_menu_dom
-                .event_handler(window, ui_settings, event),
-        );
-        perform_events(&events, window, ui_settings, self);
+        events.append(&mut self.settings_menu_dom.event_handler(
+            window,
+            ui_settings,
+            ui_state,
+            event,
+        ));
+        perform_events(&events, window, ui_settings, ui_state, self);
         events
     }
 
-    fn render(&mut self, window: &mut RenderWindow) {
-        if !self.display {
-            return;
-        }
-        self.settings_menu_dom.render(window);
-        self.confirm_file_deletion.render(window);
+    /// Only drawn by [`DomControllerInterface::render_dialog`], while it is open
+    fn render(&mut self, _window: &mut RenderWindow) {}
+
+    fn render_dialog(&mut self, window: &mut RenderWindow, dom_id: u64) {
+        self.settings_menu_dom.render_dialog(window, dom_id);
+        self.confirm_file_deletion.render_dialog(window, dom_id);
     }
 
     fn update(&mut self, resource_manager: &ResourceManager) -> Vec<Event> {
@@ -166,11 +259,15 @@ impl DomControllerInterface for SettingsMenu {
         if self.trigger_export_event == TriggerFileStates::JustSaved {
             self.trigger_export_event = TriggerFileStates::Idle;
         }
-        if !self.display {
+        if !self.is_open() {
             return Default::default();
         }
         let mut events = self.settings_menu_dom.update(resource_manager);
         events.append(&mut self.confirm_file_deletion.update(resource_manager));
         events
     }
+
+    fn has_open_dialog(&self) -> bool {
+        self.is_open()
+    }
 }
@@ -1,16 +1,26 @@
 use std::str::FromStr;
 
-use sfml::{graphics::RenderWindow, window::clipboard};
-use tracing::{error, warn};
+use sfml::{
+    graphics::RenderWindow,
+    window::{clipboard, Event as SFMLEvent, Key},
+};
+use tracing::error;
 use ui::{
     dom_controller::{DomController, DomControllerInterface},
     elements::{traits::Element as ElementTrait, Element},
-    events::{Event, Events},
+    events::{event_routes::EventRoutes, Event, Events},
     syncs::Syncs,
     ui_settings::{
         aspect_ratio::{AspectRatio, DefaultAspectRatios},
+        controls::{
+            chord::{is_modifier_key, Chord},
+            possible_binds::{APPLICATION_BINDS, UI_BINDS},
+            possible_inputs::PossibleInputs,
+            shortcut::Shortcut,
+        },
         UISettings,
     },
+    ui_state::UIState,
     utils::consts::DUMMY_MOUSE_MOVED_EVENT,
 };
 
@@ -18,114 +28,204 @@ use crate::pallete_builder::color_grid::load_save::{
     full_file_path, list_of_files_with_pacrb_extension,
 };
 
-use super::{SettingsMenu, TriggerFileStates};
+use super::{BindGroup, SettingsMenu, SettingsMenuEventHandler, TriggerFileStates};
+
+/// Number of rows in the scrolled list of the controls menu, one for every bind of the
+/// largest group
+const BIND_ROWS: usize = APPLICATION_BINDS.len();
+/// Number of files shown per page of the load menu
+const FILES_PER_PAGE: usize = 5;
+
+pub const EVENT_ROUTES: EventRoutes<SettingsMenuEventHandler> = EventRoutes(&[
+    ("backdrop", close_by_backdrop),
+    ("close", close),
+    ("general_tab", open_general_tab),
+    ("save_load_tab", open_save_load_tab),
+    ("controls_tab", open_controls_tab),
+    ("export_tab", open_export_tab),
+    ("aspect_ratio", set_aspect_ratio),
+    ("save_settings", save_settings),
+    ("vsync", set_vsync),
+    ("grid_width", set_grid_width),
+    ("grid_height", set_grid_height),
+    ("apply_grid_size", apply_grid_size),
+    ("export_extension", set_export_extension),
+    ("export_file_name", set_export_file_name),
+    ("export", export),
+    ("save_open_folder", open_folder),
+    ("save_copy_folder_path", copy_folder_path),
+    ("export_open_folder", open_folder),
+    ("export_copy_folder_path", copy_folder_path),
+    ("refresh_files", refresh_files),
+    ("next_files", next_files),
+    ("previous_files", previous_files),
+    // Buttons in the rows of a list give their handler the row they are in
+    ("load_file_0", load_file::<0>),
+    ("load_file_1", load_file::<1>),
+    ("load_file_2", load_file::<2>),
+    ("load_file_3", load_file::<3>),
+    ("load_file_4", load_file::<4>),
+    ("delete_file_0", delete_file::<0>),
+    ("delete_file_1", delete_file::<1>),
+    ("delete_file_2", delete_file::<2>),
+    ("delete_file_3", delete_file::<3>),
+    ("delete_file_4", delete_file::<4>),
+    ("save_file", set_save_file),
+    ("save", save),
+    ("notes", set_notes),
+    ("reset_all_binds", reset_all_binds),
+    ("application_binds", list_application_binds),
+    ("ui_binds", list_ui_binds),
+    ("rebind_0", rebind::<0>),
+    ("rebind_1", rebind::<1>),
+    ("rebind_2", rebind::<2>),
+    ("rebind_3", rebind::<3>),
+    ("rebind_4", rebind::<4>),
+    ("rebind_5", rebind::<5>),
+    ("rebind_6", rebind::<6>),
+    ("rebind_7", rebind::<7>),
+    ("rebind_8", rebind::<8>),
+    ("rebind_9", rebind::<9>),
+    ("rebind_10", rebind::<10>),
+    ("rebind_11", rebind::<11>),
+    ("rebind_12", rebind::<12>),
+    ("rebind_13", rebind::<13>),
+    ("rebind_14", rebind::<14>),
+    ("rebind_15", rebind::<15>),
+    ("rebind_16", rebind::<16>),
+    ("rebind_17", rebind::<17>),
+    ("rebind_18", rebind::<18>),
+    ("rebind_19", rebind::<19>),
+    ("rebind_20", rebind::<20>),
+    ("rebind_21", rebind::<21>),
+    ("rebind_22", rebind::<22>),
+    ("rebind_23", rebind::<23>),
+    ("rebind_24", rebind::<24>),
+    ("rebind_25", rebind::<25>),
+    ("reset_bind_0", reset_bind::<0>),
+    ("reset_bind_1", reset_bind::<1>),
+    ("reset_bind_2", reset_bind::<2>),
+    ("reset_bind_3", reset_bind::<3>),
+    ("reset_bind_4", reset_bind::<4>),
+    ("reset_bind_5", reset_bind::<5>),
+    ("reset_bind_6", reset_bind::<6>),
+    ("reset_bind_7", reset_bind::<7>),
+    ("reset_bind_8", reset_bind::<8>),
+    ("reset_bind_9", reset_bind::<9>),
+    ("reset_bind_10", reset_bind::<10>),
+    ("reset_bind_11", reset_bind::<11>),
+    ("reset_bind_12", reset_bind::<12>),
+    ("reset_bind_13", reset_bind::<13>),
+    ("reset_bind_14", reset_bind::<14>),
+    ("reset_bind_15", reset_bind::<15>),
+    ("reset_bind_16", reset_bind::<16>),
+    ("reset_bind_17", reset_bind::<17>),
+    ("reset_bind_18", reset_bind::<18>),
+    ("reset_bind_19", reset_bind::<19>),
+    ("reset_bind_20", reset_bind::<20>),
+    ("reset_bind_21", reset_bind::<21>),
+    ("reset_bind_22", reset_bind::<22>),
+    ("reset_bind_23", reset_bind::<23>),
+    ("reset_bind_24", reset_bind::<24>),
+    ("reset_bind_25", reset_bind::<25>),
+]);
 
 pub fn perform_events(
-    events: &Vec<Event>,
+    events: &[Event],
     window: &mut RenderWindow,
     ui_settings: &mut UISettings,
+    ui_state: &mut UIState,
     settings_menu: &mut SettingsMenu,
 ) {
-    for event in events {
-        perform_event(event, window, ui_settings, settings_menu, events.len());
-    }
-}
-
-fn perform_event(
-    event: &Event,
-    window: &mut RenderWindow,
-    ui_settings: &mut UISettings,
-    settings_menu: &mut SettingsMenu,
-    num_of_events: usize,
-) {
-    match event.id {
-        0 => {}
-        1 => event1(settings_menu, num_of_events),
-        2 => event2(window, ui_settings, settings_menu),
-        3 => event3(settings_menu, ui_settings),
-        4 => event4(settings_menu, ui_settings),
-        5 => event5(settings_menu, ui_settings),
-        6 => event6(settings_menu, ui_settings),
-        100 => event100(event, ui_settings),
-        101 => event101(ui_settings),
-        102 => event102(event, ui_settings, window),
-        // Export functions
-        200 => event200(event, settings_menu, ui_settings),
-        201 => event201(event, settings_menu, ui_settings),
-        202 => event202(settings_menu),
-        // Open file directory
-        300 => event300(),
-        // Copy file directory
-        301 => event301(),
-        // Refresh
-        1097 => event1097(settings_menu),
-        // Next
-        1098 => event1098(settings_menu),
-        // Prev
-        1099 => event1099(settings_menu),
-        // Load file buttons
-        1100 => event1100(settings_menu),
-        1101 => event1101(settings_menu),
-        1102 => event1102(settings_menu),
-        1103 => event1103(settings_menu),
-        1104 => event1104(settings_menu),
-        // Delete file buttons
-        1200 => event1200(settings_menu),
-        1201 => event1201(settings_menu),
-        1202 => event1202(settings_menu),
-        1203 => event1203(settings_menu),
-        1204 => event1204(settings_menu),
-        // Save file events
-        2000 => event2000(event, settings_menu),
-        2001 => event2001(settings_menu),
-        _ => {
-            warn!("Event: {:#?} is not yet implemented", event)
+    for (handler, event) in EVENT_ROUTES.resolve(&settings_menu.settings_menu_dom, events) {
+        // Clicks on the menu also land on the backdrop behind it
+        let element_id = settings_menu
+            .settings_menu_dom
+            .element_id_of_event(event.id);
+        if events.len() > 1 && element_id == Some("backdrop") {
+            continue;
         }
+        handler(event, settings_menu, ui_settings, ui_state, window);
     }
 }
 
-fn event1(settings_menu: &mut SettingsMenu, num_of_events: usize) {
-    // If we click on the background, it'll throw an event. Div will also throw an event.
-    // Meaning we have 2 events. 2 events, means we clicked on the background.
-    if num_of_events > 1 {
-        return;
-    }
-    settings_menu.display = false;
+fn close_by_backdrop(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    _: &mut UISettings,
+    ui_state: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    settings_menu.settings_menu_dom.close_dialog(ui_state);
 }
 
-fn event2(
-    window: &mut RenderWindow,
-    ui_settings: &mut UISettings,
+fn close(
+    _: &Event,
     settings_menu: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    ui_state: &mut UIState,
+    window: &mut RenderWindow,
 ) {
-    settings_menu.display = false;
-    settings_menu
-        .settings_menu_dom
-        .event_handler(window, ui_settings, DUMMY_MOUSE_MOVED_EVENT);
+    settings_menu.settings_menu_dom.event_handler(
+        window,
+        ui_settings,
+        ui_state,
+        DUMMY_MOUSE_MOVED_EVENT,
+    );
+    settings_menu.settings_menu_dom.close_dialog(ui_state);
 }
 
-fn event3(settings_menu: &mut SettingsMenu, ui_settings: &UISettings) {
+fn open_general_tab(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
     set_the_current_set(&mut settings_menu.settings_menu_dom, 0);
     sync_events(settings_menu, ui_settings);
 }
 
-fn event4(settings_menu: &mut SettingsMenu, ui_settings: &UISettings) {
-    set_the_current_set(&mut settings_menu.settings_menu_dom, 1);
-    sync_events(settings_menu, ui_settings);
-    reload_list_of_files(settings_menu);
+fn open_save_load_tab(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    open_save_menu(settings_menu, ui_settings);
 }
 
-fn event5(settings_menu: &mut SettingsMenu, ui_settings: &UISettings) {
+fn open_controls_tab(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
     set_the_current_set(&mut settings_menu.settings_menu_dom, 2);
     sync_events(settings_menu, ui_settings);
+    reload_list_of_binds(settings_menu, ui_settings);
 }
 
-fn event6(settings_menu: &mut SettingsMenu, ui_settings: &UISettings) {
+fn open_export_tab(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
     set_the_current_set(&mut settings_menu.settings_menu_dom, 3);
     sync_events(settings_menu, ui_settings);
 }
 
-fn event100(event: &Event, ui_settings: &mut UISettings) {
+fn set_aspect_ratio(
+    event: &Event,
+    _: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
     let Events::StringEvent(event) = event.event.clone() else {
         error!("Event is not a StringEvent {:#?}", event);
         return;
@@ -142,11 +242,23 @@ fn event100(event: &Event, ui_settings: &mut UISettings) {
     ui_settings.aspect_ratio = aspect_ratio;
 }
 
-fn event101(ui_settings: &UISettings) {
+fn save_settings(
+    _: &Event,
+    _: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
     ui_settings.save_settings()
 }
 
-fn event102(event: &Event, ui_settings: &mut UISettings, window: &mut RenderWindow) {
+fn set_vsync(
+    event: &Event,
+    _: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    window: &mut RenderWindow,
+) {
     let Events::BooleanEvent(enable_vsync) = event.event else {
         error!("event is not a boolean event! {:#?}", event);
         return;
@@ -159,7 +271,53 @@ fn event102(event: &Event, ui_settings: &mut UISettings, window: &mut RenderWind
     }
 }
 
-fn event200(event: &Event, settings_menu: &mut SettingsMenu, ui_settings: &UISettings) {
+fn set_grid_width(
+    event: &Event,
+    settings_menu: &mut SettingsMenu,
+    _: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    let Events::NumericalEvent(width) = event.event else {
+        error!("event is not a numerical event! {:#?}", event);
+        return;
+    };
+
+    settings_menu.grid_size.x = width as usize;
+}
+
+fn set_grid_height(
+    event: &Event,
+    settings_menu: &mut SettingsMenu,
+    _: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    let Events::NumericalEvent(height) = event.event else {
+        error!("event is not a numerical event! {:#?}", event);
+        return;
+    };
+
+    settings_menu.grid_size.y = height as usize;
+}
+
+fn apply_grid_size(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    _: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    settings_menu.grid_size_to_apply = Some(settings_menu.grid_size);
+}
+
+fn set_export_extension(
+    event: &Event,
+    settings_menu: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
     let Events::StringEvent(extension) = &event.event else {
         error!("event is not a string event! {:#?}", event);
         return;
@@ -169,7 +327,13 @@ fn event200(event: &Event, settings_menu: &mut SettingsMenu, ui_settings: &UISet
     sync_events(settings_menu, ui_settings);
 }
 
-fn event201(event: &Event, settings_menu: &mut SettingsMenu, ui_settings: &UISettings) {
+fn set_export_file_name(
+    event: &Event,
+    settings_menu: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
     let Events::TextBoxEvent(event) = &event.event else {
         error!("event is not a textbox event! {:#?}", event);
         return;
@@ -179,11 +343,23 @@ fn event201(event: &Event, settings_menu: &mut SettingsMenu, ui_settings: &UISet
     sync_events(settings_menu, ui_settings);
 }
 
-fn event202(settings_menu: &mut SettingsMenu) {
+fn export(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    _: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
     settings_menu.trigger_export_event = TriggerFileStates::Save;
 }
 
-fn event300() {
+fn open_folder(
+    _: &Event,
+    _: &mut SettingsMenu,
+    _: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
     let full_file_path = full_file_path();
     let Ok(full_file_path) = full_file_path else {
         error!("{:#?}", full_file_path);
@@ -195,7 +371,13 @@ fn event300() {
     }
 }
 
-fn event301() {
+fn copy_folder_path(
+    _: &Event,
+    _: &mut SettingsMenu,
+    _: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
     let full_file_path = full_file_path();
     let Ok(full_file_path) = full_file_path else {
         error!("{:#?}", full_file_path);
@@ -211,201 +393,436 @@ fn event301() {
     clipboard::set_string(&full_file_path);
 }
 
-fn event1097(settings_menu: &mut SettingsMenu) {
-    settings_menu.current_list_of_files_idx = 0;
-    settings_menu.current_list_of_files_idx = 0;
-    settings_menu.list_of_files = list_of_files_with_pacrb_extension();
-    reload_list_of_files(settings_menu);
+fn refresh_files(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    _: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    refresh_event(settings_menu)
 }
 
-fn event1098(settings_menu: &mut SettingsMenu) {
+fn next_files(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    _: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
     if settings_menu.current_list_of_files_idx >= settings_menu.list_of_files.len() {
         return;
     }
-    settings_menu.current_list_of_files_idx += 5;
+    settings_menu.current_list_of_files_idx += FILES_PER_PAGE;
     reload_list_of_files(settings_menu);
 }
 
-fn event1099(settings_menu: &mut SettingsMenu) {
-    settings_menu.current_list_of_files_idx =
-        settings_menu.current_list_of_files_idx.saturating_sub(5);
+fn previous_files(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    _: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    settings_menu.current_list_of_files_idx = settings_menu
+        .current_list_of_files_idx
+        .saturating_sub(FILES_PER_PAGE);
     settings_menu.list_of_files = list_of_files_with_pacrb_extension();
     reload_list_of_files(settings_menu);
 }
 
-fn setup_load_event(settings_menu: &mut SettingsMenu, index: usize) -> Option<()> {
-    let file_name = settings_menu
+fn load_file<const ROW: usize>(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    _: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    let Some(file_name) = settings_menu
         .list_of_files
-        .get(settings_menu.current_list_of_files_idx + index)?;
+        .get(settings_menu.current_list_of_files_idx + ROW)
+    else {
+        return;
+    };
 
     settings_menu.file_to_load = Some(file_name.to_owned());
-
-    Some(())
 }
 
-fn event1100(settings_menu: &mut SettingsMenu) {
-    setup_load_event(settings_menu, 0);
+fn delete_file<const ROW: usize>(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    _: &mut UISettings,
+    ui_state: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    setup_deletion_confirmation_prompt(
+        settings_menu,
+        ui_state,
+        settings_menu.current_list_of_files_idx + ROW,
+    );
 }
 
-fn event1101(settings_menu: &mut SettingsMenu) {
-    setup_load_event(settings_menu, 1);
-}
+fn set_save_file(
+    event: &Event,
+    settings_menu: &mut SettingsMenu,
+    _: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    let Events::TextBoxEvent(text_box_event) = event.event.clone() else {
+        error!("event is not a string event! {:#?}", event);
+        return;
+    };
 
-fn event1102(settings_menu: &mut SettingsMenu) {
-    setup_load_event(settings_menu, 2);
+    let file_name = text_box_event.string;
+    if file_name.ends_with(".pacrb") {
+        settings_menu.save_file = file_name;
+    } else {
+        settings_menu.save_file = format!("{}{}", file_name, ".pacrb");
+    }
 }
 
-fn event1103(settings_menu: &mut SettingsMenu) {
-    setup_load_event(settings_menu, 3);
+fn set_notes(
+    event: &Event,
+    settings_menu: &mut SettingsMenu,
+    _: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    let Events::TextBoxEvent(text_box_event) = event.event.clone() else {
+        error!("event is not a string event! {:#?}", event);
+        return;
+    };
+
+    settings_menu.notes = text_box_event.string;
 }
 
-fn event1104(settings_menu: &mut SettingsMenu) {
-    setup_load_event(settings_menu, 4);
+fn save(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    _: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    settings_menu.trigger_save_event = TriggerFileStates::Save;
 }
 
-fn event1200(settings_menu: &mut SettingsMenu) {
-    setup_deletion_confirmation_prompt(settings_menu, settings_menu.current_list_of_files_idx);
+fn reset_all_binds(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    settings_menu.bind_to_rebind = None;
+    settings_menu.conflicting_chord = None;
+    ui_settings.binds.reset_all_shortcuts();
+    ui_settings.save_settings();
+    reload_list_of_binds(settings_menu, ui_settings);
+    set_keybind_status(settings_menu, "Every shortcut was reset");
 }
 
-fn event1201(settings_menu: &mut SettingsMenu) {
-    setup_deletion_confirmation_prompt(settings_menu, settings_menu.current_list_of_files_idx + 1);
+fn list_application_binds(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    list_bind_group(settings_menu, ui_settings, BindGroup::Application);
 }
 
-fn event1202(settings_menu: &mut SettingsMenu) {
-    setup_deletion_confirmation_prompt(settings_menu, settings_menu.current_list_of_files_idx + 2);
+fn list_ui_binds(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    list_bind_group(settings_menu, ui_settings, BindGroup::UI);
 }
 
-fn event1203(settings_menu: &mut SettingsMenu) {
-    setup_deletion_confirmation_prompt(settings_menu, settings_menu.current_list_of_files_idx + 3);
+fn list_bind_group(
+    settings_menu: &mut SettingsMenu,
+    ui_settings: &UISettings,
+    bind_group: BindGroup,
+) {
+    settings_menu.listed_bind_group = bind_group;
+    if let Some(Element::ScrollView(scroll_view)) =
+        settings_menu.settings_menu_dom.get_element_by_id("binds")
+    {
+        scroll_view.scroll_to(0);
+    }
+    settings_menu.bind_to_rebind = None;
+    settings_menu.conflicting_chord = None;
+    reload_list_of_binds(settings_menu, ui_settings);
+    set_keybind_status(settings_menu, "Click rebind, then press the new shortcut");
 }
 
-fn event1204(settings_menu: &mut SettingsMenu) {
-    setup_deletion_confirmation_prompt(settings_menu, settings_menu.current_list_of_files_idx + 4);
+fn rebind<const ROW: usize>(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    let listed_binds = settings_menu.listed_bind_group.binds();
+    let Some(&bind) = listed_binds.get(ROW) else {
+        return;
+    };
+
+    settings_menu.bind_to_rebind = Some(bind);
+    settings_menu.conflicting_chord = None;
+    reload_list_of_binds(settings_menu, ui_settings);
+    set_keybind_status(
+        settings_menu,
+        &format!("Press the new shortcut for {}. Escape cancels", bind),
+    );
 }
 
-fn event2000(event: &Event, settings_menu: &mut SettingsMenu) {
-    let Events::TextBoxEvent(text_box_event) = event.event.clone() else {
-        error!("event is not a string event! {:#?}", event);
+fn reset_bind<const ROW: usize>(
+    _: &Event,
+    settings_menu: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    _: &mut UIState,
+    _: &mut RenderWindow,
+) {
+    let listed_binds = settings_menu.listed_bind_group.binds();
+    let Some(&bind) = listed_binds.get(ROW) else {
         return;
     };
 
-    let file_name = text_box_event.string;
-    if file_name.ends_with(".pacrb") {
-        settings_menu.save_file = file_name;
+    settings_menu.bind_to_rebind = None;
+    settings_menu.conflicting_chord = None;
+    ui_settings.binds.reset_shortcuts(bind);
+    ui_settings.save_settings();
+    reload_list_of_binds(settings_menu, ui_settings);
+    set_keybind_status(settings_menu, &format!("{} was reset", bind));
+}
+
+/// Captures the next chord while a bind is being rebound.
+///
+/// Returns true if the event was used up. A plain left click is let through, so other
+/// buttons in the menu can still be clicked.
+pub fn rebind_event_handler(
+    settings_menu: &mut SettingsMenu,
+    ui_settings: &mut UISettings,
+    event: SFMLEvent,
+) -> bool {
+    let Some(bind) = settings_menu.bind_to_rebind else {
+        return false;
+    };
+
+    match event {
+        SFMLEvent::KeyPressed { code, .. } if is_modifier_key(code) => return true,
+        SFMLEvent::KeyPressed {
+            code: Key::Escape, ..
+        } => {
+            settings_menu.bind_to_rebind = None;
+            settings_menu.conflicting_chord = None;
+            reload_list_of_binds(settings_menu, ui_settings);
+            set_keybind_status(settings_menu, "Rebinding was cancelled");
+            return true;
+        }
+        SFMLEvent::KeyPressed { .. } | SFMLEvent::MouseButtonPressed { .. } => {}
+        _ => return false,
+    }
+    let Some(chord) = Chord::from_event(event) else {
+        return false;
+    };
+    if chord == Chord::new(PossibleInputs::ButtonLeft) {
+        return false;
+    }
+
+    let shortcut = Shortcut::from(chord);
+    match ui_settings.binds.conflicting_bind(&shortcut, bind) {
+        Some(other_bind) if settings_menu.conflicting_chord != Some(chord) => {
+            settings_menu.conflicting_chord = Some(chord);
+            set_keybind_status(
+                settings_menu,
+                &format!(
+                    "{} is used by {}. Press it again to replace it",
+                    chord, other_bind
+                ),
+            );
+            return true;
+        }
+        _ => {}
+    }
+
+    // The UI has to stay clickable
+    if UI_BINDS.contains(&bind) {
+        ui_settings.binds.clear_shortcuts_but_mouse(bind);
     } else {
-        settings_menu.save_file = format!("{}{}", file_name, ".pacrb");
+        ui_settings.binds.clear_shortcuts(bind);
     }
-}
+    ui_settings.binds.set_shortcut(shortcut, bind);
+    ui_settings.save_settings();
+    settings_menu.bind_to_rebind = None;
+    settings_menu.conflicting_chord = None;
+    reload_list_of_binds(settings_menu, ui_settings);
+    set_keybind_status(settings_menu, &format!("{} is now {}", bind, chord));
 
-fn event2001(settings_menu: &mut SettingsMenu) {
-    settings_menu.trigger_save_event = TriggerFileStates::Save;
+    true
 }
 
 pub fn sync_events(settings_menu: &mut SettingsMenu, ui_settings: &UISettings) {
-    let export_file = &settings_menu.export_file();
-    let save_file = settings_menu.save_file().to_string();
-    settings_menu.settings_menu_dom
-        .root_node
-        .traverse_dom_mut(&mut |ele| match ele.sync_id() {
-            0 | 1 | 1000 | 1001 | 1002 | 1003 | 1004 => {}
-            100 => {
-                let Ok(aspect_ratio) = DefaultAspectRatios::try_from(ui_settings.aspect_ratio) else {
-                    error!("Failed to convert aspect_ratio");
-                    return;
-                };
-
-                let Element::ListBox(list_box) = ele else {
-                    error!("Failed to convert element into listbox: {:#?}", ele);
-                    return;
-                };
-                list_box.sync(Syncs::String(aspect_ratio.to_string()));
-            }
-            102 => {
-                let Element::Button(boolean_image_button) = ele else {
-                    error!("Failed to convert element into boolean image button: {:#?}", ele);
-                    return;
-                };
-
-                boolean_image_button.sync(Syncs::Boolean(ui_settings.is_vsync_enabled()));
-            }
-            200 => {
-                ele.sync(Syncs::String(export_file.clone()))
-            }
-            2000 => {
-                set_save_file(ele, &save_file);
-            }
-            sync_id => {
-                warn!(
-                    "Synchronization with sync_id {} has not yet been implemented!",
-                    sync_id
-                );
+    let export_file = settings_menu.export_file();
+    let dom_controller = &mut settings_menu.settings_menu_dom;
+    match DefaultAspectRatios::try_from(ui_settings.aspect_ratio) {
+        Ok(aspect_ratio) => {
+            if let Some(Element::Dropdown(dropdown)) =
+                dom_controller.get_element_by_id("aspect_ratio")
+            {
+                dropdown.sync(Syncs::String(aspect_ratio.to_string()));
             }
-        })
+        }
+        Err(_) => error!("Failed to convert aspect_ratio"),
+    }
+    if let Some(Element::Button(boolean_image_button)) = dom_controller.get_element_by_id("vsync") {
+        boolean_image_button.sync(Syncs::Boolean(ui_settings.is_vsync_enabled()));
+    }
+    if let Some(ele) = dom_controller.get_element_by_id("export_file") {
+        ele.sync(Syncs::String(export_file));
+    }
+    sync_save_file(settings_menu);
+    sync_grid_size(settings_menu);
 }
 
-fn setup_deletion_confirmation_prompt(settings_menu: &mut SettingsMenu, index: usize) {
+fn setup_deletion_confirmation_prompt(
+    settings_menu: &mut SettingsMenu,
+    ui_state: &mut UIState,
+    index: usize,
+) {
     let Some(file_name) = settings_menu.list_of_files.get(index) else {
         return;
     };
 
-    settings_menu
-        .confirm_file_deletion
-        .set_file_to_delete(file_name);
-    settings_menu.confirm_file_deletion.set_display(true);
+    settings_menu.confirm_file_deletion.open(
+        ui_state,
+        &format!("Are you sure you want to delete {}?", file_name),
+        "",
+    );
+    settings_menu.file_to_delete = Some(file_name.clone());
 }
 
 fn set_the_current_set(dom_controller: &mut DomController, set_num: usize) {
-    dom_controller.root_node.traverse_dom_mut(&mut |ele| {
-        if ele.sync_id() == 1 {
-            let Element::Sets(set) = ele else {
-                error!("Element is not a set!");
-                return;
-            };
-            set.set_current_set(set_num);
-        }
-    });
+    let Some(Element::Sets(set)) = dom_controller.get_element_by_id("sets") else {
+        error!("Element is not a set!");
+        return;
+    };
+    set.set_current_set(set_num);
 }
 
 pub fn reload_list_of_files(settings_menu: &mut SettingsMenu) {
-    let dom_controller = &mut settings_menu.settings_menu_dom;
-    let list_of_files = &settings_menu.list_of_files;
-    let mut idx = settings_menu.current_list_of_files_idx;
-    dom_controller
-        .root_node
-        .traverse_dom_mut(&mut |ele| match ele.sync_id() {
-            1000 | 1001 | 1002 | 1003 | 1004 => {
-                let file_name = list_of_files.get(idx);
-                idx += 1;
-
-                if let Some(file_name) = file_name {
-                    ele.sync(Syncs::String(file_name.clone()));
+    for row in 0..FILES_PER_PAGE {
+        let file_name = settings_menu
+            .list_of_files
+            .get(settings_menu.current_list_of_files_idx + row)
+            .cloned()
+            .unwrap_or_else(|| "________________________".to_string());
+        if let Some(ele) = settings_menu
+            .settings_menu_dom
+            .get_element_by_id(&format!("file_{}", row))
+        {
+            ele.sync(Syncs::String(file_name));
+        }
+    }
+}
+
+pub fn reload_list_of_binds(settings_menu: &mut SettingsMenu, ui_settings: &UISettings) {
+    let binds = &ui_settings.binds;
+    for row in 0..BIND_ROWS {
+        let bind = settings_menu.listed_bind_group.binds().get(row).copied();
+        let name = bind.map_or("________________".to_string(), |bind| bind.to_string());
+        let shortcuts = match bind {
+            None => "________________".to_string(),
+            Some(bind) if settings_menu.bind_to_rebind == Some(bind) => "...".to_string(),
+            Some(bind) => {
+                let mut shortcuts: Vec<String> =
+                    binds.shortcuts(bind).map(Shortcut::to_string).collect();
+                shortcuts.sort();
+                if shortcuts.is_empty() {
+                    "Unbound".to_string()
                 } else {
-                    ele.sync(Syncs::String("________________________".to_string()));
+                    shortcuts.join(" / ")
                 }
             }
-            _ => {}
-        })
+        };
+
+        let dom_controller = &mut settings_menu.settings_menu_dom;
+        if let Some(ele) = dom_controller.get_element_by_id(&format!("bind_name_{}", row)) {
+            ele.sync(Syncs::String(name));
+        }
+        if let Some(ele) = dom_controller.get_element_by_id(&format!("bind_shortcuts_{}", row)) {
+            ele.sync(Syncs::String(shortcuts));
+        }
+    }
+}
+
+fn set_keybind_status(settings_menu: &mut SettingsMenu, status: &str) {
+    if let Some(ele) = settings_menu
+        .settings_menu_dom
+        .get_element_by_id("keybind_status")
+    {
+        ele.sync(Syncs::String(status.to_string()))
+    }
 }
 
 pub fn open_save_menu(settings_menu: &mut SettingsMenu, ui_settings: &UISettings) {
-    event4(settings_menu, ui_settings)
+    set_the_current_set(&mut settings_menu.settings_menu_dom, 1);
+    sync_events(settings_menu, ui_settings);
+    reload_list_of_files(settings_menu);
 }
 
-pub fn set_save_file_traverse_dom(settings_menu: &mut SettingsMenu) {
-    settings_menu
+pub fn sync_save_file(settings_menu: &mut SettingsMenu) {
+    if let Some(ele) = settings_menu
         .settings_menu_dom
-        .root_node
-        .traverse_dom_mut(&mut |ele| {
-            if let 2000 = ele.sync_id() {
-                set_save_file(ele, &settings_menu.save_file)
-            }
-        })
+        .get_element_by_id("save_file")
+    {
+        ele.sync(Syncs::String(settings_menu.save_file.clone()))
+    }
+}
+
+pub fn sync_notes(settings_menu: &mut SettingsMenu) {
+    if let Some(ele) = settings_menu.settings_menu_dom.get_element_by_id("notes") {
+        ele.sync(Syncs::String(settings_menu.notes.clone()))
+    }
 }
 
-pub fn set_save_file(ele: &mut Element, new_save_file: &str) {
-    ele.sync(Syncs::String(new_save_file.to_string()))
+pub fn sync_grid_size(settings_menu: &mut SettingsMenu) {
+    let grid_size = settings_menu.grid_size;
+    let dom_controller = &mut settings_menu.settings_menu_dom;
+    if let Some(ele) = dom_controller.get_element_by_id("grid_width") {
+        ele.sync(Syncs::Numerical(grid_size.x as f32));
+    }
+    if let Some(ele) = dom_controller.get_element_by_id("grid_height") {
+        ele.sync(Syncs::Numerical(grid_size.y as f32));
+    }
 }
 
 pub fn refresh_event(settings_menu: &mut SettingsMenu) {
-    event1097(settings_menu)
+    settings_menu.current_list_of_files_idx = 0;
+    settings_menu.list_of_files = list_of_files_with_pacrb_extension();
+    reload_list_of_files(settings_menu);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_event_routes_match_layout() {
+        EVENT_ROUTES.assert_matches_layout(include_str!("settings_menu_content.xml"));
+    }
+
+    #[test]
+    fn test_every_bind_has_a_row() {
+        assert!(UI_BINDS.len() <= BIND_ROWS);
+        for row in 0..BIND_ROWS {
+            for id in [format!("rebind_{}", row), format!("reset_bind_{}", row)] {
+                assert!(EVENT_ROUTES.0.iter().any(|&(route_id, _)| route_id == id));
+            }
+        }
+    }
 }
@@ -0,0 +1,131 @@
+use sfml::{graphics::RenderWindow, window::Event as SFMLEvent};
+use ui::{
+    dom_controller::{DomController, DomControllerInterface},
+    events::Event,
+    ui_settings::UISettings,
+    ui_state::UIState,
+    xml_layout,
+};
+use utils::resource_manager::ResourceManager;
+
+use self::tab_strip_content::{perform_events, sync_close_text, sync_events};
+
+mod tab_strip_content;
+
+/// Number of tab slots in tab_strip_content.xml
+pub const MAX_TABS: usize = 5;
+
+/// Row of tabs across the top of the screen, with buttons to open and close tabs.
+///
+/// Does not own the tabs. Requests are polled by the pallete builder, which then calls
+/// [`TabStrip::set_tabs`] with the result.
+#[derive(Debug)]
+pub struct TabStrip {
+    tab_strip_dom: DomController,
+    titles: Vec<String>,
+    active_tab: usize,
+    tab_to_select: Option<usize>,
+    new_tab_requested: bool,
+    close_requested: bool,
+    is_confirming_close: bool,
+}
+
+impl TabStrip {
+    pub fn new(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
+        let mut tab_strip = Self {
+            tab_strip_dom: DomController::from_layout(
+                resource_manager,
+                ui_settings,
+                xml_layout!("tab_strip/tab_strip_content.xml"),
+            ),
+            titles: Vec::new(),
+            active_tab: 0,
+            tab_to_select: None,
+            new_tab_requested: false,
+            close_requested: false,
+            is_confirming_close: false,
+        };
+        sync_events(
+            &mut tab_strip.tab_strip_dom,
+            &tab_strip.titles,
+            tab_strip.active_tab,
+        );
+
+        tab_strip
+    }
+
+    /// Only resyncs the dom if a title or the active tab changed
+    pub fn set_tabs(&mut self, titles: Vec<String>, active_tab: usize) {
+        if self.titles == titles && self.active_tab == active_tab {
+            return;
+        }
+        if self.active_tab != active_tab {
+            self.cancel_close_confirmation();
+        }
+
+        self.titles = titles;
+        self.active_tab = active_tab;
+        sync_events(&mut self.tab_strip_dom, &self.titles, self.active_tab);
+    }
+
+    pub fn take_tab_to_select(&mut self) -> Option<usize> {
+        self.tab_to_select.take()
+    }
+
+    pub fn take_new_tab_request(&mut self) -> bool {
+        std::mem::take(&mut self.new_tab_requested)
+    }
+
+    /// Closing a tab with unsaved changes needs the close button to be pressed twice.
+    ///
+    /// Returns true once the active tab should actually be closed.
+    pub fn take_close_request(&mut self, active_tab_has_unsaved_changes: bool) -> bool {
+        if !std::mem::take(&mut self.close_requested) {
+            return false;
+        }
+        if active_tab_has_unsaved_changes && !self.is_confirming_close {
+            self.is_confirming_close = true;
+            sync_close_text(&mut self.tab_strip_dom, self.is_confirming_close);
+            return false;
+        }
+
+        self.cancel_close_confirmation();
+        true
+    }
+
+    fn cancel_close_confirmation(&mut self) {
+        if self.is_confirming_close {
+            self.is_confirming_close = false;
+            sync_close_text(&mut self.tab_strip_dom, self.is_confirming_close);
+        }
+    }
+}
+
+impl DomControllerInterface for TabStrip {
+    fn event_handler(
+        &mut self,
+        window: &mut RenderWindow,
+        ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
+        event: SFMLEvent,
+    ) -> Vec<Event> {
+        let events = self
+            .tab_strip_dom
+            .event_handler(window, ui_settings, ui_state, event);
+        perform_events(&events, self);
+        events
+    }
+
+    fn update(&mut self, resource_manager: &ResourceManager) -> Vec<Event> {
+        let events = self.tab_strip_dom.update(resource_manager);
+        if self.tab_strip_dom.take_reloaded() {
+            sync_events(&mut self.tab_strip_dom, &self.titles, self.active_tab);
+            sync_close_text(&mut self.tab_strip_dom, self.is_confirming_close);
+        }
+        events
+    }
+
+    fn render(&mut self, window: &mut RenderWindow) {
+        self.tab_strip_dom.render(window);
+    }
+}
@@ -0,0 +1,104 @@
+use tracing::{error, warn};
+use ui::{
+    dom_controller::DomController,
+    elements::{traits::Element as ElementTrait, Element},
+    events::Event,
+    syncs::Syncs,
+};
+
+use super::{TabStrip, MAX_TABS};
+
+const TAB_TEXT_SYNC_ID_OFFSET: u16 = 11;
+const TAB_EVENT_ID_OFFSET: u16 = 11;
+const CLOSE_TEXT_SYNC_ID: u16 = 20;
+
+/// Hidden, inactive and active tab sets
+const HIDDEN_TAB_SET: usize = 0;
+const INACTIVE_TAB_SET: usize = 1;
+const ACTIVE_TAB_SET: usize = 2;
+
+pub fn perform_events(events: &Vec<Event>, tab_strip: &mut TabStrip) {
+    for event in events {
+        perform_event(event, tab_strip);
+    }
+}
+
+fn perform_event(event: &Event, tab_strip: &mut TabStrip) {
+    match event.id {
+        0 => {}
+        1 => event1(tab_strip),
+        2 => event2(tab_strip),
+        id if (TAB_EVENT_ID_OFFSET..TAB_EVENT_ID_OFFSET + MAX_TABS as u16).contains(&id) => {
+            select_tab_event(tab_strip, usize::from(id - TAB_EVENT_ID_OFFSET))
+        }
+        _ => {
+            warn!("Event: {:#?} is not yet implemented", event)
+        }
+    }
+}
+
+/// New tab
+fn event1(tab_strip: &mut TabStrip) {
+    tab_strip.new_tab_requested = true;
+}
+
+/// Close the active tab
+fn event2(tab_strip: &mut TabStrip) {
+    tab_strip.close_requested = true;
+}
+
+fn select_tab_event(tab_strip: &mut TabStrip, tab: usize) {
+    if tab < tab_strip.titles.len() {
+        tab_strip.tab_to_select = Some(tab);
+    }
+}
+
+pub fn sync_events(dom_controller: &mut DomController, titles: &[String], active_tab: usize) {
+    // Only the current set of a `Sets` is traversed, so the sets are switched before the
+    // titles are synced
+    dom_controller.root_node.traverse_dom_mut(&mut |ele| {
+        let sync_id = usize::from(ele.sync_id());
+        if !(1..=MAX_TABS).contains(&sync_id) {
+            return;
+        }
+        let Element::Sets(set) = ele else {
+            error!("Element is not a set!");
+            return;
+        };
+        let tab = sync_id - 1;
+        set.set_current_set(if tab >= titles.len() {
+            HIDDEN_TAB_SET
+        } else if tab == active_tab {
+            ACTIVE_TAB_SET
+        } else {
+            INACTIVE_TAB_SET
+        });
+    });
+
+    dom_controller
+        .root_node
+        .traverse_dom_mut(&mut |ele| match ele.sync_id() {
+            sync_id
+                if (TAB_TEXT_SYNC_ID_OFFSET..TAB_TEXT_SYNC_ID_OFFSET + MAX_TABS as u16)
+                    .contains(&sync_id) =>
+            {
+                if let Some(title) = titles.get(usize::from(sync_id - TAB_TEXT_SYNC_ID_OFFSET)) {
+                    ele.sync(Syncs::String(title.clone()));
+                }
+            }
+            _ => {}
+        });
+}
+
+pub fn sync_close_text(dom_controller: &mut DomController, is_confirming_close: bool) {
+    let close_text = if is_confirming_close {
+        "Discard?"
+    } else {
+        "Close"
+    };
+    dom_controller.root_node.traverse_dom_mut(&mut |ele| {
+        if ele.sync_id() == CLOSE_TEXT_SYNC_ID {
+            ele.sync(Syncs::String(close_text.to_string()));
+        }
+    });
+}
@@ -0,0 +1,192 @@
+use sfml::{graphics::RenderWindow, window::Event as SFMLEvent};
+use tracing::error;
+use utils::resource_manager::ResourceManager;
+
+use crate::{
+    dom_controller::{DomController, DomControllerInterface},
+    elements::traits::Element as ElementTrait,
+    events::{Event, EventId, Events},
+    syncs::{SyncId, Syncs},
+    ui_settings::UISettings,
+    ui_state::UIState,
+    xml_layout,
+};
+
+pub mod dialog_stack;
+
+const CONFIRM_EVENT_ID: EventId = 1;
+const CANCEL_EVENT_ID: EventId = 2;
+const TEXT_EVENT_ID: EventId = 3;
+const MESSAGE_SYNC_ID: SyncId = 1;
+const TEXT_SYNC_ID: SyncId = 2;
+
+/// How a [`Dialog`] was answered
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DialogResult {
+    /// The confirm dialog was answered with yes
+    Confirmed,
+    /// The prompt was answered with ok, and had this text in its text box
+    Submitted(String),
+    /// Answered with no or cancel, or closed with escape
+    Cancelled,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum DialogKind {
+    #[default]
+    Confirm,
+    Prompt,
+}
+
+/// A yes or no question, or a question answered with a line of text, shown over everything
+/// else with [`DomController::open_dialog`].
+///
+/// Open it with a message, then check [`Dialog::take_result`] after its events were handled
+#[derive(Debug, Default)]
+pub struct Dialog {
+    kind: DialogKind,
+    dialog_dom: DomController,
+    message: String,
+    /// Text in the text box of a prompt
+    text: String,
+    result: Option<DialogResult>,
+}
+
+impl Dialog {
+    pub fn confirm(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
+        Self::new(
+            DialogKind::Confirm,
+            DomController::from_layout(
+                resource_manager,
+                ui_settings,
+                xml_layout!("dialogs/confirm_dialog_content.xml"),
+            ),
+        )
+    }
+
+    pub fn prompt(resource_manager: &ResourceManager, ui_settings: &UISettings) -> Self {
+        Self::new(
+            DialogKind::Prompt,
+            DomController::from_layout(
+                resource_manager,
+                ui_settings,
+                xml_layout!("dialogs/prompt_dialog_content.xml"),
+            ),
+        )
+    }
+
+    fn new(kind: DialogKind, dialog_dom: DomController) -> Self {
+        Self {
+            kind,
+            dialog_dom,
+            message: Default::default(),
+            text: Default::default(),
+            result: None,
+        }
+    }
+
+    /// Shows the dialog with `message`. A prompt starts with `text` in its text box
+    pub fn open(&mut self, ui_state: &mut UIState, message: &str, text: &str) {
+        self.message = message.to_string();
+        self.text = text.to_string();
+        self.result = None;
+        self.sync_events();
+        self.dialog_dom.open_dialog(ui_state);
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.dialog_dom.is_dialog_open()
+    }
+
+    /// How the dialog was answered, once it was closed. Only returned once
+    pub fn take_result(&mut self) -> Option<DialogResult> {
+        self.result.take()
+    }
+
+    fn close(&mut self, ui_state: &mut UIState, result: DialogResult) {
+        self.dialog_dom.close_dialog(ui_state);
+        self.result = Some(result);
+    }
+
+    fn perform_events(&mut self, events: &[Event], ui_state: &mut UIState) {
+        for event in events {
+            match (event.id, &event.event) {
+                (CONFIRM_EVENT_ID, _) => {
+                    let result = match self.kind {
+                        DialogKind::Confirm => DialogResult::Confirmed,
+                        DialogKind::Prompt => DialogResult::Submitted(self.text.clone()),
+                    };
+                    self.close(ui_state, result);
+                }
+                (CANCEL_EVENT_ID, _) => self.close(ui_state, DialogResult::Cancelled),
+                (TEXT_EVENT_ID, Events::TextBoxEvent(text_box_event)) => {
+                    self.text = text_box_event.string.clone();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn sync_events(&mut self) {
+        let message = &self.message;
+        let text = &self.text;
+        self.dialog_dom
+            .root_node
+            .traverse_dom_mut(&mut |ele| match ele.sync_id() {
+                0 => {}
+                MESSAGE_SYNC_ID => ele.sync(Syncs::String(message.clone())),
+                TEXT_SYNC_ID => ele.sync(Syncs::String(text.clone())),
+                sync_id => error!("Dialog has no synchronization with sync_id {}", sync_id),
+            })
+    }
+}
+
+impl DomControllerInterface for Dialog {
+    fn event_handler(
+        &mut self,
+        window: &mut RenderWindow,
+        ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
+        event: SFMLEvent,
+    ) -> Vec<Event> {
+        if !self.is_open() && !matches!(event, SFMLEvent::Resized { .. }) {
+            return Default::default();
+        }
+
+        let was_open = self.is_open();
+        let events = self
+            .dialog_dom
+            .event_handler(window, ui_settings, ui_state, event);
+        // Closed with escape
+        if was_open && !self.is_open() {
+            self.result = Some(DialogResult::Cancelled);
+            return events;
+        }
+        self.perform_events(&events, ui_state);
+
+        events
+    }
+
+    fn update(&mut self, resource_manager: &ResourceManager) -> Vec<Event> {
+        if !self.is_open() {
+            return Default::default();
+        }
+
+        let events = self.dialog_dom.update(resource_manager);
+        if self.dialog_dom.take_reloaded() {
+            self.sync_events();
+        }
+        events
+    }
+
+    /// Only drawn by [`DomControllerInterface::render_dialog`], while it is open
+    fn render(&mut self, _window: &mut RenderWindow) {}
+
+    fn render_dialog(&mut self, window: &mut RenderWindow, dom_id: u64) {
+        self.dialog_dom.render_dialog(window, dom_id);
+    }
+
+    fn has_open_dialog(&self) -> bool {
+        self.is_open()
+    }
+}
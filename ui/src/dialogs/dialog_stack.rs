@@ -0,0 +1,87 @@
+use crate::ui_settings::controls::{possible_binds::PossibleBinds, Bindings};
+
+/// The [`DomController`]s opened as dialogs, bottom to top, across every dom. Only the top
+/// one gets input, every other dom only sees resizes. Escape closes the top one.
+///
+/// Keyed by dom id like [`Focus`](crate::dom_controller::focus::Focus). See
+/// [`DomController::open_dialog`]
+///
+/// [`DomController`]: crate::dom_controller::DomController
+/// [`DomController::open_dialog`]: crate::dom_controller::DomController::open_dialog
+#[derive(Clone, Debug, Default)]
+pub struct DialogStack {
+    dom_ids: Vec<u64>,
+    /// The dialog that escape was pressed on, during the event it was pressed in
+    escaped: Option<u64>,
+}
+
+impl DialogStack {
+    /// Expects `binds` to have already seen the event
+    pub fn event_handler(&mut self, binds: &Bindings) {
+        self.escaped = self
+            .dom_ids
+            .last()
+            .copied()
+            .filter(|_| binds.is_bind_just_pressed(PossibleBinds::Escape));
+    }
+
+    pub fn is_open(&self) -> bool {
+        !self.dom_ids.is_empty()
+    }
+
+    /// Ids of the open dialogs, from the bottom one to the top one
+    pub fn dom_ids(&self) -> &[u64] {
+        &self.dom_ids
+    }
+
+    /// True while a dialog is open, and during the event that closed the last one with
+    /// escape. Input handled outside of doms should be skipped then
+    pub fn blocks_input(&self) -> bool {
+        self.is_open() || self.escaped.is_some()
+    }
+
+    /// Whether the dom should handle input. Every dom does while no dialog is open
+    pub fn accepts_input(&self, dom_id: u64) -> bool {
+        match self.escaped {
+            Some(escaped) => escaped == dom_id,
+            None => self.dom_ids.last().map_or(true, |&top| top == dom_id),
+        }
+    }
+
+    /// Whether the dom is the top dialog and escape was just pressed
+    pub fn is_escaped(&self, dom_id: u64) -> bool {
+        self.escaped == Some(dom_id)
+    }
+
+    pub(crate) fn push(&mut self, dom_id: u64) {
+        self.remove(dom_id);
+        self.dom_ids.push(dom_id);
+    }
+
+    pub(crate) fn remove(&mut self, dom_id: u64) {
+        self.dom_ids.retain(|&id| id != dom_id);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_only_top_accepts_input() {
+        let mut dialog_stack = DialogStack::default();
+        assert!(dialog_stack.accepts_input(1));
+
+        dialog_stack.push(1);
+        dialog_stack.push(2);
+        assert!(!dialog_stack.accepts_input(1));
+        assert!(dialog_stack.accepts_input(2));
+        assert!(!dialog_stack.accepts_input(3));
+
+        dialog_stack.remove(2);
+        assert!(dialog_stack.accepts_input(1));
+        dialog_stack.remove(1);
+        assert!(!dialog_stack.blocks_input());
+        assert!(dialog_stack.accepts_input(3));
+    }
+}
@@ -2,22 +2,57 @@ use crate::{
     dom_loader::dom_loader,
     elements::{traits::Element as ElementTrait, Element},
     events::*,
-    ui_settings::UISettings,
+    ui_settings::{controls::possible_binds::PossibleBinds, UISettings},
+    ui_state::UIState,
 };
 use sfml::{
-    graphics::{Color, Rect, RenderTarget, RenderTexture, RenderWindow, Sprite, View},
+    graphics::{
+        Color, IntRect, Rect, RectangleShape, RenderTarget, RenderTexture, RenderWindow, Shape,
+        Sprite, View,
+    },
     window::Event as SFMLEvent,
     SfBox,
 };
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering},
+};
 use tracing::error;
 use utils::{resource_manager::ResourceManager, vector_to_rect_with_zeroed_origin};
 
+use self::{
+    focus::{actionable_element_bounds, focus_outline, with_nth_actionable_element},
+    hot_reload::{XmlLayout, XmlWatcher},
+    tooltip_hover::TooltipHover,
+};
+
+pub mod focus;
+pub mod hot_reload;
+pub mod text_input;
+pub mod tooltip_hover;
+
+static NEXT_DOM_ID: AtomicU64 = AtomicU64::new(1);
+/// Drawn over everything underneath an open dialog
+const DIALOG_BACKDROP_COLOR: Color = Color::rgba(0, 0, 0, 128);
+
 #[derive(Default, Debug)]
 pub struct DomController {
     pub root_node: Element,
     render_texture: Option<RenderTexture>,
     needs_rerender: bool,
     view: SfBox<View>,
+    /// Tells apart the doms when they share focus. See [`focus::Focus`]
+    id: u64,
+    /// Bounds of the focused element, if it is in this dom
+    focused_bounds: Option<IntRect>,
+    /// Only set while hot reloading, see [`DomController::from_layout`]
+    xml_watcher: Option<XmlWatcher>,
+    is_reloaded: bool,
+    /// Id of the element that emits each event id, for the elements that were given an id
+    event_element_ids: HashMap<EventId, String>,
+    tooltip_hover: TooltipHover,
+    /// Open as a dialog, see [`DomController::open_dialog`]
+    is_dialog: bool,
 }
 
 impl DomController {
@@ -37,12 +72,89 @@ impl DomController {
             view: View::from_rect(view_size),
             needs_rerender: true,
             render_texture: RenderTexture::new(view_size.width as u32, view_size.height as u32),
+            id: NEXT_DOM_ID.fetch_add(1, Ordering::Relaxed),
+            focused_bounds: None,
+            xml_watcher: None,
+            is_reloaded: false,
+            event_element_ids: Default::default(),
+            tooltip_hover: Default::default(),
+            is_dialog: false,
         };
+        dc.event_element_ids = event_element_ids(&mut dc.root_node);
         dc.reset_view(ui_settings);
 
         dc
     }
 
+    /// In debug builds run with [`HOT_RELOAD_ENV_VAR`](hot_reload::HOT_RELOAD_ENV_VAR) set,
+    /// the layout is read from the source tree instead, and the dom is rebuilt whenever the
+    /// file is saved. Components check [`DomController::take_reloaded`] to sync their state
+    /// back into the new dom.
+    ///
+    /// Use the [`crate::xml_layout`] macro to make the layout.
+    pub fn from_layout(
+        resource_manager: &ResourceManager,
+        ui_settings: &UISettings,
+        xml_layout: XmlLayout,
+    ) -> Self {
+        let Some((xml_watcher, xml_doc)) = XmlWatcher::new(&xml_layout) else {
+            return Self::new(resource_manager, ui_settings, xml_layout.embedded);
+        };
+
+        let mut dc = Self::new(resource_manager, ui_settings, &xml_doc);
+        dc.xml_watcher = Some(xml_watcher);
+        dc
+    }
+
+    /// True once after the layout was reloaded from disk. The new dom has none of the
+    /// state synced into the old one
+    pub fn take_reloaded(&mut self) -> bool {
+        std::mem::take(&mut self.is_reloaded)
+    }
+
+    fn reload_if_necessary(&mut self, resource_manager: &ResourceManager) {
+        let Some(xml_doc) = self.xml_watcher.as_mut().and_then(XmlWatcher::poll) else {
+            return;
+        };
+
+        let view_rect = Rect::from_vecs(Default::default(), self.view.size());
+        self.root_node =
+            Element::RootNode(dom_loader(resource_manager, view_rect.as_other(), &xml_doc));
+        self.event_element_ids = event_element_ids(&mut self.root_node);
+        self.focused_bounds = None;
+        self.tooltip_hover.hide();
+        self.needs_rerender = true;
+        self.is_reloaded = true;
+    }
+
+    /// The element given `id="..."` in the layout. Elements inside of buttons and sliders
+    /// can not be looked up
+    pub fn get_element_by_id(&mut self, id: &str) -> Option<&mut Element> {
+        self.root_node.find_by_id_mut(id)
+    }
+
+    /// Calls `f` with every element given `class="..."` in the layout
+    pub fn for_each_element_with_class<F: FnMut(&mut Element)>(&mut self, class: &str, mut f: F) {
+        self.root_node.traverse_identified_mut(&mut |ele| {
+            if ele.has_class(class) {
+                f(&mut ele.element);
+            }
+        });
+    }
+
+    /// Id of the element that emits events with `event_id`, if it was given one
+    pub fn element_id_of_event(&self, event_id: EventId) -> Option<&str> {
+        self.event_element_ids.get(&event_id).map(String::as_str)
+    }
+
+    /// Event id of the element given `id="..."` in the layout, if it emits events
+    pub fn event_id_of_element(&self, id: &str) -> Option<EventId> {
+        self.event_element_ids
+            .iter()
+            .find(|(_, element_id)| *element_id == id)
+            .map(|(&event_id, _)| event_id)
+    }
+
     pub fn reset_view(&mut self, ui_settings: &UISettings) -> Vec<Event> {
         let view_size =
             vector_to_rect_with_zeroed_origin!(f32, ui_settings.aspect_ratio.computed_resolution());
@@ -57,10 +169,123 @@ impl DomController {
         self.root_node.update_position(view_size.as_other());
 
         self.view = View::from_rect(view_size);
+        self.tooltip_hover.hide();
         self.needs_rerender = true;
 
         events
     }
+
+    /// Shows the dom over everything else, on a dimmed backdrop. Until it is closed, only
+    /// the top dialog gets input. See [`DialogStack`](crate::dialogs::dialog_stack::DialogStack)
+    ///
+    /// It is then drawn by [`DomControllerInterface::render_dialog`] instead of `render`
+    ///
+    /// Escape closes it, so owners check [`DomController::is_dialog_open`] after passing
+    /// it events
+    pub fn open_dialog(&mut self, ui_state: &mut UIState) {
+        ui_state.dialogs.push(self.id);
+        ui_state.focus.clear();
+        self.is_dialog = true;
+        self.needs_rerender = true;
+    }
+
+    pub fn close_dialog(&mut self, ui_state: &mut UIState) {
+        ui_state.dialogs.remove(self.id);
+        ui_state.text_input.set_typing(self.id, false);
+        self.is_dialog = false;
+        self.tooltip_hover.hide();
+        self.needs_rerender = true;
+    }
+
+    pub fn is_dialog_open(&self) -> bool {
+        self.is_dialog
+    }
+
+    /// Offers this dom's elements to a focus navigation in progress. If the focused element
+    /// is in this dom, it is outlined and shown hovered, and buttons and dropdowns are
+    /// activated with Select.
+    fn focus_event_handler(
+        &mut self,
+        ui_settings: &UISettings,
+        ui_state: &mut UIState,
+    ) -> Vec<Event> {
+        if ui_state.focus.is_navigating() {
+            let bounds = actionable_element_bounds(&mut self.root_node);
+            ui_state.focus.offer_candidates(self.id, &bounds);
+        }
+        let focused = ui_state.focus.focused_element(self.id);
+        let focused_bounds = focused.map(|focused| focused.bounds);
+        if self.focused_bounds != focused_bounds {
+            self.focused_bounds = focused_bounds;
+            self.needs_rerender = true;
+        }
+        let Some(focused) = focused else {
+            return Vec::new();
+        };
+
+        let binds = &ui_settings.binds;
+        let mut events = Vec::new();
+        let mut is_activated = false;
+        with_nth_actionable_element(&mut self.root_node, focused.element_idx, |ele| {
+            let is_button = matches!(ele, Element::Button(_));
+            let is_activatable = is_button || matches!(ele, Element::Dropdown(_));
+            let Some(actionable) = ele.actionable_element_mut() else {
+                return;
+            };
+            // The dom changed since the element was focused
+            if actionable.global_bounds() != focused.bounds {
+                return;
+            }
+
+            actionable.set_hover(focused.center());
+            if is_activatable && binds.is_bind_just_pressed(PossibleBinds::Select) {
+                actionable.bind_pressed(focused.center());
+                is_activated = true;
+            } else if is_activatable && binds.is_bind_just_released(PossibleBinds::Select) {
+                actionable.bind_released(focused.center());
+                if is_button {
+                    events.push(actionable.triggered_event());
+                }
+                is_activated = true;
+            }
+        });
+        self.needs_rerender |= is_activated;
+
+        events
+    }
+
+    /// Draws the dom, redrawing it into its render texture first if needed
+    fn draw(&mut self, window: &mut RenderWindow) {
+        if self.needs_rerender {
+            self.render_texture =
+                RenderTexture::new(self.view.size().x as u32, self.view.size().y as u32);
+            if let Some(render_texture) = &mut self.render_texture {
+                render_texture.set_smooth(false);
+                render_texture.clear(Color::TRANSPARENT);
+                render_texture.set_view(&self.view);
+                if self.is_dialog {
+                    let mut backdrop = RectangleShape::with_size(self.view.size());
+                    backdrop.set_fill_color(DIALOG_BACKDROP_COLOR);
+                    render_texture.draw(&backdrop);
+                }
+                self.root_node.render(render_texture);
+                render_dropdown_popups(&mut self.root_node, render_texture);
+                if let Some(focused_bounds) = self.focused_bounds {
+                    render_texture.draw(&focus_outline(focused_bounds));
+                }
+                self.tooltip_hover.render(render_texture);
+                render_texture.display();
+                self.needs_rerender = false;
+            } else {
+                error!("Failed to create render texture!");
+            }
+        }
+
+        if let Some(render_texture) = &self.render_texture {
+            window.set_view(&self.view);
+            window.draw(&Sprite::with_texture(render_texture.texture()));
+        }
+    }
 }
 
 impl DomControllerInterface for DomController {
@@ -68,48 +293,145 @@ impl DomControllerInterface for DomController {
         &mut self,
         _window: &mut RenderWindow,
         ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
         event: SFMLEvent,
     ) -> Vec<Event> {
         match event {
             SFMLEvent::Resized { .. } => self.reset_view(ui_settings),
+            _ if !ui_state.dialogs.accepts_input(self.id) => {
+                self.needs_rerender |= self.tooltip_hover.hide();
+                Vec::new()
+            }
+            _ if ui_state.dialogs.is_escaped(self.id) => {
+                self.close_dialog(ui_state);
+                Vec::new()
+            }
             _ => {
-                let events = self.root_node.event_handler(ui_settings, event);
+                // An open dropdown is drawn over everything else, so it is the only one to
+                // see events, and the UI binds move through its options instead of focus
+                if let Some((events, rerender)) =
+                    open_dropdown_event_handler(&mut self.root_node, ui_settings, event)
+                {
+                    self.needs_rerender |= rerender | self.tooltip_hover.hide();
+                    ui_state.focus.cancel_navigation();
+                    return events;
+                }
+                // Keys and the gamepad go to the focused element instead, which is
+                // activated in focus_event_handler. A text box being typed in still gets them
+                let is_focus_input = ui_state.focus.has_focus()
+                    && is_key_or_joystick_event(event)
+                    && !has_selected_text_box(&mut self.root_node);
+                let mut events = if is_focus_input {
+                    Default::default()
+                } else {
+                    self.root_node.event_handler(ui_settings, event)
+                };
                 self.needs_rerender |= events.1;
+                ui_state
+                    .text_input
+                    .set_typing(self.id, has_selected_text_box(&mut self.root_node));
+                events
+                    .0
+                    .append(&mut self.focus_event_handler(ui_settings, ui_state));
+                self.needs_rerender |= self.tooltip_hover.event_handler(
+                    &mut self.root_node,
+                    ui_settings.cursor_position,
+                    event,
+                );
                 events.0
             }
         }
     }
 
     fn update(&mut self, resource_manager: &ResourceManager) -> Vec<Event> {
+        self.reload_if_necessary(resource_manager);
         let events = self.root_node.update(resource_manager);
         self.needs_rerender |= events.1;
+        let view_rect = Rect::from_vecs(Default::default(), self.view.size());
+        self.needs_rerender |= self
+            .tooltip_hover
+            .update(&mut self.root_node, view_rect.as_other());
 
         events.0
     }
 
+    /// Dialogs are only drawn by [`DomControllerInterface::render_dialog`]
     fn render(&mut self, window: &mut RenderWindow) {
-        if self.needs_rerender {
-            self.render_texture =
-                RenderTexture::new(self.view.size().x as u32, self.view.size().y as u32);
-            if let Some(render_texture) = &mut self.render_texture {
-                render_texture.set_smooth(false);
-                render_texture.clear(Color::TRANSPARENT);
-                render_texture.set_view(&self.view);
-                self.root_node.render(render_texture);
-                render_texture.display();
-                self.needs_rerender = false;
-            } else {
-                error!("Failed to create render texture!");
-            }
+        if !self.is_dialog {
+            self.draw(window);
         }
+    }
 
-        if let Some(render_texture) = &self.render_texture {
-            window.set_view(&self.view);
-            window.draw(&Sprite::with_texture(render_texture.texture()));
+    fn render_dialog(&mut self, window: &mut RenderWindow, dom_id: u64) {
+        if self.is_dialog && self.id == dom_id {
+            self.draw(window);
         }
     }
 }
 
+/// Passes `event` to the open dropdown of the dom, if there is one
+fn open_dropdown_event_handler(
+    root_node: &mut Element,
+    ui_settings: &UISettings,
+    event: SFMLEvent,
+) -> Option<(Vec<Event>, bool)> {
+    let mut handled = None;
+    root_node.traverse_dom_mut(&mut |ele| {
+        if let Element::Dropdown(dropdown) = ele {
+            if handled.is_none() && dropdown.is_open() {
+                handled = Some(dropdown.event_handler(ui_settings, event));
+            }
+        }
+    });
+
+    handled
+}
+
+fn is_key_or_joystick_event(event: SFMLEvent) -> bool {
+    matches!(
+        event,
+        SFMLEvent::KeyPressed { .. }
+            | SFMLEvent::KeyReleased { .. }
+            | SFMLEvent::JoystickButtonPressed { .. }
+            | SFMLEvent::JoystickButtonReleased { .. }
+            | SFMLEvent::JoystickMoved { .. }
+    )
+}
+
+fn has_selected_text_box(root_node: &mut Element) -> bool {
+    let mut is_selected = false;
+    root_node.traverse_dom_mut(&mut |ele| {
+        if let Element::TextBox(text_box) = ele {
+            is_selected |= text_box.is_selected();
+        }
+    });
+
+    is_selected
+}
+
+/// Draws the options of open dropdowns over the rest of the dom
+fn render_dropdown_popups(root_node: &mut Element, render_texture: &mut RenderTexture) {
+    root_node.traverse_dom_mut(&mut |ele| {
+        if let Element::Dropdown(dropdown) = ele {
+            dropdown.render_popup(render_texture);
+        }
+    });
+}
+
+fn event_element_ids(root_node: &mut Element) -> HashMap<EventId, String> {
+    let mut event_element_ids = HashMap::new();
+    root_node.traverse_identified_mut(&mut |ele| {
+        let (Some(id), Some(actionable)) = (&ele.id, ele.element.actionable_element_mut()) else {
+            return;
+        };
+        if actionable.event_id() != 0 {
+            event_element_ids.insert(actionable.event_id(), id.clone());
+        }
+    });
+
+    event_element_ids
+}
+
 pub trait DomControllerInterface {
     fn render(&mut self, window: &mut RenderWindow);
     fn update(&mut self, resource_manager: &ResourceManager) -> Vec<Event>;
@@ -117,6 +439,19 @@ pub trait DomControllerInterface {
         &mut self,
         window: &mut RenderWindow,
         ui_settings: &mut UISettings,
+        ui_state: &mut UIState,
         event: SFMLEvent,
     ) -> Vec<Event>;
+
+    /// Draws the dom `dom_id`, if it is one of the open dialogs of this interface. Called
+    /// after every interface was rendered, for each open dialog from the bottom of the
+    /// [`DialogStack`](crate::dialogs::dialog_stack::DialogStack) to the top, so a dialog
+    /// opened from another one is drawn over it
+    fn render_dialog(&mut self, _window: &mut RenderWindow, _dom_id: u64) {}
+
+    /// Whether one of the doms is open as a dialog. While any dialog is open, only the
+    /// interfaces that have one are given events
+    fn has_open_dialog(&self) -> bool {
+        false
+    }
 }
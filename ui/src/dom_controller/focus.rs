@@ -0,0 +1,342 @@
+use crate::{
+    elements::{traits::Element as ElementTrait, Element},
+    ui_settings::controls::{possible_binds::PossibleBinds, Bindings},
+};
+use sfml::{
+    graphics::{Color, IntRect, RectangleShape, Shape, Transformable},
+    system::Vector2i,
+    window::Event as SFMLEvent,
+};
+
+const FOCUS_OUTLINE_COLOR: Color = Color::rgb(255, 214, 10);
+const FOCUS_OUTLINE_THICKNESS: f32 = 2.;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FocusDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl FocusDirection {
+    const BINDS: [(PossibleBinds, FocusDirection); 4] = [
+        (PossibleBinds::UIUp, FocusDirection::Up),
+        (PossibleBinds::UIDown, FocusDirection::Down),
+        (PossibleBinds::UILeft, FocusDirection::Left),
+        (PossibleBinds::UIRight, FocusDirection::Right),
+    ];
+
+    fn unit(self) -> Vector2i {
+        match self {
+            FocusDirection::Up => Vector2i::new(0, -1),
+            FocusDirection::Down => Vector2i::new(0, 1),
+            FocusDirection::Left => Vector2i::new(-1, 0),
+            FocusDirection::Right => Vector2i::new(1, 0),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FocusedElement {
+    pub dom_id: u64,
+    /// Position of the element among the actionable elements of its dom, in traversal order
+    pub element_idx: usize,
+    pub bounds: IntRect,
+}
+
+impl FocusedElement {
+    pub fn center(&self) -> Vector2i {
+        rect_center(self.bounds)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Navigation {
+    Direction(FocusDirection),
+    /// Tab order, which is the order the elements were offered in
+    Next,
+    Previous,
+}
+
+#[derive(Clone, Debug)]
+struct PendingNavigation {
+    navigation: Navigation,
+    from: Vector2i,
+    closest: Option<(i32, FocusedElement)>,
+    offered: Vec<FocusedElement>,
+}
+
+/// Tracks the one actionable element that has focus, across every [`DomController`].
+///
+/// Moving focus takes two steps. While an event is handled, every dom offers its elements
+/// with [`Focus::offer_candidates`]. Once every dom has seen the event,
+/// [`Focus::finish_navigation`] moves focus to the closest one, or to the next one in tab
+/// order.
+///
+/// [`DomController`]: super::DomController
+#[derive(Clone, Debug, Default)]
+pub struct Focus {
+    focused: Option<FocusedElement>,
+    pending: Option<PendingNavigation>,
+}
+
+impl Focus {
+    /// Starts a navigation when tab or a UI direction bind is pressed. Using the mouse or
+    /// pressing escape drops focus.
+    ///
+    /// The arrow keys only move focus once something has it, so they can still pan the view.
+    /// The gamepad always moves focus.
+    ///
+    /// Expects `binds` to have already seen `event`
+    pub fn event_handler(&mut self, binds: &Bindings, cursor_position: Vector2i, event: SFMLEvent) {
+        if matches!(
+            event,
+            SFMLEvent::MouseMoved { .. } | SFMLEvent::MouseButtonPressed { .. }
+        ) || binds.is_bind_just_pressed(PossibleBinds::Escape)
+        {
+            self.clear();
+            return;
+        }
+
+        let is_joystick_event = matches!(
+            event,
+            SFMLEvent::JoystickMoved { .. } | SFMLEvent::JoystickButtonPressed { .. }
+        );
+        let navigation = if binds.is_shortcut_pressed(event, PossibleBinds::FocusNext) {
+            Navigation::Next
+        } else if binds.is_shortcut_pressed(event, PossibleBinds::FocusPrevious) {
+            Navigation::Previous
+        } else if let Some(&(_, direction)) = FocusDirection::BINDS
+            .iter()
+            .find(|(bind, _)| binds.is_bind_just_pressed(*bind))
+            .filter(|_| self.focused.is_some() || is_joystick_event)
+        {
+            Navigation::Direction(direction)
+        } else {
+            return;
+        };
+        self.pending = Some(PendingNavigation {
+            navigation,
+            from: self
+                .focused
+                .map_or(cursor_position, |focused| focused.center()),
+            closest: None,
+            offered: Vec::new(),
+        });
+    }
+
+    pub fn is_navigating(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    pub fn has_focus(&self) -> bool {
+        self.focused.is_some()
+    }
+
+    /// The focused element, if it belongs to the dom `dom_id`
+    pub fn focused_element(&self, dom_id: u64) -> Option<FocusedElement> {
+        self.focused.filter(|focused| focused.dom_id == dom_id)
+    }
+
+    /// Offers the bounds of every actionable element of a dom to the navigation in progress
+    pub fn offer_candidates(&mut self, dom_id: u64, candidates: &[IntRect]) {
+        let Some(pending) = &mut self.pending else {
+            return;
+        };
+
+        for (element_idx, &bounds) in candidates.iter().enumerate() {
+            let candidate = FocusedElement {
+                dom_id,
+                element_idx,
+                bounds,
+            };
+            let Navigation::Direction(direction) = pending.navigation else {
+                pending.offered.push(candidate);
+                continue;
+            };
+            let Some(score) = navigation_score(pending.from, direction, bounds) else {
+                continue;
+            };
+            if pending
+                .closest
+                .is_some_and(|(closest_score, _)| closest_score <= score)
+            {
+                continue;
+            }
+            pending.closest = Some((score, candidate));
+        }
+    }
+
+    /// Moves focus to the element picked among the ones offered. Focus stays put if nothing
+    /// was in the way.
+    ///
+    /// Returns true if focus moved
+    pub fn finish_navigation(&mut self) -> bool {
+        let Some(pending) = self.pending.take() else {
+            return false;
+        };
+
+        let next_focused = match pending.navigation {
+            Navigation::Direction(_) => pending.closest.map(|(_, closest)| closest),
+            Navigation::Next | Navigation::Previous => {
+                next_in_tab_order(&pending.offered, self.focused, pending.navigation)
+            }
+        };
+        let Some(next_focused) = next_focused else {
+            return false;
+        };
+        self.focused = Some(next_focused);
+        true
+    }
+
+    /// Drops the navigation in progress, for when an element used the event itself
+    pub fn cancel_navigation(&mut self) {
+        self.pending = None;
+    }
+
+    pub fn clear(&mut self) {
+        self.focused = None;
+        self.pending = None;
+    }
+}
+
+/// The element after, or before, `focused` in `offered`. Wraps around at both ends
+fn next_in_tab_order(
+    offered: &[FocusedElement],
+    focused: Option<FocusedElement>,
+    navigation: Navigation,
+) -> Option<FocusedElement> {
+    if offered.is_empty() {
+        return None;
+    }
+
+    let focused_idx = focused.and_then(|focused| {
+        offered.iter().position(|element| {
+            element.dom_id == focused.dom_id && element.element_idx == focused.element_idx
+        })
+    });
+    let next_idx = match (focused_idx, navigation) {
+        (Some(idx), Navigation::Previous) => (idx + offered.len() - 1) % offered.len(),
+        (Some(idx), _) => (idx + 1) % offered.len(),
+        (None, Navigation::Previous) => offered.len() - 1,
+        (None, _) => 0,
+    };
+
+    offered.get(next_idx).copied()
+}
+
+/// Outline drawn around the focused element
+pub(crate) fn focus_outline(bounds: IntRect) -> RectangleShape<'static> {
+    let mut outline = RectangleShape::with_size(bounds.size().as_other());
+    outline.set_position(bounds.position().as_other());
+    outline.set_fill_color(Color::TRANSPARENT);
+    outline.set_outline_color(FOCUS_OUTLINE_COLOR);
+    outline.set_outline_thickness(FOCUS_OUTLINE_THICKNESS);
+
+    outline
+}
+
+fn rect_center(rect: IntRect) -> Vector2i {
+    Vector2i::new(rect.left + rect.width / 2, rect.top + rect.height / 2)
+}
+
+/// How far `to` is from `from` when moving in `direction`. Straying sideways costs twice as
+/// much as going forward. None if `to` is not in that direction at all
+fn navigation_score(from: Vector2i, direction: FocusDirection, to: IntRect) -> Option<i32> {
+    let offset = rect_center(to) - from;
+    let unit = direction.unit();
+    let forward = offset.x * unit.x + offset.y * unit.y;
+    let sideways = (offset.x * unit.y - offset.y * unit.x).abs();
+    if forward <= 0 {
+        return None;
+    }
+
+    Some(forward + 2 * sideways)
+}
+
+/// Bounds of every actionable element of the dom, in traversal order
+pub(crate) fn actionable_element_bounds(root_node: &mut Element) -> Vec<IntRect> {
+    let mut bounds = Vec::new();
+    root_node.traverse_dom_mut(&mut |ele| {
+        if let Some(actionable) = ele.actionable_element_mut() {
+            bounds.push(actionable.global_bounds());
+        }
+    });
+
+    bounds
+}
+
+/// Runs `f` on the `n`th actionable element of the dom, if there is one
+pub(crate) fn with_nth_actionable_element<F: FnOnce(&mut Element)>(
+    root_node: &mut Element,
+    n: usize,
+    f: F,
+) {
+    let mut f = Some(f);
+    let mut idx = 0;
+    root_node.traverse_dom_mut(&mut |ele| {
+        if ele.actionable_element_mut().is_none() {
+            return;
+        }
+        if idx == n {
+            if let Some(f) = f.take() {
+                f(ele);
+            }
+        }
+        idx += 1;
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_navigation_picks_closest_element_in_direction() {
+        let mut focus = Focus {
+            focused: None,
+            pending: Some(PendingNavigation {
+                navigation: Navigation::Direction(FocusDirection::Right),
+                from: Vector2i::new(0, 0),
+                closest: None,
+                offered: Vec::new(),
+            }),
+        };
+
+        focus.offer_candidates(
+            1,
+            &[
+                IntRect::new(-110, -10, 20, 20),
+                IntRect::new(190, -10, 20, 20),
+            ],
+        );
+        focus.offer_candidates(2, &[IntRect::new(90, 60, 20, 20)]);
+        assert!(focus.finish_navigation());
+        assert_eq!(focus.focused_element(1).map(|f| f.element_idx), Some(1));
+        assert!(focus.focused_element(2).is_none());
+    }
+
+    #[test]
+    fn test_tab_order_wraps_around_between_doms() {
+        let element = |dom_id, element_idx| FocusedElement {
+            dom_id,
+            element_idx,
+            bounds: Default::default(),
+        };
+        let offered = [element(1, 0), element(1, 1), element(2, 0)];
+
+        assert_eq!(
+            next_in_tab_order(&offered, Some(element(1, 1)), Navigation::Next),
+            Some(element(2, 0))
+        );
+        assert_eq!(
+            next_in_tab_order(&offered, Some(element(2, 0)), Navigation::Next),
+            Some(element(1, 0))
+        );
+        assert_eq!(
+            next_in_tab_order(&offered, None, Navigation::Previous),
+            Some(element(2, 0))
+        );
+    }
+}
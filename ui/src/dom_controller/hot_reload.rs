@@ -0,0 +1,156 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+use tracing::{info, warn};
+
+/// How often the layout file is checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Turns hot reloading on in debug builds, when set to anything but `0`
+pub const HOT_RELOAD_ENV_VAR: &str = "PACRB_HOT_RELOAD";
+
+/// Embeds an xml layout like `include_str!`, and remembers where it lives in the source
+/// tree so it can be reloaded when it is edited. See [`XmlWatcher`]
+///
+/// # Usage
+///
+/// ```ignore
+/// let dom = DomController::from_layout(
+///     resource_manager,
+///     ui_settings,
+///     xml_layout!("minimap/minimap_content.xml"),
+/// );
+/// ```
+#[macro_export]
+macro_rules! xml_layout {
+    ($path: literal) => {
+        $crate::dom_controller::hot_reload::XmlLayout::new(
+            include_str!($path),
+            env!("CARGO_MANIFEST_DIR"),
+            file!(),
+            $path,
+        )
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct XmlLayout {
+    pub embedded: &'static str,
+    manifest_dir: &'static str,
+    /// File the layout was included from, as given by `file!()`
+    caller_file: &'static str,
+    /// Relative to `caller_file`
+    path: &'static str,
+}
+
+impl XmlLayout {
+    pub const fn new(
+        embedded: &'static str,
+        manifest_dir: &'static str,
+        caller_file: &'static str,
+        path: &'static str,
+    ) -> Self {
+        Self {
+            embedded,
+            manifest_dir,
+            caller_file,
+            path,
+        }
+    }
+
+    /// `file!()` is relative to the workspace root, which is the manifest dir or one of its
+    /// ancestors. None if the source tree is not around anymore
+    fn source_path(&self) -> Option<PathBuf> {
+        let relative_path = Path::new(self.caller_file).parent()?.join(self.path);
+        Path::new(self.manifest_dir)
+            .ancestors()
+            .map(|dir| dir.join(&relative_path))
+            .find(|path| path.is_file())
+    }
+}
+
+/// Polls the modification time of a layout in the source tree, to rebuild its dom when it
+/// is saved. Only exists in debug builds run with [`HOT_RELOAD_ENV_VAR`] set, everything
+/// else uses the embedded copy.
+#[derive(Debug)]
+pub struct XmlWatcher {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    last_poll: Instant,
+}
+
+impl XmlWatcher {
+    /// Also returns the layout as it is on disk, which may be newer than the embedded copy
+    pub fn new(xml_layout: &XmlLayout) -> Option<(Self, String)> {
+        if !is_hot_reload_enabled() {
+            return None;
+        }
+
+        let path = xml_layout.source_path()?;
+        let xml_doc = fs::read_to_string(&path).ok()?;
+        let watcher = Self {
+            modified: modified(&path),
+            path,
+            last_poll: Instant::now(),
+        };
+
+        Some((watcher, xml_doc))
+    }
+
+    /// New contents of the layout, if it changed since it was last read
+    pub fn poll(&mut self) -> Option<String> {
+        if self.last_poll.elapsed() < POLL_INTERVAL {
+            return None;
+        }
+        self.last_poll = Instant::now();
+
+        let modified = modified(&self.path);
+        if modified == self.modified {
+            return None;
+        }
+        self.modified = modified;
+
+        match fs::read_to_string(&self.path) {
+            Ok(xml_doc) => {
+                info!("Reloading {}", self.path.display());
+                Some(xml_doc)
+            }
+            Err(err) => {
+                warn!("Failed to reload {}: {}", self.path.display(), err);
+                None
+            }
+        }
+    }
+}
+
+/// Whether layouts are read from the source tree, instead of the embedded copies
+fn is_hot_reload_enabled() -> bool {
+    cfg!(debug_assertions)
+        && env::var_os(HOT_RELOAD_ENV_VAR).is_some_and(|value| !value.is_empty() && value != "0")
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_source_path() {
+        let xml_layout = xml_layout!("../../Cargo.toml");
+        assert_eq!(
+            xml_layout
+                .source_path()
+                .map(|path| fs::read_to_string(path).expect("unit-test")),
+            Some(xml_layout.embedded.to_string())
+        );
+
+        let missing = XmlLayout::new("", env!("CARGO_MANIFEST_DIR"), file!(), "missing.xml");
+        assert_eq!(missing.source_path(), None);
+    }
+}
@@ -0,0 +1,43 @@
+/// The doms that have a selected text box, across every [`DomController`]. Key presses go
+/// to the text box then, so shortcuts handled outside of doms should be skipped.
+///
+/// Keyed by dom id like [`Focus`](super::focus::Focus)
+///
+/// [`DomController`]: super::DomController
+#[derive(Clone, Debug, Default)]
+pub struct TextInput {
+    dom_ids: Vec<u64>,
+}
+
+impl TextInput {
+    pub fn is_typing(&self) -> bool {
+        !self.dom_ids.is_empty()
+    }
+
+    pub(crate) fn set_typing(&mut self, dom_id: u64, is_typing: bool) {
+        self.dom_ids.retain(|&id| id != dom_id);
+        if is_typing {
+            self.dom_ids.push(dom_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_typing_until_every_dom_stops() {
+        let mut text_input = TextInput::default();
+        assert!(!text_input.is_typing());
+
+        text_input.set_typing(1, true);
+        text_input.set_typing(2, true);
+        text_input.set_typing(1, true);
+        text_input.set_typing(1, false);
+        assert!(text_input.is_typing());
+
+        text_input.set_typing(2, false);
+        assert!(!text_input.is_typing());
+    }
+}
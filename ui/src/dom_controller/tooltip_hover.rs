@@ -0,0 +1,120 @@
+use std::time::Instant;
+
+use sfml::{
+    graphics::{IntRect, RenderTexture},
+    system::Vector2i,
+    window::Event as SFMLEvent,
+};
+
+use crate::{
+    elements::{tooltip::Tooltip, traits::Element as ElementTrait, Element},
+    utils::animation_constants::TOOLTIP_DELAY,
+};
+
+#[derive(Clone, Copy, Debug)]
+struct Hovered {
+    bounds: IntRect,
+    cursor_position: Vector2i,
+    since: Instant,
+}
+
+/// Shows the tooltip of the hovered element once the cursor has rested on it for
+/// [`TOOLTIP_DELAY`]. Pressing anything hides it, and it shows again after the cursor moves
+/// and rests once more
+#[derive(Debug, Default)]
+pub struct TooltipHover {
+    hovered: Option<Hovered>,
+    shown: Option<Tooltip>,
+}
+
+impl TooltipHover {
+    /// Follows the hovered element after the dom handled `event`. Returns whether a shown
+    /// tooltip was hidden
+    pub fn event_handler(
+        &mut self,
+        root_node: &mut Element,
+        cursor_position: Vector2i,
+        event: SFMLEvent,
+    ) -> bool {
+        let is_dismissed = matches!(
+            event,
+            SFMLEvent::MouseButtonPressed { .. }
+                | SFMLEvent::KeyPressed { .. }
+                | SFMLEvent::MouseWheelScrolled { .. }
+        );
+        let bounds = hovered_tooltip_bounds(root_node).filter(|_| !is_dismissed);
+
+        if let Some(hovered) = self
+            .hovered
+            .as_mut()
+            .filter(|hovered| Some(hovered.bounds) == bounds)
+        {
+            hovered.cursor_position = cursor_position;
+            return false;
+        }
+
+        self.hovered = bounds.map(|bounds| Hovered {
+            bounds,
+            cursor_position,
+            since: Instant::now(),
+        });
+        self.hide()
+    }
+
+    /// Shows the tooltip once its element was hovered long enough. Returns whether it was
+    /// shown
+    pub fn update(&mut self, root_node: &mut Element, view_rect: IntRect) -> bool {
+        let Some(hovered) = self.hovered else {
+            return false;
+        };
+        if self.shown.is_some() || hovered.since.elapsed() < TOOLTIP_DELAY {
+            return false;
+        }
+
+        let mut tooltip = None;
+        root_node.traverse_identified_mut(&mut |ele| {
+            if tooltip.is_none() && hovered_bounds(&mut ele.element) == Some(hovered.bounds) {
+                tooltip = ele.tooltip.clone();
+            }
+        });
+        let Some(mut tooltip) = tooltip else {
+            // The element is gone, the dom was reloaded or changed sets
+            self.hovered = None;
+            return false;
+        };
+
+        tooltip.place(hovered.bounds, hovered.cursor_position, view_rect);
+        self.shown = Some(tooltip);
+        true
+    }
+
+    /// Returns whether a tooltip was shown
+    pub fn hide(&mut self) -> bool {
+        self.shown.take().is_some()
+    }
+
+    pub fn render(&mut self, render_texture: &mut RenderTexture) {
+        if let Some(tooltip) = &mut self.shown {
+            tooltip.render(render_texture);
+        }
+    }
+}
+
+/// Bounds of the hovered element that has a tooltip
+fn hovered_tooltip_bounds(root_node: &mut Element) -> Option<IntRect> {
+    let mut bounds = None;
+    root_node.traverse_identified_mut(&mut |ele| {
+        if bounds.is_none() && ele.tooltip.is_some() {
+            bounds = hovered_bounds(&mut ele.element);
+        }
+    });
+
+    bounds
+}
+
+fn hovered_bounds(element: &mut Element) -> Option<IntRect> {
+    element
+        .actionable_element_mut()
+        .filter(|actionable| actionable.is_hover())
+        .map(|actionable| actionable.global_bounds())
+}
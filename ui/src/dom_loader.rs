@@ -1,6 +1,7 @@
 use crate::elements::{root_node::RootNode, traits::Element as ElementTrait};
 use ::utils::resource_manager::ResourceManager;
 use element_loader::*;
+use identified_loader::assign_named_event_ids;
 use sfml::graphics::{Color, IntRect};
 use std::error::Error;
 use tracing::{error, warn};
@@ -10,19 +11,25 @@ use self::utils::{get_color_attribute, get_font_size, get_scale};
 mod background_loader;
 mod button_loader;
 mod div_loader;
+mod dropdown_loader;
 mod element_loader;
 mod grid_loader;
+mod identified_loader;
 mod image_loader;
 mod listbox_loader;
 mod missing_texture_loader;
 mod primitive_loader;
+mod scroll_view_loader;
 mod sets_loader;
 mod slider_loader;
 mod text_loader;
 mod textbox_loader;
 mod utils;
+pub mod validation;
 
 /// This function is how loading ui elements from an xml document will work. Returns empty document on failure.
+/// In debug builds, problems found by [`validate_xml`](validation::validate_xml) are logged as
+/// warnings.
 ///
 /// # Args
 /// - resource_manager: ResourceManager,
@@ -50,6 +57,13 @@ pub fn dom_loader(
     relative_rect: IntRect,
     xml_doc: &str,
 ) -> RootNode {
+    // Layouts only change during development, so release builds skip checking them
+    if cfg!(debug_assertions) {
+        for diagnostic in validation::validate_xml(xml_doc) {
+            warn!("Invalid xml layout at {}", diagnostic);
+        }
+    }
+
     let mut root_node =
         try_page_loader(resource_manager, relative_rect, xml_doc).unwrap_or_else(|e| {
             error!("Error loading page: {:#?}", e);
@@ -70,7 +84,8 @@ fn try_page_loader(
     relative_rect: IntRect,
     xml_doc: &str,
 ) -> Result<RootNode, Box<dyn Error>> {
-    let root_node = xml_doc.parse()?;
+    let mut root_node = xml_doc.parse()?;
+    assign_named_event_ids(&mut root_node);
     let default_scale = get_scale(&root_node).unwrap_or_else(|err| {
         warn!(
             "No default scale in root node! Exact error: {:?} Setting to {}",
@@ -53,8 +53,8 @@ fn repeatable_3x3_background(
     ))
 }
 
-const REPEATABLE_3X3_BACKGROUND: &str = "Repeatable3x3Background";
-const BACKGROUND_TYPES: [&str; 1] = [REPEATABLE_3X3_BACKGROUND];
+pub(super) const REPEATABLE_3X3_BACKGROUND: &str = "Repeatable3x3Background";
+pub(super) const BACKGROUND_TYPES: [&str; 1] = [REPEATABLE_3X3_BACKGROUND];
 pub fn background_loader(
     resource_manager: &ResourceManager,
     minidom_element: &MinidomElement,
@@ -1,10 +1,14 @@
 use super::{element_loader::element_loader, utils::*};
 use crate::elements::button::{
-    boolean_image_button::BooleanImageButton, image_button::ImageButton,
-    primitive_fill_button::PrimitiveFillButton, tiling_text_button::TilingButton, traits::Button,
+    boolean_image_button::BooleanImageButton, color_swatch_button::ColorSwatchButton,
+    image_button::ImageButton, primitive_fill_button::PrimitiveFillButton,
+    tiling_text_button::TilingButton, traits::Button,
 };
 use minidom::Element as MinidomElement;
-use sfml::{graphics::Color, system::Vector2f};
+use sfml::{
+    graphics::Color,
+    system::{Vector2f, Vector2u},
+};
 use std::error::Error;
 use tracing::error;
 use utils::{
@@ -224,15 +228,40 @@ fn primitive_fill_button(
     }
 }
 
-const TILING_BUTTON: &str = "TilingButton";
-const IMAGE_BUTTON: &str = "ImageButton";
-const BOOLEAN_IMAGE_BUTTON: &str = "BooleanImageButton";
-const PRIMITIVE_FILL_BUTTON: &str = "PrimitiveFillButton";
-const BUTTON_STYLES: [&str; 4] = [
+const DEFAULT_COLOR_SWATCH_SIZE: Vector2u = Vector2u::new(16, 16);
+
+/// # Usage
+///
+/// ## Required:
+/// - type ([`COLOR_SWATCH_BUTTON`])
+///
+/// ## Optional:
+/// - position ([`UIPosition`](crate::utils::positioning::UIPosition))
+/// - size ([`Vector2u`]). Defaults to 16x16
+/// - color ([`Color`]). Empty if not set
+/// - event_id ([`u16`])
+/// - sync_id ([`u16`])
+fn color_swatch_button_loader(minidom_element: &MinidomElement) -> ColorSwatchButton {
+    ColorSwatchButton::new(
+        get_ui_position(minidom_element).unwrap_or_default(),
+        get_size(minidom_element).unwrap_or(DEFAULT_COLOR_SWATCH_SIZE),
+        get_color_attribute(minidom_element).ok(),
+        get_event_id_or_default(minidom_element),
+        get_sync_id_or_default(minidom_element),
+    )
+}
+
+pub(super) const TILING_BUTTON: &str = "TilingButton";
+pub(super) const IMAGE_BUTTON: &str = "ImageButton";
+pub(super) const BOOLEAN_IMAGE_BUTTON: &str = "BooleanImageButton";
+pub(super) const PRIMITIVE_FILL_BUTTON: &str = "PrimitiveFillButton";
+pub(super) const COLOR_SWATCH_BUTTON: &str = "ColorSwatchButton";
+pub(super) const BUTTON_STYLES: [&str; 5] = [
     TILING_BUTTON,
     IMAGE_BUTTON,
     BOOLEAN_IMAGE_BUTTON,
     PRIMITIVE_FILL_BUTTON,
+    COLOR_SWATCH_BUTTON,
 ];
 pub fn button_loader(
     resource_manager: &ResourceManager,
@@ -266,6 +295,7 @@ pub fn button_loader(
             default_font_size,
             default_color,
         ))),
+        Some(COLOR_SWATCH_BUTTON) => Ok(Box::new(color_swatch_button_loader(minidom_element))),
         string => Err(Box::new(SimpleError::new(format!(
             "Unable to parse type: {:#?} for valid button type. List of valid button types: {:#?}",
             string, BUTTON_STYLES
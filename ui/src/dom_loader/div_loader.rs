@@ -6,7 +6,7 @@ use crate::{elements::grouping::div::Div, utils::positioning::UIPosition};
 
 use super::utils::{
     collect_children_as_vector, get_color_attribute, get_font_size, get_generic_attribute,
-    get_scale, get_ui_position,
+    get_scale, get_stack_layout, get_ui_position,
 };
 
 /// # Usage
@@ -19,6 +19,8 @@ use super::utils::{
 /// - scale ([`f32`])
 /// - font_size ([`u32`])
 /// - color ([`Color`])
+/// - layout, gap, align, justify and wrap, see
+///   [`get_stack_layout`](super::utils::get_stack_layout)
 pub fn div_loader(
     resource_manager: &ResourceManager,
     minidom_element: &MinidomElement,
@@ -26,7 +28,7 @@ pub fn div_loader(
     default_font_size: u32,
     default_color: Color,
 ) -> Div {
-    Div::new(
+    let mut div = Div::new(
         get_ui_position(minidom_element).unwrap_or_default(),
         collect_children_as_vector(
             resource_manager,
@@ -39,5 +41,8 @@ pub fn div_loader(
         minidom_element
             .attr("size")
             .and_then(|size| vector2_from_str(size).ok()),
-    )
+    );
+    div.set_layout(get_stack_layout(minidom_element));
+
+    div
 }
@@ -2,8 +2,10 @@ use crate::elements::Element;
 
 use super::{
     background_loader::background_loader, button_loader::button_loader, div_loader::div_loader,
-    grid_loader::grid_loader, image_loader::image_loader, listbox_loader::listbox_loader,
-    missing_texture_loader::missing_texture_loader, primitive_loader::primitive_loader,
+    dropdown_loader::dropdown_loader, grid_loader::grid_loader,
+    identified_loader::identified_loader, image_loader::image_loader,
+    listbox_loader::listbox_loader, missing_texture_loader::missing_texture_loader,
+    primitive_loader::primitive_loader, scroll_view_loader::scroll_view_loader,
     sets_loader::sets_loader, slider_loader::slider_loader, text_loader::text_loader,
     textbox_loader::textbox_loader,
 };
@@ -37,16 +39,22 @@ Element in question: {:#?}\n
 ///     "Button",
 ///     "Slider",
 ///     "TextBox",
+///     "ListBox",
+///     "Dropdown",
 ///     "TilingSprite",
 ///     "Background",
 ///     "Div",
 ///     "Grid",
+///     "ScrollView",
 ///     "Sets",
 ///     "Text",
 ///     "Primitive",
 ///     "Image",
 ///     "Empty"
 /// ]
+///
+/// Any of them can also be given an `id` and a `class`, and the actionable ones a
+/// `tooltip`, see [`identified_loader`](super::identified_loader::identified_loader)
 pub fn element_loader(
     resource_manager: &ResourceManager,
     ele: &MinidomElement,
@@ -54,7 +62,7 @@ pub fn element_loader(
     default_font_size: u32,
     default_color: Color,
 ) -> Element {
-    match ele.name() {
+    let element = match ele.name() {
         "Button" => {
             match button_loader(
                 resource_manager,
@@ -85,6 +93,12 @@ pub fn element_loader(
                 Err(e) => print_error_and_return_missing_texture(resource_manager, e, ele)
             }
         }
+        "Dropdown" => {
+            match dropdown_loader(resource_manager, ele, default_scale, default_font_size, default_color) {
+                Ok(v) => Element::Dropdown(v),
+                Err(e) => print_error_and_return_missing_texture(resource_manager, e, ele),
+            }
+        }
         "Div" => Element::Div(div_loader(
             resource_manager,
             ele,
@@ -102,6 +116,16 @@ pub fn element_loader(
             Ok(v) => Element::Grid(v),
             Err(e) => print_error_and_return_missing_texture(resource_manager, e, ele),
         },
+        "ScrollView" => match scroll_view_loader(
+            resource_manager,
+            ele,
+            default_scale,
+            default_font_size,
+            default_color,
+        ) {
+            Ok(v) => Element::ScrollView(v),
+            Err(e) => print_error_and_return_missing_texture(resource_manager, e, ele),
+        },
         "Sets" => Element::Sets(sets_loader(
             resource_manager,
             ele,
@@ -130,5 +154,14 @@ pub fn element_loader(
             ))),
             ele,
         ),
-    }
+    };
+
+    identified_loader(
+        resource_manager,
+        ele,
+        element,
+        default_scale,
+        default_font_size,
+        default_color,
+    )
 }
@@ -0,0 +1,87 @@
+use minidom::Element as MinidomElement;
+use sfml::graphics::Color;
+use utils::resource_manager::ResourceManager;
+
+use crate::{
+    elements::{identified::IdentifiedElement, tooltip::Tooltip, Element},
+    events::EventId,
+};
+
+use super::validation::schema::ACTIONABLE_ELEMENTS;
+
+pub(super) const ID_ATTRIBUTE: &str = "id";
+pub(super) const CLASS_ATTRIBUTE: &str = "class";
+/// Only read on [`ACTIONABLE_ELEMENTS`]
+pub(super) const TOOLTIP_ATTRIBUTE: &str = "tooltip";
+
+/// Event ids given to elements that have an id but no event_id. Counts down from here, far
+/// away from the event ids written in layouts
+const FIRST_NAMED_EVENT_ID: EventId = EventId::MAX;
+
+/// # Usage
+///
+/// ## Optional, on every element:
+/// - id ([`String`])
+/// - class ([`String`]). Several classes are separated by whitespace
+///
+/// ## Optional, on actionable elements:
+/// - tooltip ([`String`]). Shown in a [`Tooltip`] when the cursor rests on the element
+///
+/// Wraps `element` in an [`Element::Identified`] if `minidom_element` has any of them
+pub fn identified_loader(
+    resource_manager: &ResourceManager,
+    minidom_element: &MinidomElement,
+    element: Element,
+    default_scale: f32,
+    default_font_size: u32,
+    default_color: Color,
+) -> Element {
+    let id = minidom_element.attr(ID_ATTRIBUTE).map(str::to_string);
+    let classes: Vec<String> = minidom_element
+        .attr(CLASS_ATTRIBUTE)
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(str::to_string)
+        .collect();
+    let tooltip = minidom_element
+        .attr(TOOLTIP_ATTRIBUTE)
+        .filter(|_| ACTIONABLE_ELEMENTS.contains(&minidom_element.name()))
+        .map(|text| {
+            Tooltip::new(
+                resource_manager,
+                text.trim(),
+                default_scale,
+                default_font_size,
+                default_color,
+            )
+        });
+    if id.is_none() && classes.is_empty() && tooltip.is_none() {
+        return element;
+    }
+
+    Element::Identified(Box::new(IdentifiedElement::new(
+        id, classes, tooltip, element,
+    )))
+}
+
+/// Gives every actionable element with an id, but no event_id, an event id of its own. Its
+/// events can then be told apart by the id of the element alone, see
+/// [`EventRoutes`](crate::events::event_routes::EventRoutes)
+pub fn assign_named_event_ids(root: &mut MinidomElement) {
+    let mut next_event_id = FIRST_NAMED_EVENT_ID;
+    assign_named_event_ids_recursive(root, &mut next_event_id);
+}
+
+fn assign_named_event_ids_recursive(ele: &mut MinidomElement, next_event_id: &mut EventId) {
+    if ACTIONABLE_ELEMENTS.contains(&ele.name())
+        && ele.attr(ID_ATTRIBUTE).is_some()
+        && ele.attr("event_id").is_none()
+    {
+        ele.set_attr("event_id", next_event_id.to_string());
+        *next_event_id -= 1;
+    }
+
+    for child in ele.children_mut() {
+        assign_named_event_ids_recursive(child, next_event_id);
+    }
+}
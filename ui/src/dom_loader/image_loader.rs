@@ -30,8 +30,8 @@ pub fn icon_loader(
     ))
 }
 
-const ICON: &str = "Icon";
-const IMAGE_TYPES: [&str; 1] = [ICON];
+pub(super) const ICON: &str = "Icon";
+pub(super) const IMAGE_TYPES: [&str; 1] = [ICON];
 pub fn image_loader(
     resource_manager: &ResourceManager,
     ele: &Element,
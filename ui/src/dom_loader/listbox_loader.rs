@@ -57,8 +57,8 @@ pub fn up_down_scroll_listbox_loader(
     )
 }
 
-const UP_DOWN_SCROLL_LISTBOX: &str = "UpDownScrollListbox";
-const LISTBOX_STYLES: [&str; 1] = [UP_DOWN_SCROLL_LISTBOX];
+pub(super) const UP_DOWN_SCROLL_LISTBOX: &str = "UpDownScrollListbox";
+pub(super) const LISTBOX_STYLES: [&str; 1] = [UP_DOWN_SCROLL_LISTBOX];
 pub fn listbox_loader(
     resource_manager: &ResourceManager,
     minidom_element: &MinidomElement,
@@ -140,14 +140,14 @@ fn triangle_fan_loader(ele: &Element) -> Primitive {
     )
 }
 
-const FILL: &str = "Fill";
-const POINTS: &str = "Points";
-const LINES: &str = "Lines";
-const LINE_STRIP: &str = "LineStrip";
-const TRIANGLES: &str = "Triangles";
-const TRIANGLE_STRIP: &str = "TriangleStrip";
-const TRIANGLE_FAN: &str = "TriangleFan";
-const PRIMITIVE_TYPES: [&str; 7] = [
+pub(super) const FILL: &str = "Fill";
+pub(super) const POINTS: &str = "Points";
+pub(super) const LINES: &str = "Lines";
+pub(super) const LINE_STRIP: &str = "LineStrip";
+pub(super) const TRIANGLES: &str = "Triangles";
+pub(super) const TRIANGLE_STRIP: &str = "TriangleStrip";
+pub(super) const TRIANGLE_FAN: &str = "TriangleFan";
+pub(super) const PRIMITIVE_TYPES: [&str; 7] = [
     FILL,
     POINTS,
     LINES,
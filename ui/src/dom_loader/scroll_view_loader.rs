@@ -0,0 +1,57 @@
+use std::error::Error;
+
+use minidom::Element as MinidomElement;
+use sfml::graphics::Color;
+use utils::resource_manager::ResourceManager;
+
+use crate::elements::grouping::scroll_view::ScrollView;
+
+use super::utils::{
+    collect_children_as_vector, get_asset_id, get_color_attribute, get_font_size,
+    get_generic_attribute, get_scale, get_size, get_ui_position,
+};
+
+/// # Usage
+///
+/// ## Required
+/// - size ([`Vector2`](sfml::system::Vector2))
+/// - asset ([`String`])
+/// - frame_id ([`usize`])
+/// - hover_frame_id ([`usize`])
+/// - click_frame_id ([`usize`])
+///
+/// ## Optional
+/// - position ([`UIPosition`](crate::utils::positioning::UIPosition))
+/// - scale ([`f32`])
+/// - font_size ([`u32`])
+/// - color ([`Color`])
+pub fn scroll_view_loader(
+    resource_manager: &ResourceManager,
+    minidom_element: &MinidomElement,
+    default_scale: f32,
+    default_font_size: u32,
+    default_color: Color,
+) -> Result<ScrollView, Box<dyn Error>> {
+    let scale = get_scale(minidom_element).unwrap_or(default_scale);
+
+    Ok(ScrollView::new(
+        resource_manager,
+        &get_asset_id(minidom_element)?,
+        get_ui_position(minidom_element).unwrap_or_default(),
+        get_size(minidom_element)?,
+        get_generic_attribute::<usize>(minidom_element, "frame_id")
+            .ok_or("ui::dom_loader::scroll_view_loader::scroll_view_loader: Unable to parse frame_id")?,
+        get_generic_attribute::<usize>(minidom_element, "hover_frame_id")
+            .ok_or("ui::dom_loader::scroll_view_loader::scroll_view_loader: Unable to parse hover_frame_id")?,
+        get_generic_attribute::<usize>(minidom_element, "click_frame_id")
+            .ok_or("ui::dom_loader::scroll_view_loader::scroll_view_loader: Unable to parse click_frame_id")?,
+        scale,
+        collect_children_as_vector(
+            resource_manager,
+            minidom_element,
+            scale,
+            get_font_size(minidom_element).unwrap_or(default_font_size),
+            get_color_attribute(minidom_element).unwrap_or(default_color),
+        ),
+    ))
+}
@@ -5,8 +5,8 @@ use utils::resource_manager::ResourceManager;
 use crate::{elements::grouping::sets::Sets, utils::positioning::UIPosition};
 
 use super::utils::{
-    collect_children_as_vector, get_generic_attribute, get_scale, get_size, get_sync_id_or_default,
-    get_ui_position,
+    collect_children_as_vector, get_generic_attribute, get_scale, get_size, get_stack_layout,
+    get_sync_id_or_default, get_ui_position,
 };
 
 // Documentation imports
@@ -24,6 +24,8 @@ use sfml::system::Vector2;
 /// - font_size ([`u32`])
 /// - color ([`Color`])
 /// - size ([`Vector2`])
+/// - layout, gap, align, justify and wrap, see
+///   [`get_stack_layout`](super::utils::get_stack_layout)
 pub fn sets_loader(
     resource_manager: &ResourceManager,
     minidom_element: &MinidomElement,
@@ -47,6 +49,7 @@ pub fn sets_loader(
         sets,
         get_generic_attribute::<UIPosition>(minidom_element, "padding"),
         get_size(minidom_element).ok(),
+        get_stack_layout(minidom_element),
         get_sync_id_or_default(minidom_element),
     )
 }
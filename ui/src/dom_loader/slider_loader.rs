@@ -160,10 +160,10 @@ pub fn hue_color_picker_loader(
     ))
 }
 
-const INCREMENT_DECREMENT_POINTER_SLIDER: &str = "IncrementPointerSlider";
-const QUAD_COLOR_PICKER: &str = "QuadColorPicker";
-const HUE_COLOR_PICKER: &str = "HueColorPicker";
-const SLIDER_STYLES: [&str; 3] = [
+pub(super) const INCREMENT_DECREMENT_POINTER_SLIDER: &str = "IncrementPointerSlider";
+pub(super) const QUAD_COLOR_PICKER: &str = "QuadColorPicker";
+pub(super) const HUE_COLOR_PICKER: &str = "HueColorPicker";
+pub(super) const SLIDER_STYLES: [&str; 3] = [
     INCREMENT_DECREMENT_POINTER_SLIDER,
     QUAD_COLOR_PICKER,
     HUE_COLOR_PICKER,
@@ -9,7 +9,7 @@ use utils::{
 };
 
 use crate::elements::textbox::{
-    fixed_size_one_line_textbox::FixedSizeOneLineTextbox, traits::TextBox,
+    fixed_size_one_line_textbox::FixedSizeOneLineTextbox, text_area::TextArea, traits::TextBox,
 };
 
 use super::utils::{
@@ -56,8 +56,48 @@ fn fixed_size_one_line_textbox_loader(
     ))
 }
 
-const FIXED_SIZE_ONE_LINE_TEXTBOX: &str = "FixedSizeOneLineTextbox";
-const TEXTBOX_STYLES: [&str; 1] = [FIXED_SIZE_ONE_LINE_TEXTBOX];
+/// # Usage
+///
+/// ## Required
+/// - type [`TEXT_AREA`]
+///
+/// ## Optional
+/// - position ([`UIPosition`](crate::utils::positioning::UIPosition))
+/// - size ([`Vector2u`](sfml::system::Vector2u)) <- without a y, four lines fit
+/// - font_size ([`u32`])
+/// - color ([`Color`]) <- text color
+/// - background_color ([`Color`])
+/// - event_id ([`EventId`](crate::events::EventId))
+/// - sync_id ([`SyncId`](crate::syncs::SyncId))
+/// - INNER TEXT CHILD ELEMENT ([`String`])
+fn text_area_loader(
+    resource_manager: &ResourceManager,
+    minidom_element: &MinidomElement,
+    default_font_size: u32,
+    default_color: Color,
+) -> Result<TextArea, Box<dyn Error>> {
+    let text_color = get_color_attribute(minidom_element).unwrap_or(default_color);
+    Ok(TextArea::new(
+        resource_manager,
+        get_ui_position(minidom_element).unwrap_or_default(),
+        get_size(minidom_element).unwrap_or_default(),
+        get_font_size(minidom_element).unwrap_or(default_font_size),
+        text_color,
+        color_from_str(
+            minidom_element
+                .attr("background_color")
+                .unwrap_or("This color doesn't exist : !"),
+        )
+        .unwrap_or(invert_color(text_color)),
+        minidom_element.text().trim(),
+        get_event_id_or_default(minidom_element),
+        get_sync_id_or_default(minidom_element),
+    ))
+}
+
+pub(super) const FIXED_SIZE_ONE_LINE_TEXTBOX: &str = "FixedSizeOneLineTextbox";
+pub(super) const TEXT_AREA: &str = "TextArea";
+pub(super) const TEXTBOX_STYLES: [&str; 2] = [FIXED_SIZE_ONE_LINE_TEXTBOX, TEXT_AREA];
 pub fn textbox_loader(
     resource_manager: &ResourceManager,
     minidom_element: &MinidomElement,
@@ -72,6 +112,12 @@ pub fn textbox_loader(
             default_font_size,
             default_color,
         )?)),
+        Some(TEXT_AREA) => Ok(Box::new(text_area_loader(
+            resource_manager,
+            minidom_element,
+            default_font_size,
+            default_color,
+        )?)),
         string => Err(Box::new(SimpleError::new(format!(
             "Unabe to parse type: {:#?} for valid textbox type. List of valid textbox types: {:#?}",
             string, TEXTBOX_STYLES
@@ -1,4 +1,7 @@
-use crate::{elements::Element as UIElement, utils::positioning::UIPosition};
+use crate::{
+    elements::{grouping::div::stack_layout::StackLayout, Element as UIElement},
+    utils::positioning::UIPosition,
+};
 use minidom::Element;
 use serde::ser::StdError;
 use sfml::{graphics::Color, system::Vector2};
@@ -10,11 +13,9 @@ use utils::{
 
 use super::element_loader;
 
-pub fn get_generic_attribute<T: std::default::Default + std::str::FromStr>(
-    ele: &Element,
-    attr: &str,
-) -> Option<T> {
-    Some(ele.attr(attr)?.parse::<T>().unwrap_or_default())
+/// None if the attribute is missing or does not parse
+pub fn get_generic_attribute<T: std::str::FromStr>(ele: &Element, attr: &str) -> Option<T> {
+    ele.attr(attr)?.parse::<T>().ok()
 }
 
 pub fn empty_element() -> Element {
@@ -56,6 +57,26 @@ where
     vector2_from_str::<T>(ele.attr("size").ok_or("No size attribute")?)
 }
 
+/// None if there is no `layout` attribute, see [`StackLayout`]
+///
+/// # Usage
+///
+/// ## Optional:
+/// - layout (`row` or `column`)
+/// - gap ([`i32`])
+/// - align (`start`, `center` or `end`)
+/// - justify (`start`, `center` or `end`)
+/// - wrap ([`bool`])
+pub fn get_stack_layout(ele: &Element) -> Option<StackLayout> {
+    Some(StackLayout {
+        direction: get_generic_attribute(ele, "layout")?,
+        gap: get_generic_attribute(ele, "gap").unwrap_or_default(),
+        align: get_generic_attribute(ele, "align").unwrap_or_default(),
+        justify: get_generic_attribute(ele, "justify").unwrap_or_default(),
+        wrap: get_generic_attribute(ele, "wrap").unwrap_or_default(),
+    })
+}
+
 pub fn get_event_id_or_default(ele: &Element) -> u16 {
     ele.attr("event_id")
         .unwrap_or("0")
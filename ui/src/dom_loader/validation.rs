@@ -0,0 +1,443 @@
+use std::{collections::HashMap, fmt::Display, fs, path::Path};
+
+use super::identified_loader::{CLASS_ATTRIBUTE, ID_ATTRIBUTE, TOOLTIP_ATTRIBUTE};
+
+use self::{
+    scanner::{scan, XmlNode, XmlPosition},
+    schema::{
+        element_schema, element_types, Children, ElementSchema, ACTIONABLE_ELEMENTS, ELEMENT_NAMES,
+        INFO_ATTRIBUTE, ROOT_NODE, TYPE_ATTRIBUTE,
+    },
+};
+
+pub mod scanner;
+pub mod schema;
+
+/// Something wrong with an xml layout, and where
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmlDiagnostic {
+    pub position: XmlPosition,
+    pub message: String,
+}
+
+impl XmlDiagnostic {
+    fn new(position: XmlPosition, message: String) -> Self {
+        Self { position, message }
+    }
+}
+
+impl Display for XmlDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}: {}",
+            self.position.line, self.position.column, self.message
+        )
+    }
+}
+
+/// Checks an xml layout against what the loaders accept, which are much more forgiving:
+/// they skip unknown attributes and fall back to defaults on bad values.
+///
+/// Reports malformed xml, unknown elements, types and attributes, values that do not parse,
+/// missing required attributes, children that are not allowed and ids that are used twice.
+/// Every element may also have an `info` attribute, which is ignored by the loaders and is
+/// there for documentation, and an `id` and a `class`. Actionable elements may have a
+/// `tooltip`.
+pub fn validate_xml(xml_doc: &str) -> Vec<XmlDiagnostic> {
+    let (root, mut diagnostics) = scan(xml_doc);
+    let Some(root) = root else {
+        return diagnostics;
+    };
+
+    if root.name == ROOT_NODE {
+        validate_element(&root, &mut diagnostics);
+        validate_ids(&root, false, &mut HashMap::new(), &mut diagnostics);
+    } else {
+        diagnostics.push(XmlDiagnostic::new(
+            root.position,
+            format!(
+                "The root element must be <{}>, not <{}>",
+                ROOT_NODE, root.name
+            ),
+        ));
+    }
+    diagnostics.sort_by_key(|diagnostic| (diagnostic.position.line, diagnostic.position.column));
+
+    diagnostics
+}
+
+/// Validates every `.xml` file in `dir` and its subfolders, and panics listing every
+/// diagnostic as `path:line:column: message`. Meant to be called from a test, so typos in
+/// embedded layouts fail the build instead of being quietly ignored at runtime
+#[track_caller]
+pub fn assert_xml_layouts_are_valid(dir: impl AsRef<Path>) {
+    let mut layouts = Vec::new();
+    collect_xml_files(dir.as_ref(), &mut layouts);
+    assert!(
+        !layouts.is_empty(),
+        "No xml layouts found in {}",
+        dir.as_ref().display()
+    );
+
+    let mut errors = Vec::new();
+    for path in layouts {
+        let xml_doc = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("Failed to read {}: {}", path.display(), err));
+        errors.extend(
+            validate_xml(&xml_doc)
+                .into_iter()
+                .map(|diagnostic| format!("{}:{}", path.display(), diagnostic)),
+        );
+    }
+
+    assert!(
+        errors.is_empty(),
+        "Invalid xml layouts:\n{}",
+        errors.join("\n")
+    );
+}
+
+fn collect_xml_files(dir: &Path, layouts: &mut Vec<std::path::PathBuf>) {
+    let entries =
+        fs::read_dir(dir).unwrap_or_else(|err| panic!("Failed to read {}: {}", dir.display(), err));
+    for path in entries.filter_map(|entry| Some(entry.ok()?.path())) {
+        if path.is_dir() {
+            collect_xml_files(&path, layouts);
+        } else if path.extension().is_some_and(|extension| extension == "xml") {
+            layouts.push(path);
+        }
+    }
+    layouts.sort();
+}
+
+fn validate_element(node: &XmlNode, diagnostics: &mut Vec<XmlDiagnostic>) {
+    if !ELEMENT_NAMES.contains(&node.name.as_str()) && node.name != ROOT_NODE {
+        diagnostics.push(XmlDiagnostic::new(
+            node.position,
+            format!(
+                "Unknown element <{}>{}",
+                node.name,
+                did_you_mean(&node.name, ELEMENT_NAMES)
+            ),
+        ));
+        return;
+    }
+
+    let element_types = element_types(&node.name);
+    let element_type = node.attribute(TYPE_ATTRIBUTE);
+    let schema = match (element_types, element_type) {
+        (Some(element_types), None) => {
+            diagnostics.push(XmlDiagnostic::new(
+                node.position,
+                format!(
+                    "<{}> is missing the `{}` attribute, one of {:?}",
+                    node.name, TYPE_ATTRIBUTE, element_types
+                ),
+            ));
+            None
+        }
+        (Some(element_types), Some(element_type))
+            if !element_types.contains(&element_type.value.as_str()) =>
+        {
+            diagnostics.push(XmlDiagnostic::new(
+                element_type.value_position,
+                format!(
+                    "Unknown {} type `{}`{}",
+                    node.name,
+                    element_type.value,
+                    did_you_mean(&element_type.value, element_types.iter().copied())
+                ),
+            ));
+            None
+        }
+        (None, Some(element_type)) => {
+            diagnostics.push(XmlDiagnostic::new(
+                element_type.position,
+                format!("<{}> does not have a `{}`", node.name, TYPE_ATTRIBUTE),
+            ));
+            element_schema(&node.name, None)
+        }
+        (_, element_type) => element_schema(
+            &node.name,
+            element_type.map(|element_type| element_type.value.as_str()),
+        ),
+    };
+
+    // Without a schema only the children can still be checked
+    if let Some(schema) = schema {
+        validate_attributes(node, &schema, diagnostics);
+        validate_children(node, &schema, diagnostics);
+    }
+    for child in &node.children {
+        validate_element(child, diagnostics);
+    }
+}
+
+fn validate_attributes(
+    node: &XmlNode,
+    schema: &ElementSchema,
+    diagnostics: &mut Vec<XmlDiagnostic>,
+) {
+    for attribute in &node.attributes {
+        if [
+            INFO_ATTRIBUTE,
+            TYPE_ATTRIBUTE,
+            ID_ATTRIBUTE,
+            CLASS_ATTRIBUTE,
+        ]
+        .contains(&attribute.name.as_str())
+            || attribute.name == TOOLTIP_ATTRIBUTE
+                && ACTIONABLE_ELEMENTS.contains(&node.name.as_str())
+        {
+            continue;
+        }
+
+        match schema.attribute_kind(&attribute.name) {
+            Some(kind) => {
+                if let Err(err) = kind.check(&attribute.value) {
+                    diagnostics.push(XmlDiagnostic::new(
+                        attribute.value_position,
+                        format!(
+                            "Invalid value `{}` for `{}`: {}",
+                            attribute.value, attribute.name, err
+                        ),
+                    ));
+                }
+            }
+            None => diagnostics.push(XmlDiagnostic::new(
+                attribute.position,
+                format!(
+                    "Unknown attribute `{}` on <{}>{}",
+                    attribute.name,
+                    node.name,
+                    did_you_mean(&attribute.name, schema.attribute_names())
+                ),
+            )),
+        }
+    }
+
+    for (name, _) in schema.required {
+        if node.attribute(name).is_none() {
+            diagnostics.push(XmlDiagnostic::new(
+                node.position,
+                format!("<{}> is missing the `{}` attribute", node.name, name),
+            ));
+        }
+    }
+}
+
+fn validate_children(node: &XmlNode, schema: &ElementSchema, diagnostics: &mut Vec<XmlDiagnostic>) {
+    if let Some(text_position) = node.text_position {
+        if schema.children != Children::Text {
+            diagnostics.push(XmlDiagnostic::new(
+                text_position,
+                format!("<{}> can not contain text", node.name),
+            ));
+        }
+    }
+
+    let allowed = match schema.children {
+        Children::None | Children::Text => 0..=0,
+        Children::AtMostOne => 0..=1,
+        Children::ExactlyOne => 1..=1,
+        Children::Any => 0..=usize::MAX,
+    };
+    if allowed.contains(&node.children.len()) {
+        return;
+    }
+
+    let message = match schema.children {
+        Children::ExactlyOne if node.children.is_empty() => {
+            format!("<{}> needs exactly one child element", node.name)
+        }
+        Children::ExactlyOne | Children::AtMostOne => {
+            format!("<{}> can have at most one child element", node.name)
+        }
+        _ => format!("<{}> can not have child elements", node.name),
+    };
+    let position = node
+        .children
+        .get(*allowed.end())
+        .map_or(node.position, |child| child.position);
+    diagnostics.push(XmlDiagnostic::new(position, message));
+}
+
+/// Ids have to be unique, and can not be looked up inside of buttons and sliders, since
+/// their inner element is not part of the dom traversal
+fn validate_ids<'a>(
+    node: &'a XmlNode,
+    is_inner_element: bool,
+    ids: &mut HashMap<&'a str, XmlPosition>,
+    diagnostics: &mut Vec<XmlDiagnostic>,
+) {
+    if let Some(id) = node.attribute(ID_ATTRIBUTE) {
+        if id.value.is_empty() || id.value.contains(char::is_whitespace) {
+            diagnostics.push(XmlDiagnostic::new(
+                id.value_position,
+                format!(
+                    "Invalid id `{}`, ids can not be empty or have whitespace",
+                    id.value
+                ),
+            ));
+        } else if let Some(first) = ids.get(id.value.as_str()) {
+            diagnostics.push(XmlDiagnostic::new(
+                id.value_position,
+                format!(
+                    "Duplicate id `{}`, first used at {}:{}",
+                    id.value, first.line, first.column
+                ),
+            ));
+        } else {
+            ids.insert(id.value.as_str(), id.value_position);
+        }
+        if is_inner_element {
+            diagnostics.push(XmlDiagnostic::new(
+                id.position,
+                "Elements inside of a button or slider can not be looked up by id".to_string(),
+            ));
+        }
+    }
+
+    let is_inner_element = is_inner_element || matches!(node.name.as_str(), "Button" | "Slider");
+    for child in &node.children {
+        validate_ids(child, is_inner_element, ids, diagnostics);
+    }
+}
+
+/// Suggests the closest of `candidates` to a misspelled `name`, if one is close enough
+fn did_you_mean<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> String {
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| format!(", did you mean `{}`?", candidate))
+        .unwrap_or_default()
+}
+
+/// Levenshtein distance, case insensitive
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.to_lowercase().chars().collect();
+    let b: Vec<char> = b.to_lowercase().chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.iter().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous_row[j] + usize::from(a_char != b_char);
+            row[j + 1] = substitution.min(previous_row[j + 1] + 1).min(row[j] + 1);
+        }
+        previous_row = row;
+    }
+
+    previous_row[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const HEADER: &str = r##"<RootNode scale="4" font_size="16" color="#f7e5e4" xmlns="https://www.loc.gov/marc/marcxml.html">"##;
+
+    fn messages(body: &str) -> Vec<String> {
+        validate_xml(&format!("{}\n{}\n</RootNode>", HEADER, body))
+            .into_iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect()
+    }
+
+    /// Every layout of the crate, the one of the ui test binary included
+    #[test]
+    fn test_xml_layouts_are_valid() {
+        assert_xml_layouts_are_valid(Path::new(env!("CARGO_MANIFEST_DIR")).join("src"));
+    }
+
+    #[test]
+    fn test_valid_layout() {
+        let body = r##"
+  <Div position="t:10,l:10" size="x:200,y:100" info="Save">
+    <Text position="t:2" sync_id="3">Hello</Text>
+    <Button type="TilingButton" asset="dark_blue_button.png" frame_id="0" hover_frame_id="1" click_frame_id="2" event_id="4" tooltip="Saves the file">
+      <Text>Ok</Text>
+    </Button>
+    <Primitive type="TriangleFan" color="#000000" vertices="(x:0,y:0),(x:4,y:0),(x:4,y:4)"/>
+  </Div>"##;
+        assert_eq!(messages(body), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_attribute_diagnostics() {
+        let body = r#"  <Div postion="t:10" size="x:abc,y:1"/>
+  <Image type="Icon" scale="2"/>
+  <Div layout="grid" wrap="yes" tooltip="Help"/>"#;
+        assert_eq!(
+            messages(body),
+            vec![
+                "2:8: Unknown attribute `postion` on <Div>, did you mean `position`?",
+                "2:28: Invalid value `x:abc,y:1` for `size`: invalid digit found in string",
+                "3:3: <Image> is missing the `name` attribute",
+                r#"4:15: Invalid value `grid` for `layout`: expected one of ["row", "column"]"#,
+                "4:27: Invalid value `yes` for `wrap`: provided string was not `true` or `false`",
+                "4:33: Unknown attribute `tooltip` on <Div>",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_element_diagnostics() {
+        let body = r#"  <Buton/>
+  <Slider type="HueColorPiker"/>
+  <Slider type="HueColorPicker"/>
+  <Empty>text</Empty>"#;
+        assert_eq!(
+            messages(body),
+            vec![
+                "2:3: Unknown element <Buton>, did you mean `Button`?",
+                "3:16: Unknown Slider type `HueColorPiker`, did you mean `HueColorPicker`?",
+                "4:3: <Slider> needs exactly one child element",
+                "5:10: <Empty> can not contain text",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_malformed_xml() {
+        assert_eq!(
+            messages("  <Div>\n  </Dvi>"),
+            vec!["3:3: </Dvi> does not close <Div>, which was opened at 2:3"]
+        );
+        assert_eq!(
+            messages(r#"  <!-- note --><Div padding="t:1" padding="t:2"/>"#),
+            vec![
+                "2:3: Comments are not supported by the dom loader, use an info attribute instead",
+                "2:35: Duplicate attribute `padding`, first set at 2:21",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_id_diagnostics() {
+        let body = r#"  <Div id="panel" class="a b">
+    <Text id="panel"/>
+    <Button type="PrimitiveFillButton" id="ok">
+      <Text id="label"/>
+    </Button>
+  </Div>"#;
+        assert_eq!(
+            messages(body),
+            vec![
+                "3:14: Duplicate id `panel`, first used at 2:11",
+                "5:13: Elements inside of a button or slider can not be looked up by id",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("position", "position"), 0);
+        assert_eq!(edit_distance("postion", "position"), 1);
+        assert_eq!(edit_distance("kitten", "Sitting"), 3);
+        assert_eq!(did_you_mean("font", ["font_size"]), "");
+    }
+}
@@ -0,0 +1,321 @@
+use super::XmlDiagnostic;
+
+/// Where something starts in the xml document. Both are 1 based
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct XmlPosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmlAttribute {
+    pub name: String,
+    pub value: String,
+    pub position: XmlPosition,
+    pub value_position: XmlPosition,
+}
+
+/// An element of the document, with where each part of it was written. Only what the
+/// validation needs is kept
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct XmlNode {
+    pub name: String,
+    pub position: XmlPosition,
+    pub attributes: Vec<XmlAttribute>,
+    pub children: Vec<XmlNode>,
+    /// Position of the first text that is not whitespace, if there is any
+    pub text_position: Option<XmlPosition>,
+}
+
+impl XmlNode {
+    pub fn attribute(&self, name: &str) -> Option<&XmlAttribute> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+    }
+}
+
+/// Reads `xml_doc` into a tree of [`XmlNode`]. Malformed xml stops the scan, in which case
+/// the diagnostics say why and no tree is returned
+pub fn scan(xml_doc: &str) -> (Option<XmlNode>, Vec<XmlDiagnostic>) {
+    let mut scanner = Scanner {
+        xml_doc,
+        offset: 0,
+        line_starts: std::iter::once(0)
+            .chain(xml_doc.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect(),
+        diagnostics: Vec::new(),
+    };
+    let root = scanner.scan_document().ok().flatten();
+
+    (root, scanner.diagnostics)
+}
+
+/// Marks that the scan was stopped. The reason is in the diagnostics
+struct Malformed;
+
+struct Scanner<'a> {
+    xml_doc: &'a str,
+    offset: usize,
+    /// Byte offset of the start of every line
+    line_starts: Vec<usize>,
+    diagnostics: Vec<XmlDiagnostic>,
+}
+
+impl<'a> Scanner<'a> {
+    fn rest(&self) -> &'a str {
+        &self.xml_doc[self.offset..]
+    }
+
+    fn position_of(&self, offset: usize) -> XmlPosition {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1];
+        XmlPosition {
+            line,
+            column: self.xml_doc[line_start..offset].chars().count() + 1,
+        }
+    }
+
+    fn position(&self) -> XmlPosition {
+        self.position_of(self.offset)
+    }
+
+    fn report(&mut self, position: XmlPosition, message: String) {
+        self.diagnostics.push(XmlDiagnostic { position, message });
+    }
+
+    fn fail<T>(&mut self, position: XmlPosition, message: String) -> Result<T, Malformed> {
+        self.report(position, message);
+        Err(Malformed)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    /// Moves past the next `pattern`
+    fn skip_past(&mut self, pattern: &str, what: &str) -> Result<(), Malformed> {
+        let start = self.position();
+        match self.rest().find(pattern) {
+            Some(idx) => {
+                self.offset += idx + pattern.len();
+                Ok(())
+            }
+            None => self.fail(
+                start,
+                format!("{} is never closed with `{}`", what, pattern),
+            ),
+        }
+    }
+
+    fn read_name(&mut self) -> &'a str {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '=' | '<'))
+            .unwrap_or(rest.len());
+        self.offset += len;
+        &rest[..len]
+    }
+
+    fn scan_document(&mut self) -> Result<Option<XmlNode>, Malformed> {
+        let mut root = None;
+        loop {
+            self.skip_misc()?;
+            if self.rest().is_empty() {
+                break;
+            }
+            if !self.rest().starts_with('<') {
+                let position = self.position();
+                return self.fail(position, "Text outside of the root element".to_string());
+            }
+            let node = self.scan_element()?;
+            if root.is_some() {
+                self.report(
+                    node.position,
+                    format!("Second root element <{}>, only one is allowed", node.name),
+                );
+            } else {
+                root = Some(node);
+            }
+        }
+
+        if root.is_none() {
+            self.report(
+                self.position_of(0),
+                "The document has no root element".to_string(),
+            );
+        }
+        Ok(root)
+    }
+
+    /// Skips whitespace, xml declarations and comments. Comments are reported, since the
+    /// dom loader does not accept them
+    fn skip_misc(&mut self) -> Result<(), Malformed> {
+        loop {
+            self.skip_whitespace();
+            let position = self.position();
+            if self.rest().starts_with("<?") {
+                self.skip_past("?>", "Processing instruction")?;
+            } else if self.rest().starts_with("<!--") {
+                self.report(
+                    position,
+                    "Comments are not supported by the dom loader, use an info attribute instead"
+                        .to_string(),
+                );
+                self.skip_past("-->", "Comment")?;
+            } else if self.rest().starts_with("<!") {
+                self.report(
+                    position,
+                    "Doctypes and CDATA are not supported by the dom loader".to_string(),
+                );
+                self.skip_past(">", "Declaration")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Expects to be at the `<` of a start tag
+    fn scan_element(&mut self) -> Result<XmlNode, Malformed> {
+        let position = self.position();
+        self.offset += '<'.len_utf8();
+        let name = self.read_name();
+        if name.is_empty() {
+            return self.fail(position, "Expected an element name after `<`".to_string());
+        }
+        let mut node = XmlNode {
+            name: name.to_string(),
+            position,
+            ..Default::default()
+        };
+
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.offset += 2;
+                return Ok(node);
+            }
+            if self.rest().starts_with('>') {
+                self.offset += 1;
+                break;
+            }
+            if self.rest().is_empty() {
+                return self.fail(position, format!("<{}> is never closed with `>`", name));
+            }
+
+            let attribute = self.scan_attribute()?;
+            if let Some(previous) = node.attribute(&attribute.name) {
+                let message = format!(
+                    "Duplicate attribute `{}`, first set at {}:{}",
+                    attribute.name, previous.position.line, previous.position.column
+                );
+                self.report(attribute.position, message);
+            } else {
+                node.attributes.push(attribute);
+            }
+        }
+
+        self.scan_content(&mut node)?;
+        Ok(node)
+    }
+
+    fn scan_attribute(&mut self) -> Result<XmlAttribute, Malformed> {
+        let position = self.position();
+        let name = self.read_name();
+        if name.is_empty() {
+            let found = self.rest().chars().next().unwrap_or_default();
+            return self.fail(position, format!("Unexpected `{}` in a tag", found));
+        }
+
+        self.skip_whitespace();
+        if !self.rest().starts_with('=') {
+            return self.fail(position, format!("Attribute `{}` has no value", name));
+        }
+        self.offset += 1;
+        self.skip_whitespace();
+
+        let value_position = self.position();
+        let Some(quote) = self
+            .rest()
+            .chars()
+            .next()
+            .filter(|c| matches!(c, '"' | '\''))
+        else {
+            return self.fail(
+                value_position,
+                format!("The value of `{}` must be in quotes", name),
+            );
+        };
+        self.offset += 1;
+        let Some(len) = self.rest().find(quote) else {
+            return self.fail(
+                value_position,
+                format!("The value of `{}` is never closed with {}", name, quote),
+            );
+        };
+        let value = unescape(&self.rest()[..len]);
+        self.offset += len + 1;
+
+        Ok(XmlAttribute {
+            name: name.to_string(),
+            value,
+            position,
+            value_position,
+        })
+    }
+
+    /// Children and text, up to and including the end tag of `node`
+    fn scan_content(&mut self, node: &mut XmlNode) -> Result<(), Malformed> {
+        loop {
+            let text_start = self.offset;
+            let text_len = self.rest().find('<').unwrap_or(self.rest().len());
+            let text = &self.rest()[..text_len];
+            if node.text_position.is_none() && !text.trim().is_empty() {
+                let leading_whitespace = text.len() - text.trim_start().len();
+                node.text_position = Some(self.position_of(text_start + leading_whitespace));
+            }
+            self.offset += text_len;
+
+            if self.rest().is_empty() {
+                return self.fail(
+                    node.position,
+                    format!("<{}> is never closed with </{}>", node.name, node.name),
+                );
+            }
+            if self.rest().starts_with("</") {
+                let position = self.position();
+                self.offset += 2;
+                let name = self.read_name();
+                if name != node.name {
+                    let message = format!(
+                        "</{}> does not close <{}>, which was opened at {}:{}",
+                        name, node.name, node.position.line, node.position.column
+                    );
+                    return self.fail(position, message);
+                }
+                self.skip_whitespace();
+                if !self.rest().starts_with('>') {
+                    return self.fail(position, format!("</{}> is never closed with `>`", name));
+                }
+                self.offset += 1;
+                return Ok(());
+            }
+            if self.rest().starts_with("<!") || self.rest().starts_with("<?") {
+                self.skip_misc()?;
+                continue;
+            }
+
+            node.children.push(self.scan_element()?);
+        }
+    }
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
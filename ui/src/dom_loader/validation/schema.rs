@@ -0,0 +1,438 @@
+use std::{error::Error, str::FromStr};
+
+use utils::{
+    sfml_util_functions::{color_from_str, vector2_from_str},
+    string_util_functions::get_list_of_tuple_lists_from_string,
+};
+
+use crate::{
+    elements::grouping::div::stack_layout::{LAYOUT_ALIGNMENTS, LAYOUT_DIRECTIONS},
+    utils::positioning::UIPosition,
+};
+
+use super::super::{
+    background_loader::{BACKGROUND_TYPES, REPEATABLE_3X3_BACKGROUND},
+    button_loader::{
+        BOOLEAN_IMAGE_BUTTON, BUTTON_STYLES, COLOR_SWATCH_BUTTON, IMAGE_BUTTON,
+        PRIMITIVE_FILL_BUTTON, TILING_BUTTON,
+    },
+    dropdown_loader::{DROPDOWN_TYPES, TILING_DROPDOWN},
+    image_loader::{ICON, IMAGE_TYPES},
+    listbox_loader::{LISTBOX_STYLES, UP_DOWN_SCROLL_LISTBOX},
+    primitive_loader::{
+        FILL, LINES, LINE_STRIP, POINTS, PRIMITIVE_TYPES, TRIANGLES, TRIANGLE_FAN, TRIANGLE_STRIP,
+    },
+    slider_loader::{
+        HUE_COLOR_PICKER, INCREMENT_DECREMENT_POINTER_SLIDER, QUAD_COLOR_PICKER, SLIDER_STYLES,
+    },
+    textbox_loader::{FIXED_SIZE_ONE_LINE_TEXTBOX, TEXTBOX_STYLES, TEXT_AREA},
+};
+
+/// What an attribute value has to parse as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    Str,
+    /// One of these words
+    OneOf(&'static [&'static str]),
+    Bool,
+    I32,
+    U16,
+    U32,
+    Usize,
+    F32,
+    Color,
+    UIPosition,
+    Vector2U16,
+    Vector2U32,
+    Vector2I32,
+    Vector2F32,
+    /// `(x:0,y:0),(x:4,y:0),...`
+    Vertices,
+}
+
+impl ValueKind {
+    /// Parses `value` the way the loaders do. The error says why it failed
+    pub fn check(self, value: &str) -> Result<(), Box<dyn Error>> {
+        match self {
+            ValueKind::Str => Ok(()),
+            ValueKind::OneOf(values) if values.contains(&value.trim()) => Ok(()),
+            ValueKind::OneOf(values) => Err(format!("expected one of {:?}", values).into()),
+            ValueKind::Bool => value.parse::<bool>().map(|_| ()).map_err(Into::into),
+            ValueKind::I32 => value.parse::<i32>().map(|_| ()).map_err(Into::into),
+            ValueKind::U16 => value.parse::<u16>().map(|_| ()).map_err(Into::into),
+            ValueKind::U32 => value.parse::<u32>().map(|_| ()).map_err(Into::into),
+            ValueKind::Usize => value.parse::<usize>().map(|_| ()).map_err(Into::into),
+            ValueKind::F32 => value.parse::<f32>().map(|_| ()).map_err(Into::into),
+            ValueKind::Color => color_from_str(value).map(|_| ()),
+            ValueKind::UIPosition => UIPosition::from_str(value).map(|_| ()).map_err(Into::into),
+            ValueKind::Vector2U16 => vector2_from_str::<u16>(value).map(|_| ()),
+            ValueKind::Vector2U32 => vector2_from_str::<u32>(value).map(|_| ()),
+            ValueKind::Vector2I32 => vector2_from_str::<i32>(value).map(|_| ()),
+            ValueKind::Vector2F32 => vector2_from_str::<f32>(value).map(|_| ()),
+            ValueKind::Vertices => check_vertices(value),
+        }
+    }
+}
+
+/// Every vertex needs both an x and a y, otherwise `vertex_array_from_string` skips it
+fn check_vertices(value: &str) -> Result<(), Box<dyn Error>> {
+    for tuple_list in get_list_of_tuple_lists_from_string(value) {
+        let tuples = tuple_list.collect::<Result<Vec<_>, _>>()?;
+        let (mut x, mut y) = (None, None);
+        for (axis, amt) in &tuples {
+            match axis.trim().to_lowercase().as_str() {
+                "x" => x = Some(amt.parse::<f32>()?),
+                "y" => y = Some(amt.parse::<f32>()?),
+                _ => return Err(format!("{} is not x or y", axis).into()),
+            }
+        }
+        if tuples.len() != 2 || x.is_none() || y.is_none() {
+            return Err(format!("Vertex {:?} needs exactly one x and one y", tuples).into());
+        }
+    }
+
+    Ok(())
+}
+
+/// What may be inside of an element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Children {
+    None,
+    /// Only text, which the element displays
+    Text,
+    AtMostOne,
+    ExactlyOne,
+    Any,
+}
+
+pub type Attributes = &'static [(&'static str, ValueKind)];
+
+#[derive(Debug, Clone, Copy)]
+pub struct ElementSchema {
+    pub required: Attributes,
+    pub optional: Attributes,
+    pub children: Children,
+}
+
+impl ElementSchema {
+    const fn new(required: Attributes, optional: Attributes, children: Children) -> Self {
+        Self {
+            required,
+            optional,
+            children,
+        }
+    }
+
+    pub fn attribute_kind(&self, name: &str) -> Option<ValueKind> {
+        self.required
+            .iter()
+            .chain(self.optional)
+            .find(|(attribute, _)| *attribute == name)
+            .map(|&(_, kind)| kind)
+    }
+
+    pub fn attribute_names(&self) -> impl Iterator<Item = &'static str> {
+        self.required
+            .iter()
+            .chain(self.optional)
+            .map(|&(attribute, _)| attribute)
+    }
+}
+
+/// Allowed on every element, and ignored by the loaders. Useful to document a layout, since
+/// comments are not supported
+pub const INFO_ATTRIBUTE: &str = "info";
+pub const TYPE_ATTRIBUTE: &str = "type";
+pub const ROOT_NODE: &str = "RootNode";
+
+/// Every element the [`element_loader`](super::super::element_loader::element_loader) knows
+pub const ELEMENT_NAMES: [&str; 14] = [
+    "Button",
+    "Slider",
+    "TextBox",
+    "ListBox",
+    "Dropdown",
+    "Div",
+    "Grid",
+    "ScrollView",
+    "Sets",
+    "Background",
+    "Text",
+    "Primitive",
+    "Image",
+    "Empty",
+];
+
+/// Elements that emit events
+pub const ACTIONABLE_ELEMENTS: [&str; 5] = ["Button", "Slider", "TextBox", "ListBox", "Dropdown"];
+
+/// The values the `type` attribute of `element_name` can have. None if the element has no
+/// type
+pub fn element_types(element_name: &str) -> Option<&'static [&'static str]> {
+    Some(match element_name {
+        "Button" => &BUTTON_STYLES,
+        "Slider" => &SLIDER_STYLES,
+        "TextBox" => &TEXTBOX_STYLES,
+        "ListBox" => &LISTBOX_STYLES,
+        "Dropdown" => &DROPDOWN_TYPES,
+        "Background" => &BACKGROUND_TYPES,
+        "Primitive" => &PRIMITIVE_TYPES,
+        "Image" => &IMAGE_TYPES,
+        _ => return None,
+    })
+}
+
+const POSITION: (&str, ValueKind) = ("position", ValueKind::UIPosition);
+const SCALE: (&str, ValueKind) = ("scale", ValueKind::F32);
+const FONT_SIZE: (&str, ValueKind) = ("font_size", ValueKind::U32);
+const COLOR: (&str, ValueKind) = ("color", ValueKind::Color);
+const EVENT_ID: (&str, ValueKind) = ("event_id", ValueKind::U16);
+const SYNC_ID: (&str, ValueKind) = ("sync_id", ValueKind::U16);
+const ASSET: (&str, ValueKind) = ("asset", ValueKind::Str);
+const FRAME_ID: (&str, ValueKind) = ("frame_id", ValueKind::Usize);
+const HOVER_FRAME_ID: (&str, ValueKind) = ("hover_frame_id", ValueKind::Usize);
+const CLICK_FRAME_ID: (&str, ValueKind) = ("click_frame_id", ValueKind::Usize);
+const PADDING: (&str, ValueKind) = ("padding", ValueKind::UIPosition);
+const LAYOUT: (&str, ValueKind) = ("layout", ValueKind::OneOf(&LAYOUT_DIRECTIONS));
+const GAP: (&str, ValueKind) = ("gap", ValueKind::I32);
+const ALIGN: (&str, ValueKind) = ("align", ValueKind::OneOf(&LAYOUT_ALIGNMENTS));
+const JUSTIFY: (&str, ValueKind) = ("justify", ValueKind::OneOf(&LAYOUT_ALIGNMENTS));
+const WRAP: (&str, ValueKind) = ("wrap", ValueKind::Bool);
+
+const PRIMITIVE: ElementSchema = ElementSchema::new(
+    &[],
+    &[POSITION, ("vertices", ValueKind::Vertices), COLOR],
+    Children::None,
+);
+
+/// Attributes and children `element_name` of `element_type` accepts, as read by its
+/// loader. None if there is no such element or type
+pub fn element_schema(element_name: &str, element_type: Option<&str>) -> Option<ElementSchema> {
+    use ValueKind::*;
+
+    let schema = match (element_name, element_type) {
+        (ROOT_NODE, None) => {
+            ElementSchema::new(&[("xmlns", Str)], &[SCALE, FONT_SIZE, COLOR], Children::Any)
+        }
+        ("Button", Some(IMAGE_BUTTON)) => ElementSchema::new(
+            &[ASSET, FRAME_ID, HOVER_FRAME_ID, CLICK_FRAME_ID],
+            &[POSITION, SCALE, EVENT_ID, SYNC_ID],
+            Children::None,
+        ),
+        ("Button", Some(TILING_BUTTON)) => ElementSchema::new(
+            &[ASSET, FRAME_ID, HOVER_FRAME_ID, CLICK_FRAME_ID],
+            &[POSITION, ("size", Vector2F32), SCALE, EVENT_ID, SYNC_ID],
+            Children::AtMostOne,
+        ),
+        ("Button", Some(BOOLEAN_IMAGE_BUTTON)) => ElementSchema::new(
+            &[
+                ASSET,
+                ("truth_frame_id", Usize),
+                ("truth_hover_frame_id", Usize),
+                ("truth_click_frame_id", Usize),
+                ("false_frame_id", Usize),
+                ("false_hover_frame_id", Usize),
+                ("false_click_frame_id", Usize),
+            ],
+            &[POSITION, SCALE, EVENT_ID, SYNC_ID],
+            Children::None,
+        ),
+        ("Button", Some(PRIMITIVE_FILL_BUTTON)) => ElementSchema::new(
+            &[],
+            &[
+                COLOR,
+                ("hover_color", Color),
+                ("click_color", Color),
+                EVENT_ID,
+            ],
+            Children::AtMostOne,
+        ),
+        ("Button", Some(COLOR_SWATCH_BUTTON)) => ElementSchema::new(
+            &[],
+            &[POSITION, ("size", Vector2U32), COLOR, EVENT_ID, SYNC_ID],
+            Children::None,
+        ),
+        ("Slider", Some(INCREMENT_DECREMENT_POINTER_SLIDER)) => ElementSchema::new(
+            &[
+                ASSET,
+                FRAME_ID,
+                HOVER_FRAME_ID,
+                CLICK_FRAME_ID,
+                ("min", F32),
+                ("max", F32),
+                ("increment", F32),
+            ],
+            &[
+                POSITION,
+                ("size", Vector2U16),
+                SCALE,
+                FONT_SIZE,
+                COLOR,
+                EVENT_ID,
+                SYNC_ID,
+            ],
+            Children::None,
+        ),
+        ("Slider", Some(QUAD_COLOR_PICKER)) => ElementSchema::new(
+            &[
+                ("top_left_color", Color),
+                ("top_right_color", Color),
+                ("bottom_right_color", Color),
+                ("bottom_left_color", Color),
+            ],
+            &[
+                POSITION,
+                ("size", Vector2U32),
+                SCALE,
+                FONT_SIZE,
+                COLOR,
+                EVENT_ID,
+                SYNC_ID,
+            ],
+            Children::ExactlyOne,
+        ),
+        ("Slider", Some(HUE_COLOR_PICKER)) => ElementSchema::new(
+            &[],
+            &[
+                POSITION,
+                ("size", Vector2U32),
+                SCALE,
+                FONT_SIZE,
+                COLOR,
+                EVENT_ID,
+                SYNC_ID,
+            ],
+            Children::ExactlyOne,
+        ),
+        ("TextBox", Some(FIXED_SIZE_ONE_LINE_TEXTBOX | TEXT_AREA)) => ElementSchema::new(
+            &[],
+            &[
+                POSITION,
+                ("size", Vector2U16),
+                FONT_SIZE,
+                COLOR,
+                ("background_color", Color),
+                EVENT_ID,
+                SYNC_ID,
+            ],
+            Children::Text,
+        ),
+        ("ListBox", Some(UP_DOWN_SCROLL_LISTBOX)) => ElementSchema::new(
+            &[
+                ASSET,
+                FRAME_ID,
+                HOVER_FRAME_ID,
+                CLICK_FRAME_ID,
+                ("options", Str),
+            ],
+            &[
+                POSITION,
+                PADDING,
+                ("number_of_buttons", Usize),
+                SCALE,
+                FONT_SIZE,
+                COLOR,
+                EVENT_ID,
+                SYNC_ID,
+            ],
+            Children::None,
+        ),
+        ("Dropdown", Some(TILING_DROPDOWN)) => ElementSchema::new(
+            &[
+                ASSET,
+                FRAME_ID,
+                HOVER_FRAME_ID,
+                CLICK_FRAME_ID,
+                ("options", Str),
+            ],
+            &[
+                POSITION,
+                PADDING,
+                ("max_visible_options", Usize),
+                SCALE,
+                FONT_SIZE,
+                COLOR,
+                EVENT_ID,
+                SYNC_ID,
+            ],
+            Children::None,
+        ),
+        ("Background", Some(REPEATABLE_3X3_BACKGROUND)) => ElementSchema::new(
+            &[ASSET, ("frame_id", U16)],
+            &[POSITION, PADDING, ("size", Vector2U32), SCALE],
+            Children::Any,
+        ),
+        ("Primitive", Some(FILL)) => ElementSchema::new(&[], &[COLOR], Children::None),
+        (
+            "Primitive",
+            Some(POINTS | LINES | LINE_STRIP | TRIANGLES | TRIANGLE_STRIP | TRIANGLE_FAN),
+        ) => PRIMITIVE,
+        ("Image", Some(ICON)) => {
+            ElementSchema::new(&[("name", Str)], &[POSITION, SCALE], Children::None)
+        }
+        ("Div", None) => ElementSchema::new(
+            &[],
+            &[
+                POSITION,
+                PADDING,
+                ("size", Vector2U32),
+                SCALE,
+                FONT_SIZE,
+                COLOR,
+                LAYOUT,
+                GAP,
+                ALIGN,
+                JUSTIFY,
+                WRAP,
+            ],
+            Children::Any,
+        ),
+        ("Grid", None) => ElementSchema::new(
+            &[],
+            &[
+                POSITION,
+                ("size", Vector2I32),
+                ("grid_layout", Vector2F32),
+                ("pagination_size", Vector2U16),
+                SCALE,
+                FONT_SIZE,
+                COLOR,
+            ],
+            Children::Any,
+        ),
+        ("ScrollView", None) => ElementSchema::new(
+            &[
+                ("size", Vector2I32),
+                ASSET,
+                FRAME_ID,
+                HOVER_FRAME_ID,
+                CLICK_FRAME_ID,
+            ],
+            &[POSITION, SCALE, FONT_SIZE, COLOR],
+            Children::Any,
+        ),
+        ("Sets", None) => ElementSchema::new(
+            &[],
+            &[
+                POSITION,
+                PADDING,
+                ("size", Vector2U32),
+                SCALE,
+                SYNC_ID,
+                LAYOUT,
+                GAP,
+                ALIGN,
+                JUSTIFY,
+                WRAP,
+            ],
+            Children::Any,
+        ),
+        ("Text", None) => {
+            ElementSchema::new(&[], &[POSITION, FONT_SIZE, COLOR, SYNC_ID], Children::Text)
+        }
+        ("Empty", None) => ElementSchema::new(&[], &[], Children::None),
+        _ => return None,
+    };
+
+    Some(schema)
+}
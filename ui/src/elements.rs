@@ -1,6 +1,8 @@
 pub mod background;
 pub mod button;
+pub mod dropdown;
 pub mod grouping;
+pub mod identified;
 pub mod listbox;
 pub mod misc;
 pub mod missing_texture;
@@ -8,6 +10,7 @@ pub mod root_node;
 pub mod slider;
 pub mod textbox;
 pub mod tiling_sprites;
+pub mod tooltip;
 pub mod traits;
 
 #[derive(Clone, Debug, Default)]
@@ -17,15 +20,18 @@ pub enum Element {
     Slider(Box<dyn slider::traits::Slider>),
     TextBox(Box<dyn textbox::traits::TextBox>),
     ListBox(Box<dyn listbox::traits::ListBox>),
+    Dropdown(Box<dyn dropdown::traits::Dropdown>),
     TilingSprite(Box<dyn tiling_sprites::traits::TilingSpriteElement>),
     Background(Box<dyn background::traits::BackgroundElement>),
     Div(div::Div),
     Grid(grid::Grid),
+    ScrollView(scroll_view::ScrollView),
     Sets(sets::Sets),
     Text(text::Text),
     Primitive(primitive::Primitive),
     Image(image::Image),
     RootNode(root_node::RootNode),
+    Identified(Box<identified::IdentifiedElement>),
     #[default]
     Empty,
 }
@@ -39,15 +45,18 @@ impl Element {
             Slider(_) => "Slider",
             TextBox(_) => "TextBox",
             ListBox(_) => "ListBox",
+            Dropdown(_) => "Dropdown",
             TilingSprite(_) => "TilingSprite",
             Background(_) => "Background",
             Div(_) => "Div",
             Grid(_) => "Grid",
+            ScrollView(_) => "ScrollView",
             Sets(_) => "Sets",
             Text(_) => "Text",
             Primitive(_) => "Primitive",
             Image(_) => "Image",
             RootNode(_) => "RootNode",
+            Identified(_) => "Identified",
             Empty => "Empty",
         }
     }
@@ -60,15 +69,18 @@ impl Element {
             Slider(ele) => Some(ele.as_element()),
             TextBox(ele) => Some(ele.as_element()),
             ListBox(ele) => Some(ele.as_element()),
+            Dropdown(ele) => Some(ele.as_element()),
             TilingSprite(ele) => Some(ele.as_element()),
             Background(ele) => Some(ele.as_element()),
             Div(ele) => Some(ele),
             Grid(ele) => Some(ele),
+            ScrollView(ele) => Some(ele),
             Sets(ele) => Some(ele),
             Text(ele) => Some(ele),
             Primitive(ele) => Some(ele),
             Image(ele) => Some(ele),
             RootNode(ele) => Some(ele),
+            Identified(ele) => ele.element.get_ele_with_element_trait(),
             Empty => None,
         }
     }
@@ -81,20 +93,40 @@ impl Element {
             Slider(ele) => Some(ele.as_mut_element()),
             TextBox(ele) => Some(ele.as_mut_element()),
             ListBox(ele) => Some(ele.as_mut_element()),
+            Dropdown(ele) => Some(ele.as_mut_element()),
             TilingSprite(ele) => Some(ele.as_mut_element()),
             Background(ele) => Some(ele.as_mut_element()),
             Div(ele) => Some(ele),
             Grid(ele) => Some(ele),
+            ScrollView(ele) => Some(ele),
             Sets(ele) => Some(ele),
             Text(ele) => Some(ele),
             Primitive(ele) => Some(ele),
             Image(ele) => Some(ele),
             RootNode(ele) => Some(ele),
+            Identified(ele) => ele.element.get_mut_ele_with_element_trait(),
             Empty => None,
         }
     }
 
+    /// The element as something that can be hovered, focused and activated, if it is one
+    pub fn actionable_element_mut(&mut self) -> Option<&mut dyn traits::ActionableElement> {
+        use Element::*;
+        match self {
+            Button(ele) => Some(ele.as_mut_actionable_element()),
+            Slider(ele) => Some(ele.as_mut_actionable_element()),
+            TextBox(ele) => Some(ele.as_mut_actionable_element()),
+            ListBox(ele) => Some(ele.as_mut_actionable_element()),
+            Dropdown(ele) => Some(ele.as_mut_actionable_element()),
+            Identified(ele) => ele.element.actionable_element_mut(),
+            _ => None,
+        }
+    }
+
     pub fn traverse_dom_mut<F: FnMut(&mut Element)>(&mut self, sync_element: &mut F) {
+        if let Element::Identified(ele) = self {
+            return ele.element.traverse_dom_mut(sync_element);
+        }
         sync_element(self);
 
         use Element::*;
@@ -119,6 +151,11 @@ impl Element {
                     ele.traverse_dom_mut(&mut *sync_element)
                 }
             }
+            ScrollView(ele) => {
+                for ele in ele.mut_children() {
+                    ele.traverse_dom_mut(&mut *sync_element)
+                }
+            }
             Sets(ele) => {
                 for ele in ele.mut_div().mut_children() {
                     ele.traverse_dom_mut(&mut *sync_element)
@@ -129,6 +166,9 @@ impl Element {
     }
 
     pub fn traverse_dom<F: Fn(&Element)>(&self, sync_element: &F) {
+        if let Element::Identified(ele) = self {
+            return ele.element.traverse_dom(sync_element);
+        }
         sync_element(self);
 
         use Element::*;
@@ -153,6 +193,11 @@ impl Element {
                     ele.traverse_dom(sync_element)
                 }
             }
+            ScrollView(ele) => {
+                for ele in ele.children() {
+                    ele.traverse_dom(sync_element)
+                }
+            }
             Sets(ele) => {
                 for ele in ele.div().children() {
                     ele.traverse_dom(sync_element)
@@ -161,6 +206,44 @@ impl Element {
             _ => {}
         }
     }
+
+    /// The element given `id` in its layout. See [`identified::IdentifiedElement`]
+    pub fn find_by_id_mut(&mut self, id: &str) -> Option<&mut Element> {
+        match self {
+            Element::Identified(ele) if ele.id.as_deref() == Some(id) => Some(&mut ele.element),
+            ele => ele
+                .mut_children()
+                .find_map(|child| child.find_by_id_mut(id)),
+        }
+    }
+
+    /// Like [`Element::traverse_dom_mut`], but only visits the elements that were given an
+    /// id or a class
+    pub fn traverse_identified_mut<F: FnMut(&mut identified::IdentifiedElement)>(
+        &mut self,
+        visit: &mut F,
+    ) {
+        if let Element::Identified(ele) = self {
+            visit(ele);
+        }
+        for child in self.mut_children() {
+            child.traverse_identified_mut(&mut *visit);
+        }
+    }
+
+    fn mut_children(&mut self) -> Box<dyn Iterator<Item = &mut Element> + '_> {
+        use Element::*;
+        match self {
+            RootNode(ele) => Box::new(ele.mut_children()),
+            Background(ele) => ele.mut_children(),
+            Grid(ele) => Box::new(ele.mut_children()),
+            Div(ele) => Box::new(ele.mut_children()),
+            ScrollView(ele) => Box::new(ele.mut_children()),
+            Sets(ele) => Box::new(ele.mut_div().mut_children()),
+            Identified(ele) => Box::new(std::iter::once(&mut ele.element)),
+            _ => Box::new(std::iter::empty()),
+        }
+    }
 }
 
 use crate::{events::*, syncs::Syncs, ui_settings::UISettings, utils::positioning::UIPosition};
@@ -171,7 +254,7 @@ use sfml::{
 use utils::resource_manager::ResourceManager;
 
 use self::{
-    grouping::{div, grid, sets},
+    grouping::{div, grid, scroll_view, sets},
     misc::{image, primitive, text},
     traits::cast_element,
 };
@@ -1,4 +1,5 @@
 pub mod boolean_image_button;
+pub mod color_swatch_button;
 pub mod image_button;
 pub mod primitive_fill_button;
 pub mod repeatable_sprite_button;
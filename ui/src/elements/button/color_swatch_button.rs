@@ -0,0 +1,193 @@
+use sfml::{
+    graphics::{Color, IntRect, RectangleShape, RenderTarget, RenderTexture, Shape, Transformable},
+    system::{Vector2i, Vector2u},
+    window::Event as SFMLEvent,
+};
+use tracing::warn;
+use utils::resource_manager::ResourceManager;
+
+use super::traits::Button;
+use crate::{
+    elements::traits::{cast_actionable_element, cast_element, ActionableElement, Element},
+    events::{Event, EventId, Events},
+    syncs::{ui_syncs_not_synced_str, SyncId, Syncs},
+    ui_settings::UISettings,
+    utils::{mouse_ui_states::UIMouseStates, positioning::UIPosition},
+};
+
+const EMPTY_SWATCH_COLOR: Color = Color::rgba(0, 0, 0, 60);
+const OUTLINE_COLOR: Color = Color::rgb(9, 29, 30);
+const HOVER_OUTLINE_COLOR: Color = Color::rgb(247, 229, 228);
+const CLICK_OUTLINE_COLOR: Color = Color::rgb(255, 214, 10);
+const OUTLINE_THICKNESS: f32 = 1.;
+const HOVER_OUTLINE_THICKNESS: f32 = 2.;
+
+/// A square of one color, like a paint swatch. Synced with [`Syncs::Color`].
+///
+/// A swatch without a color is drawn faded out. Clicking it still triggers an event, which
+/// is a [`Events::BooleanEvent`] telling whether the swatch has a color.
+#[derive(Debug, Clone)]
+pub struct ColorSwatchButton {
+    global_bounds: IntRect,
+    position: UIPosition,
+    color: Option<Color>,
+    swatch: RectangleShape<'static>,
+    current_mouse_state: UIMouseStates,
+    event_id: EventId,
+    sync_id: SyncId,
+    rerender: bool,
+}
+
+impl ColorSwatchButton {
+    pub fn new(
+        position: UIPosition,
+        size: Vector2u,
+        color: Option<Color>,
+        event_id: EventId,
+        sync_id: SyncId,
+    ) -> Self {
+        let mut swatch_button = Self {
+            global_bounds: IntRect::new(0, 0, size.x as i32, size.y as i32),
+            position,
+            color,
+            swatch: RectangleShape::with_size(size.as_other()),
+            current_mouse_state: UIMouseStates::Nothing,
+            event_id,
+            sync_id,
+            rerender: true,
+        };
+        swatch_button.style_swatch();
+
+        swatch_button
+    }
+
+    pub fn color(&self) -> Option<Color> {
+        self.color
+    }
+
+    pub fn set_color(&mut self, color: Option<Color>) {
+        self.color = color;
+        self.style_swatch();
+    }
+
+    fn style_swatch(&mut self) {
+        self.swatch
+            .set_fill_color(self.color.unwrap_or(EMPTY_SWATCH_COLOR));
+        let (outline_color, outline_thickness) = match self.current_mouse_state {
+            UIMouseStates::Nothing => (OUTLINE_COLOR, OUTLINE_THICKNESS),
+            UIMouseStates::Hover => (HOVER_OUTLINE_COLOR, HOVER_OUTLINE_THICKNESS),
+            UIMouseStates::Click => (CLICK_OUTLINE_COLOR, HOVER_OUTLINE_THICKNESS),
+        };
+        self.swatch.set_outline_color(outline_color);
+        // Drawn inwards, so hovering does not grow the swatch past its bounds
+        self.swatch.set_outline_thickness(-outline_thickness);
+        self.rerender = true;
+    }
+}
+
+impl ActionableElement for ColorSwatchButton {
+    cast_actionable_element!();
+
+    fn triggered_event(&self) -> Event {
+        Event {
+            id: self.event_id(),
+            event: Events::BooleanEvent(self.color.is_some()),
+        }
+    }
+
+    fn bind_pressed(&mut self, mouse_pos: Vector2i) {
+        self.set_hover(mouse_pos);
+        if self.is_hover() {
+            self.current_mouse_state = UIMouseStates::Click;
+            self.style_swatch();
+        }
+    }
+
+    fn bind_released(&mut self, mouse_pos: Vector2i) {
+        self.current_mouse_state = UIMouseStates::Nothing;
+        self.set_hover(mouse_pos);
+        self.style_swatch();
+    }
+
+    fn set_hover(&mut self, mouse_pos: Vector2i) {
+        let previous_mouse_state = self.current_mouse_state;
+        self.current_mouse_state
+            .set_hover(self.global_bounds.contains(mouse_pos));
+
+        if previous_mouse_state != self.current_mouse_state {
+            self.style_swatch();
+        }
+    }
+
+    fn is_hover(&self) -> bool {
+        self.current_mouse_state.is_hover()
+    }
+
+    fn event_id(&self) -> EventId {
+        self.event_id
+    }
+}
+
+impl Button for ColorSwatchButton {
+    fn current_mouse_state(&self) -> UIMouseStates {
+        self.current_mouse_state
+    }
+
+    fn box_clone(&self) -> Box<dyn Button> {
+        Box::new(self.clone())
+    }
+}
+
+impl Element for ColorSwatchButton {
+    cast_element!();
+
+    fn update(&mut self, _resource_manager: &ResourceManager) -> (Vec<Event>, bool) {
+        (Default::default(), self.rerender)
+    }
+
+    fn update_size(&mut self) {
+        let size = self.swatch.size();
+        self.global_bounds.width = size.x as i32;
+        self.global_bounds.height = size.y as i32;
+    }
+
+    fn update_position(&mut self, relative_rect: IntRect) {
+        self.global_bounds = self
+            .position
+            .center_with_size(relative_rect, self.global_bounds.size());
+        self.swatch
+            .set_position(self.global_bounds.position().as_other());
+    }
+
+    fn set_ui_position(&mut self, ui_position: UIPosition, relative_rect: IntRect) {
+        self.position = ui_position;
+        self.update_size();
+        self.update_position(relative_rect);
+    }
+
+    fn render(&mut self, window: &mut RenderTexture) {
+        window.draw(&self.swatch);
+        self.rerender = false;
+    }
+
+    fn global_bounds(&self) -> IntRect {
+        self.global_bounds
+    }
+
+    fn event_handler(&mut self, ui_settings: &UISettings, event: SFMLEvent) -> (Vec<Event>, bool) {
+        Button::event_handler(self, ui_settings, event)
+    }
+
+    fn sync_id(&self) -> SyncId {
+        self.sync_id
+    }
+
+    fn sync(&mut self, sync: Syncs) {
+        let Syncs::Color(color) = sync else {
+            warn!(ui_syncs_not_synced_str!(), Syncs::Color(None), sync);
+            return;
+        };
+
+        self.set_color(color);
+    }
+}
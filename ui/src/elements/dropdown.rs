@@ -0,0 +1,2 @@
+pub mod tiling_dropdown;
+pub mod traits;
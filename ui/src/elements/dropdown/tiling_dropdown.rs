@@ -0,0 +1,752 @@
+use sfml::{
+    graphics::{
+        Color, FloatRect, IntRect, PrimitiveType, RcSprite, RectangleShape, RenderStates,
+        RenderTarget, RenderTexture, Shape, Transformable, Vertex,
+    },
+    system::{Vector2f, Vector2i},
+    window::{mouse::Wheel, Event as SFMLEvent},
+};
+use tracing::error;
+use utils::{
+    quads::Quad,
+    resource_manager::{asset::Asset, ResourceManager},
+};
+
+use crate::{
+    elements::{
+        misc::text::Text,
+        traits::{cast_actionable_element, cast_element, ActionableElement, Element},
+    },
+    events::{Event, EventId, Events},
+    syncs::{SyncId, Syncs},
+    ui_settings::{
+        controls::{possible_binds::PossibleBinds, possible_inputs::PossibleInputs},
+        UISettings,
+    },
+    utils::{mouse_ui_states::UIMouseStates, positioning::UIPosition},
+};
+
+use self::option_list::{wheel_steps, OptionList};
+
+use super::traits::Dropdown;
+
+mod option_list;
+
+/// The box around the current option, by row and column. The middle row and column stretch
+const SELECTED_SLICE_NAMES: [[&str; 2]; 3] = [
+    ["top_left_selected", "top_selected"],
+    ["left_selected", "middle_selected"],
+    ["bottom_left_selected", "bottom_selected"],
+];
+/// The box around the arrow, at the right of the current option
+const ARROW_SLICE_NAMES: [[&str; 3]; 3] = [
+    [
+        "top_left_arrow_icon",
+        "top_arrow_icon",
+        "top_right_arrow_icon",
+    ],
+    ["left_arrow_icon", "middle_arrow_icon", "right_arrow_icon"],
+    [
+        "bottom_left_arrow_icon",
+        "bottom_arrow_icon",
+        "bottom_right_arrow_icon",
+    ],
+];
+/// The list of options, below the current option. The top row stretches over every option
+const OPTION_SLICE_NAMES: [[&str; 2]; 2] = [
+    ["left_option", "middle_option"],
+    ["bottom_left_option", "bottom_option"],
+];
+/// The scroll bar, at the right of the list of options
+const SCROLL_BAR_SLICE_NAMES: [[&str; 3]; 2] = [
+    [
+        "left_scroll_bar_icon",
+        "middle_scroll_bar_icon",
+        "right_scroll_bar_icon",
+    ],
+    [
+        "bottom_left_scroll_bar_icon",
+        "bottom_scroll_bar_icon",
+        "bottom_right_scroll_bar_icon",
+    ],
+];
+/// Shows which options of the list are visible
+const SCROLL_THUMB_SLICE_NAMES: [[&str; 1]; 3] = [
+    ["scroll_bar_icon_top"],
+    ["scroll_bar_icon_middle"],
+    ["scroll_bar_icon_bottom"],
+];
+const OPEN_ICON_SLICE_NAME: &str = "icon_down";
+const CLOSE_ICON_SLICE_NAME: &str = "icon_up";
+/// Drawn over the option that is picked when the select bind is released
+const HIGHLIGHT_COLOR: Color = Color::rgba(255, 255, 255, 48);
+
+/// Where the slices of the closed dropdown are in the texture, for one frame of the asset
+#[derive(Debug, Clone, Copy, Default)]
+struct BoxSlices {
+    selected: [[IntRect; 2]; 3],
+    arrow: [[IntRect; 3]; 3],
+}
+
+/// Where the slices of the list of options are in the texture
+#[derive(Debug, Clone, Copy, Default)]
+struct PopupSlices {
+    option: [[IntRect; 2]; 2],
+    scroll_bar: [[IntRect; 3]; 2],
+    scroll_thumb: [[IntRect; 1]; 3],
+    open_icon: IntRect,
+    close_icon: IntRect,
+}
+
+/// Shows the current option, and opens a list of every option when clicked. The list is
+/// drawn over the rest of the dom and scrolls when there are more options than
+/// `max_visible_options`.
+///
+/// The scroll wheel changes the current option while the dropdown is closed. While it is
+/// open, the UI up and down binds move through the options, select picks one and escape
+/// closes it.
+#[derive(Debug, Clone)]
+pub struct TilingDropdown {
+    global_bounds: IntRect,
+    position: UIPosition,
+    padding: UIPosition,
+    scale: f32,
+    rerender: bool,
+    event_id: EventId,
+    sync_id: SyncId,
+    /// Every slice is drawn from the texture of this sprite
+    texture_sprite: RcSprite,
+    /// One for each of the frame, hover frame and click frame
+    box_slices: [BoxSlices; 3],
+    popup_slices: PopupSlices,
+    current_mouse_state: UIMouseStates,
+    box_vertices: Vec<Vertex>,
+    popup_vertices: Vec<Vertex>,
+    highlight: RectangleShape<'static>,
+    selected_text: Text,
+    /// One for each row of the list of options
+    option_texts: Vec<Text>,
+    options: Vec<String>,
+    option_list: OptionList,
+    row_height: i32,
+}
+
+impl TilingDropdown {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        resource_manager: &ResourceManager,
+        asset_id: &str,
+        position: UIPosition,
+        frame_id: usize,
+        hover_frame_id: usize,
+        click_frame_id: usize,
+        mut options: Vec<String>,
+        max_visible_options: usize,
+        padding: Option<UIPosition>,
+        scale: f32,
+        font_size: u32,
+        font_color: Color,
+        event_id: EventId,
+        sync_id: SyncId,
+    ) -> Self {
+        let asset = resource_manager.fetch_asset(asset_id);
+        if options.is_empty() {
+            error!("Number of options provided is zero! Adding an option to the list.");
+            options.push(Default::default());
+        }
+        let option_list = OptionList::new(options.len(), max_visible_options);
+        let text = Text::new(
+            resource_manager,
+            UIPosition::START_HORIZONTAL,
+            "",
+            true,
+            font_size,
+            font_color,
+            0,
+        );
+        let mut highlight = RectangleShape::with_size(Default::default());
+        highlight.set_fill_color(HIGHLIGHT_COLOR);
+
+        let mut dropdown = Self {
+            global_bounds: Default::default(),
+            position,
+            padding: padding.unwrap_or_default(),
+            scale,
+            rerender: true,
+            event_id,
+            sync_id,
+            texture_sprite: asset.get_rc_sprite_with_frame_num(frame_id),
+            box_slices: [frame_id, hover_frame_id, click_frame_id].map(|frame_id| BoxSlices {
+                selected: slice_bounds(asset, SELECTED_SLICE_NAMES, frame_id),
+                arrow: slice_bounds(asset, ARROW_SLICE_NAMES, frame_id),
+            }),
+            popup_slices: PopupSlices {
+                option: slice_bounds(asset, OPTION_SLICE_NAMES, frame_id),
+                scroll_bar: slice_bounds(asset, SCROLL_BAR_SLICE_NAMES, frame_id),
+                scroll_thumb: slice_bounds(asset, SCROLL_THUMB_SLICE_NAMES, frame_id),
+                open_icon: asset.get_shifted_slice_bound(OPEN_ICON_SLICE_NAME, frame_id),
+                close_icon: asset.get_shifted_slice_bound(CLOSE_ICON_SLICE_NAME, frame_id),
+            },
+            current_mouse_state: Default::default(),
+            box_vertices: Vec::new(),
+            popup_vertices: Vec::new(),
+            highlight,
+            selected_text: text.clone(),
+            option_texts: vec![text; option_list.visible_rows()],
+            options,
+            option_list,
+            row_height: 0,
+        };
+        dropdown.update_size();
+
+        dropdown
+    }
+
+    fn scaled(&self, length: i32) -> f32 {
+        length as f32 * self.scale
+    }
+
+    fn box_slices(&self) -> BoxSlices {
+        match self.current_mouse_state {
+            UIMouseStates::Nothing => self.box_slices[0],
+            UIMouseStates::Hover => self.box_slices[1],
+            UIMouseStates::Click => self.box_slices[2],
+        }
+    }
+
+    fn visible_rows(&self) -> usize {
+        self.option_list.visible_rows()
+    }
+
+    /// The arrow at the right of the current option is square, unless its slices need more
+    /// room
+    fn arrow_width(&self) -> i32 {
+        let arrow = self.box_slices[0].arrow;
+        let min_width = self.scaled(arrow[1][0].width + arrow[1][1].width + arrow[1][2].width);
+
+        self.global_bounds.height.max(min_width as i32)
+    }
+
+    /// Bounds of the list of options, right below the current option
+    fn popup_bounds(&self) -> IntRect {
+        let bottom_height = self.scaled(self.popup_slices.option[1][0].height) as i32;
+
+        IntRect::new(
+            self.global_bounds.left,
+            self.global_bounds.top + self.global_bounds.height,
+            self.global_bounds.width,
+            self.row_height * self.visible_rows() as i32 + bottom_height,
+        )
+    }
+
+    /// Bounds of a row of the list of options, without the border and the scroll bar
+    fn row_bounds(&self, row: usize) -> IntRect {
+        let popup_bounds = self.popup_bounds();
+        let left_width = self.scaled(self.popup_slices.option[0][0].width) as i32;
+
+        IntRect::new(
+            popup_bounds.left + left_width,
+            popup_bounds.top + self.row_height * row as i32,
+            popup_bounds.width - left_width - self.arrow_width(),
+            self.row_height,
+        )
+    }
+
+    /// The row of the list at `position`, while the list is open
+    fn row_at(&self, position: Vector2i) -> Option<usize> {
+        (0..self.visible_rows()).find(|&row| self.row_bounds(row).contains(position))
+    }
+
+    fn open(&mut self) {
+        self.option_list.open();
+        self.update_layout();
+    }
+
+    fn close(&mut self) {
+        self.option_list.close();
+        self.update_layout();
+    }
+
+    /// Returns the event of the current option if it changed
+    fn current_option_changed(&mut self, is_changed: bool) -> Vec<Event> {
+        if !is_changed {
+            return Vec::new();
+        }
+
+        self.update_layout();
+        vec![self.triggered_event()]
+    }
+
+    fn update_layout(&mut self) {
+        self.update_texts();
+        self.update_vertices();
+        self.rerender = true;
+    }
+
+    fn update_texts(&mut self) {
+        let selected = self.box_slices[0].selected;
+        let left_width = self.scaled(selected[0][0].width) as i32;
+        let top_height = self.scaled(selected[0][0].height) as i32;
+        let bottom_height = self.scaled(selected[2][0].height) as i32;
+        let selected_bounds = IntRect::new(
+            self.global_bounds.left + left_width,
+            self.global_bounds.top + top_height,
+            self.global_bounds.width - left_width - self.arrow_width(),
+            self.global_bounds.height - top_height - bottom_height,
+        );
+        self.selected_text
+            .set_text(&self.options[self.option_list.current()]);
+        self.selected_text
+            .update_position(self.padding.padded_inner_rect(selected_bounds));
+
+        for row in 0..self.visible_rows() {
+            let row_bounds = self.padding.padded_inner_rect(self.row_bounds(row));
+            let option = &self.options[self.option_list.option_in_row(row)];
+            self.option_texts[row].set_text(option);
+            self.option_texts[row].update_position(row_bounds);
+        }
+    }
+
+    fn update_vertices(&mut self) {
+        let gb = self.global_bounds.as_other::<f32>();
+        let right = gb.left + gb.width;
+        let bottom = gb.top + gb.height;
+        let arrow_left = right - self.arrow_width() as f32;
+        let BoxSlices { selected, arrow } = self.box_slices();
+        let PopupSlices {
+            option,
+            scroll_bar,
+            scroll_thumb,
+            open_icon,
+            close_icon,
+        } = self.popup_slices;
+
+        let mut box_vertices = Vec::new();
+        append_slices(
+            &mut box_vertices,
+            &selected,
+            &[
+                gb.left,
+                gb.left + self.scaled(selected[0][0].width),
+                arrow_left,
+            ],
+            &[
+                gb.top,
+                gb.top + self.scaled(selected[0][0].height),
+                bottom - self.scaled(selected[2][0].height),
+                bottom,
+            ],
+        );
+        append_slices(
+            &mut box_vertices,
+            &arrow,
+            &[
+                arrow_left,
+                arrow_left + self.scaled(arrow[0][0].width),
+                right - self.scaled(arrow[0][2].width),
+                right,
+            ],
+            &[
+                gb.top,
+                gb.top + self.scaled(arrow[0][0].height),
+                bottom - self.scaled(arrow[2][0].height),
+                bottom,
+            ],
+        );
+        let icon = if self.option_list.is_open() {
+            close_icon
+        } else {
+            open_icon
+        };
+        let icon_size = Vector2f::new(self.scaled(icon.width), self.scaled(icon.height));
+        let icon_position = Vector2f::new(
+            ((arrow_left + right - icon_size.x) / 2.).floor(),
+            ((gb.top + bottom - icon_size.y) / 2.).floor(),
+        );
+        append_slice(
+            &mut box_vertices,
+            icon,
+            FloatRect::from_vecs(icon_position, icon_size),
+        );
+
+        let popup_bounds = self.popup_bounds().as_other::<f32>();
+        let popup_right = popup_bounds.left + popup_bounds.width;
+        let popup_bottom = popup_bounds.top + popup_bounds.height;
+        let rows_bottom = popup_bounds.top + (self.row_height * self.visible_rows() as i32) as f32;
+        let mut popup_vertices = Vec::new();
+        append_slices(
+            &mut popup_vertices,
+            &option,
+            &[
+                popup_bounds.left,
+                popup_bounds.left + self.scaled(option[0][0].width),
+                arrow_left,
+            ],
+            &[popup_bounds.top, rows_bottom, popup_bottom],
+        );
+        append_slices(
+            &mut popup_vertices,
+            &scroll_bar,
+            &[
+                arrow_left,
+                arrow_left + self.scaled(scroll_bar[0][0].width),
+                popup_right - self.scaled(scroll_bar[0][2].width),
+                popup_right,
+            ],
+            &[popup_bounds.top, rows_bottom, popup_bottom],
+        );
+        if self.options.len() > self.visible_rows() {
+            let track_height = rows_bottom - popup_bounds.top;
+            let option_count = self.options.len() as f32;
+            let thumb_ends_height =
+                self.scaled(scroll_thumb[0][0].height + scroll_thumb[2][0].height);
+            let thumb_height = (track_height * self.visible_rows() as f32 / option_count)
+                .max(thumb_ends_height)
+                .floor();
+            let thumb_top = (popup_bounds.top
+                + track_height * self.option_list.first_visible() as f32 / option_count)
+                .min(rows_bottom - thumb_height)
+                .floor();
+            let thumb_width = self.scaled(scroll_thumb[0][0].width);
+            let thumb_left = ((arrow_left + popup_right - thumb_width) / 2.).floor();
+            append_slices(
+                &mut popup_vertices,
+                &scroll_thumb,
+                &[thumb_left, thumb_left + thumb_width],
+                &[
+                    thumb_top,
+                    thumb_top + self.scaled(scroll_thumb[0][0].height),
+                    thumb_top + thumb_height - self.scaled(scroll_thumb[2][0].height),
+                    thumb_top + thumb_height,
+                ],
+            );
+        }
+
+        let highlight_bounds = self
+            .option_list
+            .row_of(self.option_list.highlighted())
+            .map(|row| self.row_bounds(row))
+            .unwrap_or_default();
+        self.highlight
+            .set_size(highlight_bounds.size().as_other::<f32>());
+        self.highlight
+            .set_position(highlight_bounds.position().as_other::<f32>());
+
+        self.box_vertices = box_vertices;
+        self.popup_vertices = popup_vertices;
+    }
+
+    fn closed_event_handler(
+        &mut self,
+        ui_settings: &UISettings,
+        event: SFMLEvent,
+    ) -> (Vec<Event>, bool) {
+        let binds = &ui_settings.binds;
+        match event {
+            SFMLEvent::MouseWheelScrolled { wheel, delta, .. }
+                if self.is_hover() && wheel == Wheel::VerticalWheel && delta != 0. =>
+            {
+                let is_changed = self.option_list.select_by(wheel_steps(delta));
+                (self.current_option_changed(is_changed), true)
+            }
+            SFMLEvent::MouseButtonPressed { button, .. }
+                if self.is_hover()
+                    && binds.is_bind_pressed_and_binded(
+                        PossibleInputs::from(button),
+                        PossibleBinds::Select,
+                    ) =>
+            {
+                self.bind_pressed(ui_settings.cursor_position);
+                (Default::default(), true)
+            }
+            SFMLEvent::MouseButtonReleased { button, .. }
+                if self.is_hover()
+                    && binds.is_bind_released_and_binded(
+                        PossibleInputs::from(button),
+                        PossibleBinds::Select,
+                    ) =>
+            {
+                self.bind_released(ui_settings.cursor_position);
+                (Default::default(), true)
+            }
+            _ => (Default::default(), self.rerender),
+        }
+    }
+
+    fn open_event_handler(
+        &mut self,
+        ui_settings: &UISettings,
+        event: SFMLEvent,
+    ) -> (Vec<Event>, bool) {
+        let binds = &ui_settings.binds;
+        let cursor_position = ui_settings.cursor_position;
+        match event {
+            SFMLEvent::MouseMoved { .. } => {
+                if let Some(row) = self.row_at(cursor_position) {
+                    self.option_list.highlight_row(row);
+                    self.update_layout();
+                }
+            }
+            SFMLEvent::MouseWheelScrolled { wheel, delta, .. }
+                if wheel == Wheel::VerticalWheel && delta != 0. =>
+            {
+                self.option_list.scroll_by(wheel_steps(delta));
+                if let Some(row) = self.row_at(cursor_position) {
+                    self.option_list.highlight_row(row);
+                }
+                self.update_layout();
+            }
+            // Clicking anywhere else only closes the list
+            SFMLEvent::MouseButtonPressed { button, .. }
+                if binds.is_bind_pressed_and_binded(
+                    PossibleInputs::from(button),
+                    PossibleBinds::Select,
+                ) =>
+            {
+                if !self.global_bounds.contains(cursor_position)
+                    && !self.popup_bounds().contains(cursor_position)
+                {
+                    self.close();
+                }
+            }
+            SFMLEvent::MouseButtonReleased { button, .. }
+                if binds.is_bind_released_and_binded(
+                    PossibleInputs::from(button),
+                    PossibleBinds::Select,
+                ) =>
+            {
+                if let Some(row) = self.row_at(cursor_position) {
+                    self.close();
+                    let is_changed = self.option_list.select(self.option_list.option_in_row(row));
+                    return (self.current_option_changed(is_changed), true);
+                }
+                if self.global_bounds.contains(cursor_position) {
+                    self.close();
+                }
+            }
+            _ if binds.is_bind_just_pressed(PossibleBinds::Escape) => self.close(),
+            _ if binds.is_bind_just_pressed(PossibleBinds::UIUp) => {
+                self.option_list.highlight_by(-1);
+                self.update_layout();
+            }
+            _ if binds.is_bind_just_pressed(PossibleBinds::UIDown) => {
+                self.option_list.highlight_by(1);
+                self.update_layout();
+            }
+            _ if binds.is_bind_just_released(PossibleBinds::Select) => {
+                self.close();
+                let is_changed = self.option_list.select(self.option_list.highlighted());
+                return (self.current_option_changed(is_changed), true);
+            }
+            _ => return (Default::default(), self.rerender),
+        }
+
+        (Default::default(), true)
+    }
+}
+
+/// Where each of `slice_names` is in the texture of `asset`
+fn slice_bounds<const C: usize, const R: usize>(
+    asset: &Asset,
+    slice_names: [[&str; C]; R],
+    frame_id: usize,
+) -> [[IntRect; C]; R] {
+    slice_names.map(|row| row.map(|slice_name| asset.get_shifted_slice_bound(slice_name, frame_id)))
+}
+
+/// Appends every slice stretched over its cell of the grid. `column_edges` and `row_edges`
+/// are where the cells start and end, so there is one more of them than of cells
+fn append_slices<const C: usize>(
+    vertices: &mut Vec<Vertex>,
+    slices: &[[IntRect; C]],
+    column_edges: &[f32],
+    row_edges: &[f32],
+) {
+    for (row, row_slices) in slices.iter().enumerate() {
+        for (column, &slice) in row_slices.iter().enumerate() {
+            let bounds = FloatRect::new(
+                column_edges[column],
+                row_edges[row],
+                column_edges[column + 1] - column_edges[column],
+                row_edges[row + 1] - row_edges[row],
+            );
+            append_slice(vertices, slice, bounds);
+        }
+    }
+}
+
+/// Appends the part of the texture in `texture_rect` stretched over `bounds`, as
+/// [`PrimitiveType::TRIANGLES`]
+fn append_slice(vertices: &mut Vec<Vertex>, texture_rect: IntRect, bounds: FloatRect) {
+    vertices.extend_from_slice(&Quad::with_texture_rect(bounds, texture_rect).as_triangles());
+}
+
+impl Element for TilingDropdown {
+    cast_element!();
+
+    fn global_bounds(&self) -> IntRect {
+        self.global_bounds
+    }
+
+    fn update_size(&mut self) {
+        let mut text = self.selected_text.clone();
+        let mut text_size = Vector2i::new(0, 0);
+        for option in &self.options {
+            text.set_text(option);
+            text_size.x = text_size.x.max(text.global_bounds().width);
+            text_size.y = text_size.y.max(text.global_bounds().height);
+        }
+
+        let selected = self.box_slices[0].selected;
+        self.row_height = text_size.y
+            + self.padding.top.unwrap_or_default()
+            + self.padding.bottom.unwrap_or_default();
+        self.global_bounds.height = self.row_height
+            + self.scaled(selected[0][0].height) as i32
+            + self.scaled(selected[2][0].height) as i32;
+        self.global_bounds.width = self.scaled(selected[0][0].width) as i32
+            + text_size.x
+            + self.padding.left.unwrap_or_default()
+            + self.padding.right.unwrap_or_default()
+            + self.arrow_width();
+
+        self.rerender = true;
+    }
+
+    fn update_position(&mut self, relative_rect: IntRect) {
+        self.global_bounds = self
+            .position
+            .center_with_size(relative_rect, self.global_bounds.size());
+        self.update_layout();
+    }
+
+    fn set_ui_position(&mut self, ui_position: UIPosition, relative_rect: IntRect) {
+        self.position = ui_position;
+        self.update_size();
+        self.update_position(relative_rect);
+    }
+
+    fn event_handler(&mut self, ui_settings: &UISettings, event: SFMLEvent) -> (Vec<Event>, bool) {
+        self.set_hover(ui_settings.cursor_position);
+        if self.option_list.is_open() {
+            self.open_event_handler(ui_settings, event)
+        } else {
+            self.closed_event_handler(ui_settings, event)
+        }
+    }
+
+    fn update(&mut self, _resource_manager: &ResourceManager) -> (Vec<Event>, bool) {
+        (Default::default(), self.rerender)
+    }
+
+    fn render(&mut self, render_texture: &mut RenderTexture) {
+        let mut rs = RenderStates::default();
+        rs.set_texture(self.texture_sprite.texture());
+        render_texture.draw_primitives(&self.box_vertices, PrimitiveType::TRIANGLES, &rs);
+        self.selected_text.render(render_texture);
+
+        self.rerender = false;
+    }
+
+    fn sync(&mut self, sync: Syncs) {
+        match sync {
+            Syncs::Numerical(idx) => {
+                if self.option_list.select(idx as usize) {
+                    self.update_layout();
+                }
+            }
+            Syncs::String(string) => {
+                let idx = self.options.iter().position(|option| option == &string);
+                if idx.is_some_and(|idx| self.option_list.select(idx)) {
+                    self.update_layout();
+                }
+            }
+            _ => {
+                error!("Sync: {:#?} is not valid for a dropdown", sync)
+            }
+        }
+    }
+
+    fn sync_id(&self) -> SyncId {
+        self.sync_id
+    }
+}
+
+impl ActionableElement for TilingDropdown {
+    cast_actionable_element!();
+
+    fn triggered_event(&self) -> Event {
+        Event::new(
+            self.event_id,
+            Events::StringEvent(self.options[self.option_list.current()].clone()),
+        )
+    }
+
+    fn bind_pressed(&mut self, mouse_pos: Vector2i) {
+        self.set_hover(mouse_pos);
+
+        if self.is_hover() {
+            self.current_mouse_state = UIMouseStates::Click;
+            self.update_vertices();
+            self.rerender = true;
+        }
+    }
+
+    /// Opens or closes the list of options, if the cursor is still on the dropdown
+    fn bind_released(&mut self, mouse_pos: Vector2i) {
+        self.current_mouse_state = UIMouseStates::Nothing;
+        self.set_hover(mouse_pos);
+        if !self.is_hover() {
+            return;
+        }
+
+        if self.option_list.is_open() {
+            self.close();
+        } else {
+            self.open();
+        }
+    }
+
+    /// Only the current option counts, not the list of options
+    fn set_hover(&mut self, mouse_pos: Vector2i) {
+        let previous_mouse_state = self.current_mouse_state;
+        self.current_mouse_state
+            .set_hover(self.global_bounds.contains(mouse_pos));
+        if self.current_mouse_state != previous_mouse_state {
+            self.update_vertices();
+            self.rerender = true;
+        }
+    }
+
+    fn is_hover(&self) -> bool {
+        self.current_mouse_state.is_hover()
+    }
+
+    fn event_id(&self) -> EventId {
+        self.event_id
+    }
+}
+
+impl Dropdown for TilingDropdown {
+    fn box_clone(&self) -> Box<dyn Dropdown> {
+        Box::new(self.clone())
+    }
+
+    fn is_open(&self) -> bool {
+        self.option_list.is_open()
+    }
+
+    fn render_popup(&mut self, render_texture: &mut RenderTexture) {
+        if !self.option_list.is_open() {
+            return;
+        }
+
+        let mut rs = RenderStates::default();
+        rs.set_texture(self.texture_sprite.texture());
+        render_texture.draw_primitives(&self.popup_vertices, PrimitiveType::TRIANGLES, &rs);
+        render_texture.draw(&self.highlight);
+        for text in &mut self.option_texts {
+            text.render(render_texture);
+        }
+    }
+}
@@ -0,0 +1,206 @@
+/// Which option is current, and which one is highlighted and which ones are shown while the
+/// list is open. Always has at least one option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionList {
+    len: usize,
+    visible_rows: usize,
+    current: usize,
+    /// Option under the cursor, or moved to with the keyboard, while the list is open
+    highlighted: usize,
+    /// Option shown in the first row of the list
+    first_visible: usize,
+    is_open: bool,
+}
+
+impl OptionList {
+    pub fn new(len: usize, max_visible_rows: usize) -> Self {
+        let len = len.max(1);
+
+        Self {
+            len,
+            visible_rows: max_visible_rows.clamp(1, len),
+            current: 0,
+            highlighted: 0,
+            first_visible: 0,
+            is_open: false,
+        }
+    }
+
+    pub fn visible_rows(&self) -> usize {
+        self.visible_rows
+    }
+
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn highlighted(&self) -> usize {
+        self.highlighted
+    }
+
+    pub fn first_visible(&self) -> usize {
+        self.first_visible
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// The option shown in `row` of the list
+    pub fn option_in_row(&self, row: usize) -> usize {
+        self.first_visible + row
+    }
+
+    /// The row of the list that shows `option_idx`, if it is scrolled into view
+    pub fn row_of(&self, option_idx: usize) -> Option<usize> {
+        option_idx
+            .checked_sub(self.first_visible)
+            .filter(|&row| row < self.visible_rows)
+    }
+
+    /// Opens the list on the current option
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.highlight(self.current);
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    /// Makes `option_idx` the current option. Returns true if the current option changed
+    pub fn select(&mut self, option_idx: usize) -> bool {
+        if option_idx == self.current || option_idx >= self.len {
+            return false;
+        }
+
+        self.current = option_idx;
+        true
+    }
+
+    /// Selects the option `steps` away from the current one, as far as the list goes
+    pub fn select_by(&mut self, steps: isize) -> bool {
+        self.select(self.step(self.current, steps))
+    }
+
+    /// Highlights `option_idx`, and scrolls the list just enough to show it
+    pub fn highlight(&mut self, option_idx: usize) {
+        self.highlighted = option_idx.min(self.len - 1);
+        if self.highlighted < self.first_visible {
+            self.first_visible = self.highlighted;
+        } else if self.highlighted >= self.first_visible + self.visible_rows {
+            self.first_visible = self.highlighted + 1 - self.visible_rows;
+        }
+    }
+
+    /// Highlights the option `steps` away from the highlighted one, as far as the list goes
+    pub fn highlight_by(&mut self, steps: isize) {
+        self.highlight(self.step(self.highlighted, steps))
+    }
+
+    /// Highlights the option in `row` without scrolling, for when the cursor is on it
+    pub fn highlight_row(&mut self, row: usize) {
+        if row < self.visible_rows {
+            self.highlighted = self.option_in_row(row);
+        }
+    }
+
+    /// Scrolls the list `rows` down, as far as it goes
+    pub fn scroll_by(&mut self, rows: isize) {
+        self.first_visible = self
+            .first_visible
+            .saturating_add_signed(rows)
+            .min(self.len - self.visible_rows);
+    }
+
+    fn step(&self, option_idx: usize, steps: isize) -> usize {
+        option_idx.saturating_add_signed(steps).min(self.len - 1)
+    }
+}
+
+/// How many options a notch of the wheel moves. Scrolling up moves to the options above
+pub fn wheel_steps(delta: f32) -> isize {
+    if delta.is_sign_positive() {
+        -1
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_wheel_selects_neighbouring_options() {
+        let mut option_list = OptionList::new(3, 2);
+
+        assert!(!option_list.select_by(wheel_steps(1.)));
+        assert!(option_list.select_by(wheel_steps(-1.)));
+        assert!(option_list.select_by(wheel_steps(-1.)));
+        assert!(!option_list.select_by(wheel_steps(-1.)));
+        assert_eq!(option_list.current(), 2);
+        assert!(option_list.select_by(wheel_steps(1.)));
+        assert_eq!(option_list.current(), 1);
+    }
+
+    #[test]
+    fn test_opens_on_the_current_option() {
+        let mut option_list = OptionList::new(10, 3);
+        option_list.select(7);
+
+        option_list.open();
+        assert!(option_list.is_open());
+        assert_eq!(option_list.highlighted(), 7);
+        assert_eq!(option_list.first_visible(), 5);
+        assert_eq!(option_list.row_of(7), Some(2));
+
+        option_list.close();
+        assert!(!option_list.is_open());
+        assert_eq!(option_list.current(), 7);
+    }
+
+    #[test]
+    fn test_keyboard_scrolls_to_the_highlighted_option() {
+        let mut option_list = OptionList::new(5, 2);
+        option_list.open();
+
+        option_list.highlight_by(-1);
+        assert_eq!(option_list.highlighted(), 0);
+        for _ in 0..10 {
+            option_list.highlight_by(1);
+        }
+        assert_eq!(option_list.highlighted(), 4);
+        assert_eq!(option_list.first_visible(), 3);
+        option_list.highlight_by(-2);
+        assert_eq!(option_list.first_visible(), 2);
+
+        assert!(option_list.select(option_list.highlighted()));
+        option_list.close();
+        assert_eq!(option_list.current(), 2);
+    }
+
+    #[test]
+    fn test_scrolling_stays_within_the_options() {
+        let mut option_list = OptionList::new(5, 2);
+
+        option_list.scroll_by(-1);
+        assert_eq!(option_list.first_visible(), 0);
+        option_list.scroll_by(10);
+        assert_eq!(option_list.first_visible(), 3);
+        assert_eq!(option_list.row_of(2), None);
+
+        option_list.highlight_row(1);
+        assert_eq!(option_list.highlighted(), 4);
+        option_list.highlight_row(2);
+        assert_eq!(option_list.highlighted(), 4);
+    }
+
+    #[test]
+    fn test_never_empty() {
+        let mut option_list = OptionList::new(0, 0);
+        assert_eq!(option_list.visible_rows(), 1);
+        option_list.highlight_by(1);
+        assert_eq!(option_list.highlighted(), 0);
+    }
+}
@@ -0,0 +1,21 @@
+use crate::elements::traits::ActionableElement;
+use sfml::graphics::RenderTexture;
+use std::{fmt::Debug, ops::Deref};
+
+pub trait Dropdown: ActionableElement + Debug {
+    fn box_clone(&self) -> Box<dyn Dropdown>;
+
+    /// Whether the list of options is shown. While it is, the dropdown is given every event
+    /// of its dom, see [`DomController`](crate::dom_controller::DomController)
+    fn is_open(&self) -> bool;
+
+    /// Renders the list of options over every other element of the dom. Does nothing while
+    /// the dropdown is closed
+    fn render_popup(&mut self, render_texture: &mut RenderTexture);
+}
+
+impl Clone for Box<dyn Dropdown> {
+    fn clone(&self) -> Self {
+        Dropdown::box_clone(self.deref())
+    }
+}
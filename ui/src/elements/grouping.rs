@@ -1,3 +1,4 @@
 pub mod div;
 pub mod grid;
+pub mod scroll_view;
 pub mod sets;
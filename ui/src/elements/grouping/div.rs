@@ -1,4 +1,8 @@
-use sfml::{graphics::IntRect, system::Vector2u, window::Event as SFMLEvent};
+use sfml::{
+    graphics::IntRect,
+    system::{Vector2i, Vector2u},
+    window::Event as SFMLEvent,
+};
 use utils::{
     arithmetic_util_functions::i32_from_u32, resource_manager::ResourceManager,
     sfml_util_functions::vector2u_from_vector2i,
@@ -6,12 +10,16 @@ use utils::{
 
 use crate::{events::Event, ui_settings::UISettings, utils::positioning::UIPosition};
 
+use self::stack_layout::StackLayout;
+
 use super::super::{
     traits::Element as ElementTrait,
     traits::{self, cast_element},
     Element,
 };
 
+pub mod stack_layout;
+
 #[derive(Clone, Default, Debug)]
 pub struct Div {
     global_bounds: IntRect,
@@ -20,6 +28,9 @@ pub struct Div {
     padding: Option<UIPosition>,
     size: Option<Vector2u>,
     use_relative_rect_size: bool,
+    /// Places the children one after the other instead of at their own position. Without
+    /// a size, the div is then as big as its children
+    layout: Option<StackLayout>,
 }
 
 impl Div {
@@ -40,9 +51,20 @@ impl Div {
             padding,
             size,
             use_relative_rect_size,
+            layout: None,
         }
     }
 
+    pub fn set_layout(&mut self, layout: Option<StackLayout>) {
+        self.layout = layout;
+        self.use_relative_rect_size =
+            layout.is_none() && self.padding.is_none() && self.size.is_none();
+    }
+
+    pub fn layout(&self) -> Option<StackLayout> {
+        self.layout
+    }
+
     pub fn mut_children(&mut self) -> impl Iterator<Item = &mut Element> {
         self.children.iter_mut()
     }
@@ -71,6 +93,33 @@ impl Div {
     pub fn position(&self) -> UIPosition {
         self.position
     }
+
+    fn padding_size(&self) -> Vector2i {
+        let padding = self.padding.unwrap_or_default();
+        Vector2i::new(
+            padding.left.unwrap_or_default() + padding.right.unwrap_or_default(),
+            padding.top.unwrap_or_default() + padding.bottom.unwrap_or_default(),
+        )
+    }
+
+    fn children_sizes(&self) -> Vec<Vector2i> {
+        self.children()
+            .map(|ele| ele.global_bounds().size())
+            .collect()
+    }
+
+    /// Sizes the div to its children laid out in `available` space, unless it was given a
+    /// size
+    fn fit_to_layout(&mut self, layout: StackLayout, available: Option<Vector2i>) {
+        if self.size.is_some() {
+            return;
+        }
+
+        let (_, content_size) = layout.arrange(&self.children_sizes(), available);
+        let size = content_size + self.padding_size();
+        self.global_bounds.width = size.x;
+        self.global_bounds.height = size.y;
+    }
 }
 
 impl traits::Element for Div {
@@ -84,7 +133,9 @@ impl traits::Element for Div {
         self.global_bounds.height = i32_from_u32(self.size.unwrap_or_default().y);
         self.mut_children().for_each(|ele| ele.update_size());
 
-        if let (Some(ele), Some(padding)) = (self.children.get_mut(0), self.padding) {
+        if let Some(layout) = self.layout {
+            self.fit_to_layout(layout, None);
+        } else if let (Some(ele), Some(padding)) = (self.children.get_mut(0), self.padding) {
             self.global_bounds.width = ele.global_bounds().width
                 + padding.left.unwrap_or_default()
                 + padding.right.unwrap_or_default();
@@ -98,6 +149,10 @@ impl traits::Element for Div {
         if self.use_relative_rect_size {
             self.set_size(vector2u_from_vector2i(relative_rect.size()));
         }
+        // How many lines the children wrap into depends on the space the div has
+        if let Some(layout) = self.layout.filter(|layout| layout.wrap) {
+            self.fit_to_layout(layout, Some(relative_rect.size() - self.padding_size()));
+        }
 
         self.global_bounds = self
             .position
@@ -108,8 +163,18 @@ impl traits::Element for Div {
         } else {
             self.global_bounds
         };
-        self.mut_children()
-            .for_each(|ele| ele.update_position(relative_rect));
+        if let Some(layout) = self.layout {
+            let (children_bounds, _) =
+                layout.arrange(&self.children_sizes(), Some(relative_rect.size()));
+            for (ele, mut bounds) in self.children.iter_mut().zip(children_bounds) {
+                bounds.left += relative_rect.left;
+                bounds.top += relative_rect.top;
+                ele.update_position(bounds);
+            }
+        } else {
+            self.mut_children()
+                .for_each(|ele| ele.update_position(relative_rect));
+        }
     }
 
     fn update(&mut self, resource_manager: &ResourceManager) -> (Vec<Event>, bool) {
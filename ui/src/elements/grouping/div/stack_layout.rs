@@ -0,0 +1,250 @@
+use std::str::FromStr;
+
+use sfml::{graphics::IntRect, system::Vector2i};
+use utils::simple_error::SimpleError;
+
+pub const LAYOUT_DIRECTIONS: [&str; 2] = ["row", "column"];
+pub const LAYOUT_ALIGNMENTS: [&str; 3] = ["start", "center", "end"];
+
+/// Which way children follow one another
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutDirection {
+    /// Left to right
+    #[default]
+    Row,
+    /// Top to bottom
+    Column,
+}
+
+impl FromStr for LayoutDirection {
+    type Err = SimpleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "row" => Ok(Self::Row),
+            "column" => Ok(Self::Column),
+            _ => Err(SimpleError::new(format!(
+                "{:?} is not a layout direction. Valid layout directions: {:?}",
+                s, LAYOUT_DIRECTIONS
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutAlignment {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+impl LayoutAlignment {
+    /// How far from the start something goes, with `free_space` left around it
+    fn offset(self, free_space: i32) -> i32 {
+        match self {
+            LayoutAlignment::Start => 0,
+            LayoutAlignment::Center => free_space / 2,
+            LayoutAlignment::End => free_space,
+        }
+    }
+}
+
+impl FromStr for LayoutAlignment {
+    type Err = SimpleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "start" => Ok(Self::Start),
+            "center" => Ok(Self::Center),
+            "end" => Ok(Self::End),
+            _ => Err(SimpleError::new(format!(
+                "{:?} is not a layout alignment. Valid layout alignments: {:?}",
+                s, LAYOUT_ALIGNMENTS
+            ))),
+        }
+    }
+}
+
+/// Places children one after the other, in a row or a column, instead of each at its own
+/// [`UIPosition`](crate::utils::positioning::UIPosition).
+///
+/// The main axis is the one of `direction`, the cross axis the other one.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StackLayout {
+    pub direction: LayoutDirection,
+    /// Space between children, and between lines when wrapping
+    pub gap: i32,
+    /// Where children go on the cross axis, within their line
+    pub align: LayoutAlignment,
+    /// Where lines go on the main axis, within the available space
+    pub justify: LayoutAlignment,
+    /// Starts a new line when the next child would go past the available space
+    pub wrap: bool,
+}
+
+impl StackLayout {
+    /// Bounds of children of `sizes` laid out from the origin, and the size of the space
+    /// they cover.
+    ///
+    /// Without `available` space, children are laid out in one line that is as long as
+    /// it needs to be.
+    pub fn arrange(
+        &self,
+        sizes: &[Vector2i],
+        available: Option<Vector2i>,
+    ) -> (Vec<IntRect>, Vector2i) {
+        let available = available.map(|available| self.main_cross(available));
+        let wrap_length = available
+            .filter(|_| self.wrap)
+            .map(|(available_main, _)| available_main);
+
+        // Children in each line, and the length and thickness of the line
+        let mut lines: Vec<(Vec<usize>, i32, i32)> = Vec::new();
+        for (idx, &size) in sizes.iter().enumerate() {
+            let (main, cross) = self.main_cross(size);
+            let needs_new_line = match lines.last() {
+                None => true,
+                Some((_, line_main, _)) => {
+                    wrap_length.is_some_and(|wrap_length| line_main + self.gap + main > wrap_length)
+                }
+            };
+            if needs_new_line {
+                lines.push((vec![idx], main, cross));
+                continue;
+            }
+            if let Some((children, line_main, line_cross)) = lines.last_mut() {
+                children.push(idx);
+                *line_main += self.gap + main;
+                *line_cross = (*line_cross).max(cross);
+            }
+        }
+
+        let content_main = lines
+            .iter()
+            .map(|&(_, line_main, _)| line_main)
+            .max()
+            .unwrap_or_default();
+        let content_cross = lines
+            .iter()
+            .map(|&(_, _, line_cross)| line_cross)
+            .sum::<i32>()
+            + self.gap * (lines.len() as i32 - 1).max(0);
+        let space_main = available.map_or(content_main, |(available_main, _)| available_main);
+
+        // A single line fills the available space, so children can be aligned within it
+        if let ([(_, _, line_cross)], Some((_, available_cross))) =
+            (lines.as_mut_slice(), available)
+        {
+            *line_cross = (*line_cross).max(available_cross);
+        }
+
+        let mut bounds = vec![IntRect::default(); sizes.len()];
+        let mut line_start = 0;
+        for (children, line_main, line_cross) in &lines {
+            let mut main_position = self.justify.offset(space_main - line_main);
+            for &idx in children {
+                let (main, cross) = self.main_cross(sizes[idx]);
+                let cross_position = line_start + self.align.offset(line_cross - cross);
+                bounds[idx] = IntRect::from_vecs(
+                    self.vector_from_main_cross(main_position, cross_position),
+                    sizes[idx],
+                );
+                main_position += main + self.gap;
+            }
+            line_start += line_cross + self.gap;
+        }
+
+        (
+            bounds,
+            self.vector_from_main_cross(content_main, content_cross),
+        )
+    }
+
+    fn main_cross(&self, vector: Vector2i) -> (i32, i32) {
+        match self.direction {
+            LayoutDirection::Row => (vector.x, vector.y),
+            LayoutDirection::Column => (vector.y, vector.x),
+        }
+    }
+
+    fn vector_from_main_cross(&self, main: i32, cross: i32) -> Vector2i {
+        match self.direction {
+            LayoutDirection::Row => Vector2i::new(main, cross),
+            LayoutDirection::Column => Vector2i::new(cross, main),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SIZES: [Vector2i; 3] = [
+        Vector2i { x: 10, y: 20 },
+        Vector2i { x: 30, y: 10 },
+        Vector2i { x: 20, y: 30 },
+    ];
+
+    #[test]
+    fn test_column_sizes_to_content() {
+        let layout = StackLayout {
+            direction: LayoutDirection::Column,
+            gap: 5,
+            align: LayoutAlignment::Center,
+            ..Default::default()
+        };
+
+        let (bounds, size) = layout.arrange(&SIZES, None);
+        assert_eq!(size, Vector2i::new(30, 70));
+        assert_eq!(
+            bounds,
+            vec![
+                IntRect::new(10, 0, 10, 20),
+                IntRect::new(0, 25, 30, 10),
+                IntRect::new(5, 40, 20, 30),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_row_aligns_within_available_space() {
+        let layout = StackLayout {
+            gap: 2,
+            align: LayoutAlignment::End,
+            justify: LayoutAlignment::Center,
+            ..Default::default()
+        };
+
+        let (bounds, size) = layout.arrange(&SIZES, Some(Vector2i::new(100, 40)));
+        assert_eq!(size, Vector2i::new(64, 30));
+        assert_eq!(
+            bounds,
+            vec![
+                IntRect::new(18, 20, 10, 20),
+                IntRect::new(30, 30, 30, 10),
+                IntRect::new(62, 10, 20, 30),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_row_wraps() {
+        let layout = StackLayout {
+            gap: 5,
+            wrap: true,
+            ..Default::default()
+        };
+
+        let (bounds, size) = layout.arrange(&SIZES, Some(Vector2i::new(50, 100)));
+        assert_eq!(size, Vector2i::new(45, 55));
+        assert_eq!(
+            bounds,
+            vec![
+                IntRect::new(0, 0, 10, 20),
+                IntRect::new(15, 0, 30, 10),
+                IntRect::new(0, 25, 20, 30),
+            ]
+        );
+    }
+}
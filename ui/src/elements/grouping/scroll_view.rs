@@ -0,0 +1,585 @@
+use std::ops::Range;
+
+use sfml::{
+    graphics::{
+        FloatRect, IntRect, PrimitiveType, RcSprite, RenderStates, RenderTarget, RenderTexture,
+        Vertex, View,
+    },
+    system::Vector2i,
+    window::{mouse::Wheel, Event as SFMLEvent},
+    SfBox,
+};
+use utils::{quads::Quad, resource_manager::ResourceManager};
+
+use crate::{
+    events::Event,
+    ui_settings::{
+        controls::{possible_binds::PossibleBinds, possible_inputs::PossibleInputs},
+        UISettings,
+    },
+    utils::{
+        consts::DUMMY_MOUSE_MOVED_EVENT, mouse_ui_states::UIMouseStates, positioning::UIPosition,
+    },
+};
+
+use self::scrolling::{max_scroll_offset, thumb_scroll_offset, thumb_span, visible_rows};
+
+use super::super::{
+    traits::{cast_element, Element as ElementTrait},
+    Element,
+};
+
+mod scrolling;
+
+const INCREMENT_SLICE_NAME: &str = "increment";
+const DECREMENT_SLICE_NAME: &str = "decrement";
+/// Top to bottom. The middle slice stretches
+const TRACK_SLICE_NAMES: [&str; 3] = ["scroll_bar_start", "scroll_bar_mid", "scroll_bar_end"];
+/// Top to bottom. The middle slice stretches
+const THUMB_SLICE_NAMES: [&str; 3] = ["scroll_icon_start", "scroll_icon_mid", "scroll_icon_end"];
+/// How far a notch of the wheel, a UI up or down bind or an arrow scrolls, before scaling
+const SCROLL_STEP: f32 = 12.;
+
+/// Where the slices of the scroll bar are in the texture, for one frame of the asset
+#[derive(Debug, Clone, Copy, Default)]
+struct ScrollBarSlices {
+    increment: IntRect,
+    decrement: IntRect,
+    track: [IntRect; 3],
+    thumb: [IntRect; 3],
+}
+
+/// Stacks its children from top to bottom, at their own height, and shows the part of them
+/// that fits in `size`. Everything else is clipped.
+///
+/// Scrolls with the wheel, with the UI up and down binds while nothing has focus, or with
+/// the scroll bar on its right. Only the children that can be seen are updated, rendered
+/// and given events.
+#[derive(Debug, Clone)]
+pub struct ScrollView {
+    global_bounds: IntRect,
+    position: UIPosition,
+    size: Vector2i,
+    scale: f32,
+    children: Vec<Element>,
+    /// Top and height of each child, from the top of the content
+    child_rows: Vec<(i32, i32)>,
+    content_height: i32,
+    /// How far the content is scrolled down
+    scroll_offset: i32,
+    /// Every slice is drawn from the texture of this sprite
+    texture_sprite: RcSprite,
+    /// One for each of the frame, hover frame and click frame
+    slices: [ScrollBarSlices; 3],
+    increment_state: UIMouseStates,
+    decrement_state: UIMouseStates,
+    thumb_state: UIMouseStates,
+    /// Where the thumb was grabbed from its top, while it is dragged
+    drag_offset: Option<i32>,
+    scroll_bar_vertices: Vec<Vertex>,
+    rerender: bool,
+}
+
+impl ScrollView {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        resource_manager: &ResourceManager,
+        asset_id: &str,
+        position: UIPosition,
+        size: Vector2i,
+        frame_id: usize,
+        hover_frame_id: usize,
+        click_frame_id: usize,
+        scale: f32,
+        children: Vec<Element>,
+    ) -> Self {
+        let asset = resource_manager.fetch_asset(asset_id);
+        let slices = [frame_id, hover_frame_id, click_frame_id].map(|frame_id| ScrollBarSlices {
+            increment: asset.get_shifted_slice_bound(INCREMENT_SLICE_NAME, frame_id),
+            decrement: asset.get_shifted_slice_bound(DECREMENT_SLICE_NAME, frame_id),
+            track: TRACK_SLICE_NAMES
+                .map(|slice_name| asset.get_shifted_slice_bound(slice_name, frame_id)),
+            thumb: THUMB_SLICE_NAMES
+                .map(|slice_name| asset.get_shifted_slice_bound(slice_name, frame_id)),
+        });
+
+        let mut scroll_view = Self {
+            global_bounds: IntRect::from_vecs(Default::default(), size),
+            position,
+            size,
+            scale,
+            children,
+            child_rows: Vec::new(),
+            content_height: 0,
+            scroll_offset: 0,
+            texture_sprite: asset.get_rc_sprite_with_frame_num(frame_id),
+            slices,
+            increment_state: Default::default(),
+            decrement_state: Default::default(),
+            thumb_state: Default::default(),
+            drag_offset: None,
+            scroll_bar_vertices: Vec::new(),
+            rerender: true,
+        };
+        scroll_view.update_size();
+
+        scroll_view
+    }
+
+    pub fn mut_children(&mut self) -> impl Iterator<Item = &mut Element> {
+        self.children.iter_mut()
+    }
+
+    pub fn children(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter()
+    }
+
+    pub fn scroll_offset(&self) -> i32 {
+        self.scroll_offset
+    }
+
+    /// Scrolls the content `scroll_offset` pixels down, as far as it goes. Returns true if
+    /// it moved
+    pub fn scroll_to(&mut self, scroll_offset: i32) -> bool {
+        let scroll_offset = scroll_offset.clamp(0, self.max_scroll_offset());
+        if scroll_offset == self.scroll_offset {
+            return false;
+        }
+
+        // Children that scrolled out of view are moved too, so none are left behind with
+        // bounds inside of it
+        let previously_visible = self.visible_children();
+        self.scroll_offset = scroll_offset;
+        self.update_children_position(previously_visible);
+        self.update_children_position(self.visible_children());
+        self.update_scroll_bar_vertices();
+        self.rerender = true;
+
+        true
+    }
+
+    pub fn scroll_by(&mut self, distance: i32) -> bool {
+        self.scroll_to(self.scroll_offset + distance)
+    }
+
+    fn scroll_step(&self) -> i32 {
+        (SCROLL_STEP * self.scale) as i32
+    }
+
+    fn scaled(&self, length: i32) -> i32 {
+        (length as f32 * self.scale) as i32
+    }
+
+    fn max_scroll_offset(&self) -> i32 {
+        max_scroll_offset(self.content_height, self.global_bounds.height)
+    }
+
+    /// The children inside the bounds of the view, at the current scroll offset
+    fn visible_children(&self) -> Range<usize> {
+        visible_rows(
+            &self.child_rows,
+            self.scroll_offset,
+            self.global_bounds.height,
+        )
+    }
+
+    /// Where the children are drawn, left of the scroll bar
+    fn content_bounds(&self) -> IntRect {
+        let scroll_bar_width = self.scaled(self.slices[0].increment.width);
+
+        IntRect::new(
+            self.global_bounds.left,
+            self.global_bounds.top,
+            self.global_bounds.width - scroll_bar_width,
+            self.global_bounds.height,
+        )
+    }
+
+    fn child_bounds(&self, child_idx: usize) -> IntRect {
+        let content_bounds = self.content_bounds();
+        let (row_top, row_height) = self.child_rows[child_idx];
+
+        IntRect::new(
+            content_bounds.left,
+            content_bounds.top + row_top - self.scroll_offset,
+            content_bounds.width,
+            row_height,
+        )
+    }
+
+    fn update_children_position(&mut self, child_idxs: Range<usize>) {
+        for child_idx in child_idxs {
+            let child_bounds = self.child_bounds(child_idx);
+            self.children[child_idx].update_position(child_bounds);
+        }
+    }
+
+    fn scroll_bar_bounds(&self) -> IntRect {
+        let content_bounds = self.content_bounds();
+
+        IntRect::new(
+            content_bounds.left + content_bounds.width,
+            self.global_bounds.top,
+            self.global_bounds.width - content_bounds.width,
+            self.global_bounds.height,
+        )
+    }
+
+    fn increment_bounds(&self) -> IntRect {
+        let mut bounds = self.scroll_bar_bounds();
+        bounds.height = self.scaled(self.slices[0].increment.height);
+
+        bounds
+    }
+
+    fn decrement_bounds(&self) -> IntRect {
+        let mut bounds = self.scroll_bar_bounds();
+        let height = self.scaled(self.slices[0].decrement.height);
+        bounds.top += bounds.height - height;
+        bounds.height = height;
+
+        bounds
+    }
+
+    /// Between the arrows
+    fn track_bounds(&self) -> IntRect {
+        let mut bounds = self.scroll_bar_bounds();
+        let increment_height = self.increment_bounds().height;
+        bounds.top += increment_height;
+        bounds.height -= increment_height + self.decrement_bounds().height;
+
+        bounds
+    }
+
+    /// None when everything fits, and there is nothing to scroll
+    fn thumb_bounds(&self) -> Option<IntRect> {
+        let track_bounds = self.track_bounds();
+        let thumb = self.slices[0].thumb;
+        let min_height = self.scaled(thumb[0].height + thumb[1].height + thumb[2].height);
+        let (top, height) = thumb_span(
+            track_bounds.height,
+            min_height,
+            self.global_bounds.height,
+            self.content_height,
+            self.scroll_offset,
+        )?;
+
+        Some(IntRect::new(
+            track_bounds.left,
+            track_bounds.top + top,
+            track_bounds.width,
+            height,
+        ))
+    }
+
+    /// Scrolls so the top of the thumb ends up at `thumb_top`
+    fn drag_thumb_to(&mut self, thumb_top: i32) -> bool {
+        let Some(thumb_bounds) = self.thumb_bounds() else {
+            return false;
+        };
+        let track_bounds = self.track_bounds();
+        let Some(scroll_offset) = thumb_scroll_offset(
+            thumb_top - track_bounds.top,
+            thumb_bounds.height,
+            track_bounds.height,
+            self.max_scroll_offset(),
+        ) else {
+            return false;
+        };
+
+        self.scroll_to(scroll_offset)
+    }
+
+    fn scroll_bar_pressed(&mut self, cursor_position: Vector2i) {
+        if self.increment_bounds().contains(cursor_position) {
+            self.increment_state = UIMouseStates::Click;
+            self.scroll_by(-self.scroll_step());
+        } else if self.decrement_bounds().contains(cursor_position) {
+            self.decrement_state = UIMouseStates::Click;
+            self.scroll_by(self.scroll_step());
+        } else if let Some(thumb_bounds) = self.thumb_bounds() {
+            if thumb_bounds.contains(cursor_position) {
+                self.thumb_state = UIMouseStates::Click;
+                self.drag_offset = Some(cursor_position.y - thumb_bounds.top);
+            } else if cursor_position.y < thumb_bounds.top {
+                // The track pages through the content
+                self.scroll_by(-self.global_bounds.height);
+            } else {
+                self.scroll_by(self.global_bounds.height);
+            }
+        }
+
+        self.update_scroll_bar_vertices();
+        self.rerender = true;
+    }
+
+    fn scroll_bar_released(&mut self, cursor_position: Vector2i) {
+        self.drag_offset = None;
+        self.increment_state = UIMouseStates::Nothing;
+        self.decrement_state = UIMouseStates::Nothing;
+        self.thumb_state = UIMouseStates::Nothing;
+        self.set_scroll_bar_hover(cursor_position);
+        self.update_scroll_bar_vertices();
+        self.rerender = true;
+    }
+
+    fn set_scroll_bar_hover(&mut self, cursor_position: Vector2i) {
+        let previous_states = (self.increment_state, self.decrement_state, self.thumb_state);
+        self.increment_state
+            .set_hover(self.increment_bounds().contains(cursor_position));
+        self.decrement_state
+            .set_hover(self.decrement_bounds().contains(cursor_position));
+        // The thumb stays grabbed even when the cursor leaves it
+        if self.drag_offset.is_none() {
+            let is_hover = self
+                .thumb_bounds()
+                .is_some_and(|thumb_bounds| thumb_bounds.contains(cursor_position));
+            self.thumb_state.set_hover(is_hover);
+        }
+
+        if previous_states != (self.increment_state, self.decrement_state, self.thumb_state) {
+            self.update_scroll_bar_vertices();
+            self.rerender = true;
+        }
+    }
+
+    fn frame_slices(&self, mouse_state: UIMouseStates) -> ScrollBarSlices {
+        match mouse_state {
+            UIMouseStates::Nothing => self.slices[0],
+            UIMouseStates::Hover => self.slices[1],
+            UIMouseStates::Click => self.slices[2],
+        }
+    }
+
+    fn update_scroll_bar_vertices(&mut self) {
+        let mut vertices = Vec::new();
+        append_slice(
+            &mut vertices,
+            self.frame_slices(self.increment_state).increment,
+            self.increment_bounds(),
+        );
+        append_slice(
+            &mut vertices,
+            self.frame_slices(self.decrement_state).decrement,
+            self.decrement_bounds(),
+        );
+        append_column(
+            &mut vertices,
+            self.slices[0].track,
+            self.track_bounds(),
+            self.scale,
+        );
+        if let Some(thumb_bounds) = self.thumb_bounds() {
+            append_column(
+                &mut vertices,
+                self.frame_slices(self.thumb_state).thumb,
+                thumb_bounds,
+                self.scale,
+            );
+        }
+
+        self.scroll_bar_vertices = vertices;
+    }
+
+    /// Children under the cursor are only given mouse buttons inside the view, since the
+    /// rest of them is clipped
+    fn children_event_handler(
+        &mut self,
+        ui_settings: &UISettings,
+        event: SFMLEvent,
+    ) -> (Vec<Event>, bool) {
+        let cursor_position = ui_settings.cursor_position;
+        let is_clipped_click = matches!(
+            event,
+            SFMLEvent::MouseButtonPressed { .. } | SFMLEvent::MouseButtonReleased { .. }
+        ) && !self.content_bounds().contains(cursor_position);
+
+        let mut rerender = false;
+        let mut events = Vec::new();
+        let visible_children = self.visible_children();
+        for ele in &mut self.children[visible_children] {
+            if is_clipped_click && ele.global_bounds().contains(cursor_position) {
+                continue;
+            }
+            let mut event = ele.event_handler(ui_settings, event);
+            rerender |= event.1;
+            events.append(&mut event.0);
+        }
+
+        (events, rerender)
+    }
+}
+
+impl ElementTrait for ScrollView {
+    cast_element!();
+
+    fn global_bounds(&self) -> IntRect {
+        self.global_bounds
+    }
+
+    fn update_size(&mut self) {
+        self.child_rows.clear();
+        let mut row_top = 0;
+        for ele in &mut self.children {
+            ele.update_size();
+            let row_height = ele.global_bounds().height;
+            self.child_rows.push((row_top, row_height));
+            row_top += row_height;
+        }
+        self.content_height = row_top;
+
+        self.global_bounds.width = self.size.x;
+        self.global_bounds.height = self.size.y;
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset());
+    }
+
+    fn update_position(&mut self, relative_rect: IntRect) {
+        self.global_bounds = self
+            .position
+            .center_with_size(relative_rect, self.global_bounds.size());
+
+        self.update_children_position(0..self.children.len());
+        self.update_scroll_bar_vertices();
+        self.rerender = true;
+    }
+
+    fn set_ui_position(&mut self, ui_position: UIPosition, relative_rect: IntRect) {
+        self.position = ui_position;
+        self.update_position(relative_rect);
+    }
+
+    fn event_handler(&mut self, ui_settings: &UISettings, event: SFMLEvent) -> (Vec<Event>, bool) {
+        let binds = &ui_settings.binds;
+        let cursor_position = ui_settings.cursor_position;
+        self.set_scroll_bar_hover(cursor_position);
+
+        let scrolled = match event {
+            SFMLEvent::MouseMoved { .. } if self.drag_offset.is_some() => {
+                let drag_offset = self.drag_offset.unwrap_or_default();
+                self.drag_thumb_to(cursor_position.y - drag_offset);
+                true
+            }
+            SFMLEvent::MouseButtonPressed { button, .. }
+                if self.scroll_bar_bounds().contains(cursor_position)
+                    && binds.is_bind_pressed_and_binded(
+                        PossibleInputs::from(button),
+                        PossibleBinds::Select,
+                    ) =>
+            {
+                self.scroll_bar_pressed(cursor_position);
+                true
+            }
+            SFMLEvent::MouseButtonReleased { button, .. }
+                if binds.is_bind_released_and_binded(
+                    PossibleInputs::from(button),
+                    PossibleBinds::Select,
+                ) && (self.drag_offset.is_some()
+                    || self.increment_state.is_click()
+                    || self.decrement_state.is_click()) =>
+            {
+                self.scroll_bar_released(cursor_position);
+                true
+            }
+            SFMLEvent::MouseWheelScrolled { wheel, delta, .. }
+                if wheel == Wheel::VerticalWheel
+                    && delta != 0.
+                    && self.global_bounds.contains(cursor_position) =>
+            {
+                let direction = if delta.is_sign_positive() { -1 } else { 1 };
+                self.scroll_by(direction * self.scroll_step())
+            }
+            // Focus navigation uses the same binds, the dom keeps them from the elements
+            // while something has focus
+            _ if !self.global_bounds.contains(cursor_position) => false,
+            _ if binds.is_bind_just_pressed(PossibleBinds::UIUp) => {
+                self.scroll_by(-self.scroll_step())
+            }
+            _ if binds.is_bind_just_pressed(PossibleBinds::UIDown) => {
+                self.scroll_by(self.scroll_step())
+            }
+            _ => false,
+        };
+        if scrolled {
+            // Lets the children that scrolled under the cursor show it
+            return self.children_event_handler(ui_settings, DUMMY_MOUSE_MOVED_EVENT);
+        }
+
+        let (events, rerender) = self.children_event_handler(ui_settings, event);
+        (events, rerender || self.rerender)
+    }
+
+    fn update(&mut self, resource_manager: &ResourceManager) -> (Vec<Event>, bool) {
+        let mut rerender = self.rerender;
+        let mut events = Vec::new();
+        let visible_children = self.visible_children();
+        for ele in &mut self.children[visible_children] {
+            let mut event = ele.update(resource_manager);
+            rerender |= event.1;
+            events.append(&mut event.0);
+        }
+
+        (events, rerender)
+    }
+
+    fn render(&mut self, render_texture: &mut RenderTexture) {
+        let previous_view = render_texture.view().to_owned();
+        if let Some(clip_view) = clip_view(&previous_view, self.content_bounds()) {
+            render_texture.set_view(&clip_view);
+            let visible_children = self.visible_children();
+            for ele in &mut self.children[visible_children] {
+                ele.render(render_texture);
+            }
+            render_texture.set_view(&previous_view);
+        }
+
+        let mut rs = RenderStates::default();
+        rs.set_texture(self.texture_sprite.texture());
+        render_texture.draw_primitives(&self.scroll_bar_vertices, PrimitiveType::TRIANGLES, &rs);
+
+        self.rerender = false;
+    }
+}
+
+/// A view that draws what `view` would, but only inside of `bounds`. None if `bounds` is
+/// not in `view` at all
+///
+/// Expects `view` to cover the whole render target
+fn clip_view(view: &View, bounds: IntRect) -> Option<SfBox<View>> {
+    let view_bounds = FloatRect::from_vecs(view.center() - view.size() / 2., view.size());
+    let bounds = bounds.as_other::<f32>().intersection(&view_bounds)?;
+
+    let mut clip_view = View::from_rect(bounds);
+    clip_view.set_viewport(FloatRect::new(
+        (bounds.left - view_bounds.left) / view_bounds.width,
+        (bounds.top - view_bounds.top) / view_bounds.height,
+        bounds.width / view_bounds.width,
+        bounds.height / view_bounds.height,
+    ));
+
+    Some(clip_view)
+}
+
+/// Appends the three slices of a column, top to bottom. Only the middle one stretches
+fn append_column(vertices: &mut Vec<Vertex>, slices: [IntRect; 3], bounds: IntRect, scale: f32) {
+    let top_height = (slices[0].height as f32 * scale) as i32;
+    let bottom_height = (slices[2].height as f32 * scale) as i32;
+    let middle_height = (bounds.height - top_height - bottom_height).max(0);
+    let mut top = bounds.top;
+    for (slice, height) in slices
+        .into_iter()
+        .zip([top_height, middle_height, bottom_height])
+    {
+        append_slice(
+            vertices,
+            slice,
+            IntRect::new(bounds.left, top, bounds.width, height),
+        );
+        top += height;
+    }
+}
+
+fn append_slice(vertices: &mut Vec<Vertex>, texture_rect: IntRect, bounds: IntRect) {
+    vertices.extend_from_slice(
+        &Quad::with_texture_rect(bounds.as_other(), texture_rect).as_triangles(),
+    );
+}
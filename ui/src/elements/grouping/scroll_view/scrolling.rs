@@ -0,0 +1,120 @@
+use std::ops::Range;
+
+/// How far content of `content_height` can be scrolled down, in a view of `view_height`
+pub fn max_scroll_offset(content_height: i32, view_height: i32) -> i32 {
+    (content_height - view_height).max(0)
+}
+
+/// The rows that are at least partly inside a view of `view_height`, scrolled
+/// `scroll_offset` down. Rows are given as their top and height, from the top of the
+/// content, and in order
+pub fn visible_rows(rows: &[(i32, i32)], scroll_offset: i32, view_height: i32) -> Range<usize> {
+    let bottom = scroll_offset + view_height;
+    let start =
+        rows.partition_point(|&(row_top, row_height)| row_top + row_height <= scroll_offset);
+    let end = rows.partition_point(|&(row_top, _)| row_top < bottom);
+
+    start..end.max(start)
+}
+
+/// Top and height of the thumb, from the top of a track of `track_height`. None when
+/// everything fits, and there is nothing to scroll.
+///
+/// The thumb takes as much of the track as the view shows of the content, but is never
+/// shorter than `min_thumb_height`
+pub fn thumb_span(
+    track_height: i32,
+    min_thumb_height: i32,
+    view_height: i32,
+    content_height: i32,
+    scroll_offset: i32,
+) -> Option<(i32, i32)> {
+    let max_scroll_offset = max_scroll_offset(content_height, view_height);
+    if max_scroll_offset == 0 {
+        return None;
+    }
+
+    let height = (track_height * view_height / content_height)
+        .max(min_thumb_height)
+        .min(track_height);
+    let top = (track_height - height) * scroll_offset / max_scroll_offset;
+
+    Some((top, height))
+}
+
+/// The scroll offset that puts the top of a thumb of `thumb_height` at `thumb_top`, from
+/// the top of a track of `track_height`. None when the thumb fills the track, and cannot be
+/// dragged
+pub fn thumb_scroll_offset(
+    thumb_top: i32,
+    thumb_height: i32,
+    track_height: i32,
+    max_scroll_offset: i32,
+) -> Option<i32> {
+    let free_height = track_height - thumb_height;
+    if free_height <= 0 {
+        return None;
+    }
+
+    Some((thumb_top * max_scroll_offset / free_height).clamp(0, max_scroll_offset))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Three rows of 40, 20 and 40
+    const ROWS: [(i32, i32); 3] = [(0, 40), (40, 20), (60, 40)];
+
+    #[test]
+    fn test_visible_rows() {
+        assert_eq!(max_scroll_offset(100, 50), 50);
+        assert_eq!(max_scroll_offset(30, 50), 0);
+
+        assert_eq!(visible_rows(&ROWS, 0, 50), 0..2);
+        assert_eq!(visible_rows(&ROWS, 40, 20), 1..2);
+        assert_eq!(visible_rows(&ROWS, 50, 50), 1..3);
+        assert_eq!(visible_rows(&ROWS, 100, 50), 3..3);
+        assert_eq!(visible_rows(&[], 0, 50), 0..0);
+    }
+
+    #[test]
+    fn test_thumb_follows_the_scroll_offset() {
+        assert_eq!(thumb_span(100, 10, 50, 50, 0), None);
+
+        assert_eq!(thumb_span(100, 10, 50, 200, 0), Some((0, 25)));
+        assert_eq!(thumb_span(100, 10, 50, 200, 75), Some((37, 25)));
+        assert_eq!(thumb_span(100, 10, 50, 200, 150), Some((75, 25)));
+    }
+
+    #[test]
+    fn test_thumb_is_never_too_short() {
+        assert_eq!(thumb_span(100, 10, 50, 10_000, 0), Some((0, 10)));
+        assert_eq!(thumb_span(100, 10, 50, 10_000, 9_950), Some((90, 10)));
+        assert_eq!(thumb_span(8, 10, 50, 100, 50), Some((0, 8)));
+    }
+
+    #[test]
+    fn test_dragging_the_thumb() {
+        let max_scroll_offset = max_scroll_offset(200, 50);
+        let (_, thumb_height) = thumb_span(100, 10, 50, 200, 0).expect("unit-test");
+
+        assert_eq!(
+            thumb_scroll_offset(0, thumb_height, 100, max_scroll_offset),
+            Some(0)
+        );
+        assert_eq!(
+            thumb_scroll_offset(50, thumb_height, 100, max_scroll_offset),
+            Some(100)
+        );
+        assert_eq!(
+            thumb_scroll_offset(-20, thumb_height, 100, max_scroll_offset),
+            Some(0)
+        );
+        assert_eq!(
+            thumb_scroll_offset(90, thumb_height, 100, max_scroll_offset),
+            Some(max_scroll_offset)
+        );
+        assert_eq!(thumb_scroll_offset(0, 100, 100, max_scroll_offset), None);
+    }
+}
@@ -17,7 +17,7 @@ use crate::{
     utils::positioning::UIPosition,
 };
 
-use super::div::Div;
+use super::div::{stack_layout::StackLayout, Div};
 
 #[derive(Clone, Default, Debug)]
 pub struct Sets {
@@ -34,6 +34,7 @@ impl Sets {
         mut sets: Vec<Vec<Element>>,
         padding: Option<UIPosition>,
         size: Option<Vector2u>,
+        layout: Option<StackLayout>,
         sync_id: SyncId,
     ) -> Self {
         if sets.is_empty() {
@@ -41,7 +42,8 @@ impl Sets {
             sets = vec![vec![Default::default()]];
         }
         let current_set = 0;
-        let div = Div::new(position, sets[current_set].clone(), padding, size);
+        let mut div = Div::new(position, sets[current_set].clone(), padding, size);
+        div.set_layout(layout);
 
         let mut s = Sets {
             sets,
@@ -81,6 +83,7 @@ impl Sets {
             self.div.padding(),
             self.div.size(),
         );
+        div.set_layout(self.div.layout());
         div.update_size();
         div.update_position(self.relative_rect);
         self.div = div;
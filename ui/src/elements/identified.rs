@@ -0,0 +1,34 @@
+use super::{tooltip::Tooltip, Element};
+
+/// An element that was given an `id`, a `class` or a `tooltip` in its layout. Every element method
+/// passes through to the wrapped element, and [`Element::traverse_dom_mut`] only sees the
+/// wrapped element, so the wrapper is invisible unless looked up by id or class.
+///
+/// See [`DomController::get_element_by_id`](crate::dom_controller::DomController::get_element_by_id)
+#[derive(Clone, Debug)]
+pub struct IdentifiedElement {
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    pub tooltip: Option<Tooltip>,
+    pub element: Element,
+}
+
+impl IdentifiedElement {
+    pub fn new(
+        id: Option<String>,
+        classes: Vec<String>,
+        tooltip: Option<Tooltip>,
+        element: Element,
+    ) -> Self {
+        Self {
+            id,
+            classes,
+            tooltip,
+            element,
+        }
+    }
+
+    pub fn has_class(&self, class: &str) -> bool {
+        self.classes.iter().any(|c| c == class)
+    }
+}
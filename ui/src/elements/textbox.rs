@@ -1,2 +1,4 @@
 pub mod fixed_size_one_line_textbox;
+pub mod text_area;
+pub mod text_editing;
 pub mod traits;
@@ -25,7 +25,10 @@ use crate::{
     utils::positioning::UIPosition,
 };
 
-use super::traits::{TextBox, TextBoxTriggeredEvent, BLINK_INTERVAL, CURSOR_CHAR, CURSOR_FONT};
+use super::{
+    text_editing::TextEditing,
+    traits::{TextBox, TextBoxTriggeredEvent, BLINK_INTERVAL, CURSOR_CHAR, CURSOR_FONT},
+};
 
 const START_HORIZONTAL_OFFSET: UIPosition = UIPosition {
     left: Some(5),
@@ -69,6 +72,7 @@ pub struct FixedSizeOneLineTextbox {
     bind_pressed_location: Option<Vector2i>,
     select_start_idx: Option<usize>,
     select_end_idx: Option<usize>,
+    text_editing: TextEditing,
 }
 
 impl FixedSizeOneLineTextbox {
@@ -117,6 +121,7 @@ impl FixedSizeOneLineTextbox {
             bind_pressed_location: None,
             select_start_idx: None,
             select_end_idx: None,
+            text_editing: Default::default(),
         };
         fstb.update_size();
 
@@ -187,8 +192,9 @@ impl FixedSizeOneLineTextbox {
         };
 
         let start = select_start_idx.max(self.starting_idx) - self.starting_idx;
-        let end = select_end_idx.min(self.starting_idx + self.rendered_text.text().len())
-            - self.starting_idx;
+        let end = select_end_idx
+            .min(self.starting_idx + self.rendered_text.text().len())
+            .saturating_sub(self.starting_idx);
         let (start, end) = (start.min(end), start.max(end));
         let mut width = 0.;
         let mut start_pos: Option<Vector2f> = None;
@@ -694,6 +700,53 @@ impl TextBox for FixedSizeOneLineTextbox {
         self.select_end_idx = Some(self.cursor_idx);
         self.calculate_select_box();
     }
+
+    fn string(&self) -> &str {
+        &self.string
+    }
+
+    fn cursor_idx(&self) -> usize {
+        self.cursor_idx
+    }
+
+    fn selection_anchor(&self) -> Option<usize> {
+        let (Some(select_start_idx), Some(select_end_idx)) =
+            (self.select_start_idx, self.select_end_idx)
+        else {
+            return None;
+        };
+        // The select indices are inclusive, and don't say which end the cursor is at
+        let start = select_start_idx.min(select_end_idx);
+        let end = select_start_idx.max(select_end_idx) + 1;
+        Some(if self.cursor_idx <= start { end } else { start })
+    }
+
+    fn select(&mut self, anchor: usize, cursor_idx: usize) {
+        self.move_cursor(cursor_idx);
+        self.select_rect = None;
+        let anchor = anchor.min(self.string.len());
+        if anchor == self.cursor_idx {
+            self.make_select_box_dissappear();
+            return;
+        }
+
+        self.select_start_idx = Some(anchor.min(self.cursor_idx));
+        self.select_end_idx = Some(anchor.max(self.cursor_idx) - 1);
+        self.calculate_select_box();
+    }
+
+    fn replace_string(&mut self, string: String, cursor_idx: usize) {
+        self.string = string;
+        self.make_select_box_dissappear();
+        // The shown part of the old text may not be in the new one
+        self.starting_idx = 0;
+        self.rendered_text.set_text("");
+        self.move_cursor(cursor_idx);
+    }
+
+    fn text_editing(&mut self) -> &mut TextEditing {
+        &mut self.text_editing
+    }
 }
 
 impl Default for FixedSizeOneLineTextbox {
@@ -722,6 +775,7 @@ impl Default for FixedSizeOneLineTextbox {
             select_end_idx: None,
             select_rect: None,
             instant_since_cursor_blink: Instant::now(),
+            text_editing: Default::default(),
         }
     }
 }
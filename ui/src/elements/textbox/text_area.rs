@@ -0,0 +1,721 @@
+use std::{ops::Range, time::Instant};
+
+use sfml::{
+    graphics::{
+        Color, FloatRect, IntRect, RcText, RectangleShape, RenderTarget, RenderTexture, Shape,
+        Transformable,
+    },
+    system::{Vector2, Vector2f, Vector2i},
+    window::{clipboard, mouse::Wheel, Event as SFMLEvent, Key},
+};
+use tracing::{error, warn};
+use utils::{
+    arithmetic_util_functions::i32_from_u32, resource_manager::ResourceManager,
+    sfml_util_functions::glyph_from_rc_text,
+};
+
+use crate::{
+    elements::traits::{cast_actionable_element, cast_element, ActionableElement, Element},
+    events::{Event, EventId, Events},
+    syncs::{ui_syncs_not_synced_str, SyncId, Syncs},
+    ui_settings::{
+        controls::{possible_binds::PossibleBinds, possible_inputs::PossibleInputs},
+        UISettings,
+    },
+    utils::positioning::UIPosition,
+};
+
+use self::word_wrap::{line_of, wrap_lines};
+
+use super::{
+    text_editing::TextEditing,
+    traits::{TextBox, TextBoxTriggeredEvent, BLINK_INTERVAL, CURSOR_CHAR, CURSOR_FONT},
+};
+
+mod word_wrap;
+
+/// Space between the text and the edges of the text area
+const TEXT_PADDING: Vector2i = Vector2i { x: 5, y: 3 };
+/// Lines that fit in a text area without a height
+const DEFAULT_VISIBLE_LINES: i32 = 4;
+const SCROLL_BAR_WIDTH: i32 = 3;
+/// The selection is filled with the text color at this alpha
+const SELECT_ALPHA: u8 = 96;
+
+/// A text box with as many lines as it needs. Text wraps at the width of the box, and
+/// scrolls with the wheel or by moving the cursor past the lines that fit.
+///
+/// Enter starts a new line instead of deselecting it, escape or clicking somewhere else
+/// deselects it.
+#[derive(Debug, Clone)]
+pub struct TextArea {
+    global_bounds: IntRect,
+    position: UIPosition,
+    /// Height from the layout. 0 fits [`DEFAULT_VISIBLE_LINES`]
+    height: i32,
+    background_rect: RectangleShape<'static>,
+    /// Measures and draws the text, one line at a time
+    line_text: RcText,
+    line_height: i32,
+    string: String,
+    /// Byte range of each wrapped line of `string`, without its newline
+    lines: Vec<Range<usize>>,
+    /// First line shown
+    scroll_line: usize,
+    /// Byte index in `string`
+    cursor_idx: usize,
+    /// Where the selection started. It ends at the cursor
+    select_anchor: Option<usize>,
+    event_id: EventId,
+    sync_id: SyncId,
+    hover: bool,
+    text_color: Color,
+    selected: bool,
+    rerender: bool,
+    instant_since_cursor_blink: Instant,
+    display_cursor: bool,
+    cursor: RcText,
+    bind_pressed_location: Option<Vector2i>,
+    text_editing: TextEditing,
+}
+
+impl TextArea {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        resource_manager: &ResourceManager,
+        position: UIPosition,
+        size: Vector2<u16>,
+        font_size: u32,
+        text_color: Color,
+        background_color: Color,
+        default_text: &str,
+        event_id: EventId,
+        sync_id: SyncId,
+    ) -> Self {
+        let mut background_rect = RectangleShape::new();
+        background_rect.set_fill_color(background_color);
+        let mut line_text = RcText::new("", resource_manager.fetch_current_font(), font_size);
+        line_text.set_fill_color(text_color);
+        let mut cursor = RcText::new(
+            &CURSOR_CHAR.to_string(),
+            resource_manager.fetch_font_with_id(CURSOR_FONT),
+            font_size,
+        );
+        cursor.set_origin(Vector2::new(
+            cursor.local_bounds().left,
+            cursor.local_bounds().top,
+        ));
+        cursor.set_fill_color(text_color);
+
+        let mut text_area = Self {
+            global_bounds: IntRect::new(0, 0, size.x.into(), 0),
+            position,
+            height: size.y.into(),
+            background_rect,
+            line_text,
+            line_height: 0,
+            string: default_text.to_string(),
+            lines: Vec::new(),
+            scroll_line: 0,
+            cursor_idx: default_text.len(),
+            select_anchor: None,
+            event_id,
+            sync_id,
+            hover: false,
+            text_color,
+            selected: false,
+            rerender: true,
+            instant_since_cursor_blink: Instant::now(),
+            display_cursor: false,
+            cursor,
+            bind_pressed_location: None,
+            text_editing: Default::default(),
+        };
+        text_area.update_size();
+
+        text_area
+    }
+
+    fn visible_lines(&self) -> usize {
+        let height = self.global_bounds.height - 2 * TEXT_PADDING.y;
+        (height / self.line_height.max(1)).max(1) as usize
+    }
+
+    fn visible_line_range(&self) -> Range<usize> {
+        self.scroll_line
+            ..self
+                .lines
+                .len()
+                .min(self.scroll_line + self.visible_lines())
+    }
+
+    fn cursor_line(&self) -> usize {
+        line_of(&self.lines, self.cursor_idx)
+    }
+
+    fn selection(&self) -> Option<Range<usize>> {
+        self.select_anchor
+            .filter(|&anchor| anchor != self.cursor_idx)
+            .map(|anchor| anchor.min(self.cursor_idx)..anchor.max(self.cursor_idx))
+    }
+
+    /// Wraps the text again, after it or the width changed
+    fn rewrap(&mut self) {
+        let max_width = self.global_bounds.width - 2 * TEXT_PADDING.x - SCROLL_BAR_WIDTH;
+        let line_text = &self.line_text;
+        self.lines = wrap_lines(&self.string, max_width as f32, |c| {
+            glyph_from_rc_text(line_text, c as u32).map_or(0., |glyph| glyph.advance())
+        });
+        self.scroll_to_cursor();
+    }
+
+    fn scroll_to_cursor(&mut self) {
+        let cursor_line = self.cursor_line();
+        let visible_lines = self.visible_lines();
+        if cursor_line < self.scroll_line {
+            self.scroll_line = cursor_line;
+        } else if cursor_line >= self.scroll_line + visible_lines {
+            self.scroll_line = cursor_line + 1 - visible_lines;
+        }
+        self.clamp_scroll();
+    }
+
+    fn scroll_by(&mut self, lines: i32) {
+        self.scroll_line = (self.scroll_line as i32 + lines).max(0) as usize;
+        self.clamp_scroll();
+        self.place_cursor();
+        self.rerender = true;
+    }
+
+    fn clamp_scroll(&mut self) {
+        self.scroll_line = self
+            .scroll_line
+            .min(self.lines.len().saturating_sub(self.visible_lines()));
+    }
+
+    /// Top left of `line`, which may be scrolled out of the text area
+    fn line_position(&self, line: usize) -> Vector2f {
+        let row = line as i32 - self.scroll_line as i32;
+        Vector2f::new(
+            (self.global_bounds.left + TEXT_PADDING.x) as f32,
+            (self.global_bounds.top + TEXT_PADDING.y + row * self.line_height) as f32,
+        )
+    }
+
+    /// Byte index and left of every place the cursor can go on `line`. Leaves `line_text`
+    /// ready to draw the line
+    fn cursor_stops(&mut self, line: usize) -> Vec<(usize, f32)> {
+        let Some(range) = self.lines.get(line).cloned() else {
+            return Vec::new();
+        };
+        let position = self.line_position(line);
+        let line_string = &self.string[range.clone()];
+        self.line_text.set_string(line_string);
+        self.line_text.set_position(position);
+
+        let mut stops: Vec<_> = line_string
+            .char_indices()
+            .enumerate()
+            .map(|(char_idx, (byte_idx, _))| {
+                let x = self.line_text.find_character_pos(char_idx).x;
+                (range.start + byte_idx, x)
+            })
+            .collect();
+        let end_x = self.line_text.find_character_pos(stops.len()).x;
+        stops.push((range.end, end_x));
+
+        stops
+    }
+
+    /// The index on `line` that is closest to `x`
+    fn idx_on_line_at_x(&mut self, line: usize, x: f32) -> usize {
+        let mut stops = self.cursor_stops(line);
+        // The end of a wrapped line is the start of the next one
+        if self
+            .lines
+            .get(line + 1)
+            .is_some_and(|next_line| Some(next_line.start) == stops.last().map(|stop| stop.0))
+        {
+            stops.pop();
+        }
+
+        stops
+            .into_iter()
+            .min_by(|a, b| (a.1 - x).abs().total_cmp(&(b.1 - x).abs()))
+            .map_or(0, |(idx, _)| idx)
+    }
+
+    fn idx_at_point(&mut self, point: Vector2i) -> usize {
+        let row =
+            (point.y - self.global_bounds.top - TEXT_PADDING.y).div_euclid(self.line_height.max(1));
+        let last_line = self.lines.len().saturating_sub(1) as i32;
+        let line = (self.scroll_line as i32 + row).clamp(0, last_line);
+        self.idx_on_line_at_x(line as usize, point.x as f32)
+    }
+
+    fn cursor_x(&mut self) -> f32 {
+        let cursor_idx = self.cursor_idx;
+        self.cursor_stops(self.cursor_line())
+            .into_iter()
+            .find(|&(idx, _)| idx == cursor_idx)
+            .map_or(self.line_position(0).x, |(_, x)| x)
+    }
+
+    /// The closest index to the cursor on the line `lines` above or below it. The cursor
+    /// stays where it is without such a line
+    fn vertical_cursor_idx(&mut self, lines: i32) -> usize {
+        let line = self.cursor_line() as i32 + lines;
+        if line < 0 || line >= self.lines.len() as i32 {
+            return self.cursor_idx;
+        }
+        let x = self.cursor_x();
+        self.idx_on_line_at_x(line as usize, x)
+    }
+
+    fn place_cursor(&mut self) {
+        let x = self.cursor_x();
+        let top = self.line_position(self.cursor_line()).y;
+        let cursor_height = self.cursor.global_bounds().height;
+        self.cursor.set_position(Vector2f::new(
+            x,
+            top + self.line_height as f32 - cursor_height,
+        ));
+    }
+
+    fn cursor_update(&mut self) {
+        let change_cursor = if self.selected {
+            self.instant_since_cursor_blink.elapsed() > BLINK_INTERVAL
+        } else {
+            self.display_cursor
+        };
+
+        if change_cursor {
+            self.display_cursor = !self.display_cursor;
+            self.place_cursor();
+            self.instant_since_cursor_blink = Instant::now();
+            self.rerender = true;
+        }
+    }
+
+    fn make_cursor_disappear(&mut self) {
+        let selected = self.selected;
+        self.selected = false;
+        self.cursor_update();
+        self.selected = selected;
+    }
+
+    fn make_cursor_appear(&mut self) {
+        self.instant_since_cursor_blink = Instant::now() - 2 * BLINK_INTERVAL;
+        self.display_cursor = false;
+        self.cursor_update();
+    }
+
+    fn previous_char_idx(&self, idx: usize) -> usize {
+        self.string[..idx]
+            .chars()
+            .next_back()
+            .map_or(0, |c| idx - c.len_utf8())
+    }
+
+    fn next_char_idx(&self, idx: usize) -> usize {
+        self.string[idx..]
+            .chars()
+            .next()
+            .map_or(idx, |c| idx + c.len_utf8())
+    }
+
+    /// Returns whether there was a selection to delete
+    fn delete_selection(&mut self) -> bool {
+        let Some(selection) = self.selection() else {
+            return false;
+        };
+        self.string.replace_range(selection.clone(), "");
+        self.cursor_idx = selection.start;
+        self.select_anchor = None;
+        true
+    }
+
+    /// Replaces the selection, if there is one, with `text`
+    fn insert(&mut self, text: &str) {
+        self.delete_selection();
+        self.string.insert_str(self.cursor_idx, text);
+        self.cursor_idx += text.len();
+        self.text_changed();
+    }
+
+    fn text_changed(&mut self) {
+        self.rewrap();
+        self.make_cursor_appear();
+        self.rerender = true;
+    }
+
+    fn render_selection(
+        &self,
+        render_texture: &mut RenderTexture,
+        line: usize,
+        stops: &[(usize, f32)],
+    ) {
+        let Some(selection) = self.selection() else {
+            return;
+        };
+        let left = stops.iter().find(|stop| stop.0 >= selection.start);
+        let right = stops.iter().rev().find(|stop| stop.0 <= selection.end);
+        let (Some(&(_, left)), Some(&(_, right))) = (left, right) else {
+            return;
+        };
+        if left >= right {
+            return;
+        }
+
+        let top = self.line_position(line).y;
+        let mut select_rect = RectangleShape::from_rect(FloatRect::new(
+            left,
+            top,
+            right - left,
+            self.line_height as f32,
+        ));
+        let mut color = self.text_color;
+        color.a = SELECT_ALPHA;
+        select_rect.set_fill_color(color);
+        render_texture.draw(&select_rect);
+    }
+
+    fn render_scroll_bar(&self, render_texture: &mut RenderTexture) {
+        let visible_lines = self.visible_lines();
+        if self.lines.len() <= visible_lines {
+            return;
+        }
+
+        let track_height = (self.global_bounds.height - 2 * TEXT_PADDING.y) as f32;
+        let line_count = self.lines.len() as f32;
+        let mut thumb = RectangleShape::from_rect(FloatRect::new(
+            (self.global_bounds.left + self.global_bounds.width - SCROLL_BAR_WIDTH - 1) as f32,
+            (self.global_bounds.top + TEXT_PADDING.y) as f32
+                + track_height * self.scroll_line as f32 / line_count,
+            SCROLL_BAR_WIDTH as f32,
+            track_height * visible_lines as f32 / line_count,
+        ));
+        thumb.set_fill_color(self.text_color);
+        render_texture.draw(&thumb);
+    }
+}
+
+impl Element for TextArea {
+    fn global_bounds(&self) -> IntRect {
+        self.global_bounds
+    }
+
+    fn event_handler(&mut self, ui_settings: &UISettings, event: SFMLEvent) -> (Vec<Event>, bool) {
+        let binds = &ui_settings.binds;
+        let is_bind_pressed = |code: Key, bind: PossibleBinds| {
+            binds.is_bind_pressed_and_binded(PossibleInputs::from(code), bind)
+        };
+        match event {
+            SFMLEvent::MouseWheelScrolled { wheel, delta, .. }
+                if wheel == Wheel::VerticalWheel
+                    && delta != 0.
+                    && self.global_bounds.contains(ui_settings.cursor_position) =>
+            {
+                self.scroll_by(if delta.is_sign_positive() { -1 } else { 1 });
+                (vec![], true)
+            }
+            SFMLEvent::KeyPressed { code, shift, .. }
+                if self.selected && is_bind_pressed(code, PossibleBinds::UIUp) =>
+            {
+                let idx = self.vertical_cursor_idx(-1);
+                self.move_cursor_to(idx, shift);
+                (vec![], true)
+            }
+            SFMLEvent::KeyPressed { code, shift, .. }
+                if self.selected && is_bind_pressed(code, PossibleBinds::UIDown) =>
+            {
+                let idx = self.vertical_cursor_idx(1);
+                self.move_cursor_to(idx, shift);
+                (vec![], true)
+            }
+            // Enter starts a new line once it is entered as text
+            SFMLEvent::KeyPressed { code, .. }
+                if self.selected && is_bind_pressed(code, PossibleBinds::Select) =>
+            {
+                (vec![], true)
+            }
+            _ => TextBox::event_handler(self, ui_settings, event),
+        }
+    }
+
+    fn update_size(&mut self) {
+        // Measured on every printable character, so lines are as tall with or without text
+        self.line_text.set_string(&(' '..='~').collect::<String>());
+        let text_bounds = self.line_text.local_bounds();
+        self.line_text.set_origin(Vector2::new(0., text_bounds.top));
+        let text_height = (text_bounds.height as i32).max(1);
+        self.line_height = text_height + i32_from_u32(text_height.ilog2());
+
+        self.global_bounds.height = if self.height > 0 {
+            self.height
+        } else {
+            self.line_height * DEFAULT_VISIBLE_LINES + 2 * TEXT_PADDING.y
+        };
+        self.background_rect
+            .set_size(self.global_bounds.size().as_other());
+        self.rewrap();
+        self.rerender = true;
+    }
+
+    fn update_position(&mut self, relative_rect: IntRect) {
+        self.global_bounds = self
+            .position
+            .center_with_size(relative_rect, self.global_bounds.size());
+        self.background_rect
+            .set_position(self.global_bounds.position().as_other());
+        self.place_cursor();
+        self.rerender = true;
+    }
+
+    fn set_ui_position(&mut self, ui_position: UIPosition, relative_rect: IntRect) {
+        self.position = ui_position;
+        self.update_position(relative_rect);
+    }
+
+    fn update(&mut self, _resource_manager: &ResourceManager) -> (Vec<Event>, bool) {
+        self.cursor_update();
+        (Default::default(), self.rerender)
+    }
+
+    fn render(&mut self, render_texture: &mut RenderTexture) {
+        render_texture.draw(&self.background_rect);
+        for line in self.visible_line_range() {
+            let stops = self.cursor_stops(line);
+            self.render_selection(render_texture, line, &stops);
+            render_texture.draw(&self.line_text);
+        }
+        if self.display_cursor && self.visible_line_range().contains(&self.cursor_line()) {
+            render_texture.draw(&self.cursor);
+        }
+        self.render_scroll_bar(render_texture);
+        self.rerender = false;
+    }
+
+    fn sync_id(&self) -> SyncId {
+        self.sync_id
+    }
+
+    fn sync(&mut self, sync: Syncs) {
+        let Syncs::String(string) = sync else {
+            warn!(
+                ui_syncs_not_synced_str!(),
+                Syncs::String(Default::default()),
+                sync
+            );
+            return;
+        };
+
+        self.string = string;
+        self.select_anchor = None;
+        self.cursor_idx = self.string.len();
+        self.rewrap();
+        self.deselect();
+        self.rerender = true;
+    }
+
+    cast_element!();
+}
+
+impl ActionableElement for TextArea {
+    cast_actionable_element!();
+
+    fn triggered_event(&self) -> Event {
+        Event::new(
+            self.event_id,
+            Events::TextBoxEvent(TextBoxTriggeredEvent {
+                string: self.string.clone(),
+                selected: self.selected,
+            }),
+        )
+    }
+
+    fn bind_pressed(&mut self, mouse_pos: Vector2i) {
+        self.set_hover(mouse_pos);
+        self.make_select_box_dissappear();
+        self.selected = self.hover;
+
+        if !self.hover {
+            return;
+        }
+
+        self.bind_pressed_location = Some(mouse_pos);
+        let idx = self.idx_at_point(mouse_pos);
+        self.move_cursor(idx);
+    }
+
+    fn bind_released(&mut self, mouse_pos: Vector2i) {
+        self.set_hover(mouse_pos);
+
+        if self.is_dragging() {
+            self.drag_mouse(mouse_pos);
+            self.bind_pressed_location = None;
+            self.rerender = true;
+        }
+    }
+
+    fn set_hover(&mut self, mouse_pos: Vector2i) {
+        self.hover = self.global_bounds.contains(mouse_pos);
+    }
+
+    fn is_hover(&self) -> bool {
+        self.hover
+    }
+
+    fn event_id(&self) -> EventId {
+        self.event_id
+    }
+}
+
+impl TextBox for TextArea {
+    fn move_cursor(&mut self, new_cursor_idx: usize) {
+        let mut cursor_idx = new_cursor_idx.min(self.string.len());
+        while !self.string.is_char_boundary(cursor_idx) {
+            cursor_idx -= 1;
+        }
+        self.cursor_idx = cursor_idx;
+        self.scroll_to_cursor();
+        self.make_cursor_appear();
+        self.rerender = true;
+    }
+
+    fn move_cursor_left(&mut self) {
+        self.move_cursor(self.previous_char_idx(self.cursor_idx))
+    }
+
+    fn move_cursor_right(&mut self) {
+        self.move_cursor(self.next_char_idx(self.cursor_idx))
+    }
+
+    fn box_clone(&self) -> Box<dyn TextBox> {
+        Box::new(self.clone())
+    }
+
+    fn text_entered(&mut self, event: SFMLEvent) {
+        let SFMLEvent::TextEntered { unicode } = event else {
+            error!("Event is not a TextEntered event! {:#?}", event);
+            return;
+        };
+
+        match unicode {
+            // Back space
+            '\u{8}' => {
+                if !self.delete_selection() {
+                    let start = self.previous_char_idx(self.cursor_idx);
+                    self.string.replace_range(start..self.cursor_idx, "");
+                    self.cursor_idx = start;
+                }
+                self.text_changed();
+            }
+            // Delete
+            '\u{7f}' => {
+                if !self.delete_selection() {
+                    let end = self.next_char_idx(self.cursor_idx);
+                    self.string.replace_range(self.cursor_idx..end, "");
+                }
+                self.text_changed();
+            }
+            '\r' | '\n' => self.insert("\n"),
+            // Ctrl shortcuts are also entered as control characters
+            _ if unicode.is_control() => {}
+            _ => self.insert(&unicode.to_string()),
+        }
+    }
+
+    fn select_everything(&mut self) {
+        if !self.string.is_empty() && self.is_selected() {
+            self.select_anchor = Some(0);
+            self.move_cursor(self.string.len());
+        }
+    }
+
+    fn make_select_box_dissappear(&mut self) {
+        if self.select_anchor.take().is_some() {
+            self.rerender = true;
+        }
+    }
+
+    fn deselect(&mut self) {
+        self.make_cursor_disappear();
+        self.selected = false;
+        self.make_select_box_dissappear();
+    }
+
+    fn is_selected(&self) -> bool {
+        self.selected
+    }
+
+    fn is_dragging(&self) -> bool {
+        self.bind_pressed_location.is_some()
+    }
+
+    fn cut(&mut self) {
+        if self.selection().is_none() {
+            return;
+        }
+
+        self.copy();
+        self.delete_selection();
+        self.text_changed();
+    }
+
+    fn copy(&self) {
+        if let Some(selection) = self.selection() {
+            clipboard::set_string(&self.string[selection]);
+        }
+    }
+
+    fn paste(&mut self) {
+        let pasted: String = clipboard::get_string()
+            .replace("\r\n", "\n")
+            .chars()
+            .filter(|&c| c == '\n' || !c.is_control())
+            .collect();
+        self.insert(&pasted);
+    }
+
+    fn drag_mouse(&mut self, mouse_pos: Vector2i) {
+        if !self.is_dragging() {
+            return;
+        }
+
+        if self.select_anchor.is_none() {
+            self.select_anchor = Some(self.cursor_idx);
+        }
+        let idx = self.idx_at_point(mouse_pos);
+        self.move_cursor(idx);
+    }
+
+    fn string(&self) -> &str {
+        &self.string
+    }
+
+    fn cursor_idx(&self) -> usize {
+        self.cursor_idx
+    }
+
+    fn selection_anchor(&self) -> Option<usize> {
+        self.selection().and(self.select_anchor)
+    }
+
+    fn select(&mut self, anchor: usize, cursor_idx: usize) {
+        self.move_cursor(cursor_idx);
+        self.select_anchor = Some(anchor.min(self.string.len()));
+    }
+
+    fn replace_string(&mut self, string: String, cursor_idx: usize) {
+        self.string = string;
+        self.select_anchor = None;
+        self.cursor_idx = self.cursor_idx.min(self.string.len());
+        self.rewrap();
+        self.move_cursor(cursor_idx);
+    }
+
+    fn text_editing(&mut self) -> &mut TextEditing {
+        &mut self.text_editing
+    }
+}
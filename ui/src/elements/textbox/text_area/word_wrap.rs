@@ -0,0 +1,77 @@
+use std::ops::Range;
+
+/// Splits `string` into lines no wider than `max_width`, as byte ranges without their
+/// newline. There is always at least one line.
+///
+/// Lines break after the last space that fits, in the middle of words that are too long for
+/// a line of their own, and after every newline. Spaces at the end of a line may go past
+/// `max_width`.
+pub fn wrap_lines(
+    string: &str,
+    max_width: f32,
+    char_width: impl Fn(char) -> f32,
+) -> Vec<Range<usize>> {
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    let mut width = 0.;
+    // Where the line can break, right after a space
+    let mut last_break = None;
+
+    for (idx, c) in string.char_indices() {
+        if c == '\n' {
+            lines.push(line_start..idx);
+            line_start = idx + 1;
+            width = 0.;
+            last_break = None;
+            continue;
+        }
+
+        let c_width = char_width(c);
+        if width + c_width > max_width && idx > line_start && c != ' ' {
+            let break_idx = last_break.unwrap_or(idx);
+            lines.push(line_start..break_idx);
+            line_start = break_idx;
+            width = string[line_start..idx].chars().map(&char_width).sum();
+            last_break = None;
+        }
+        width += c_width;
+        if c == ' ' {
+            last_break = Some(idx + 1);
+        }
+    }
+    lines.push(line_start..string.len());
+
+    lines
+}
+
+/// The line that byte `idx` is on. An index where a line wraps is on the next line
+pub fn line_of(lines: &[Range<usize>], idx: usize) -> usize {
+    lines
+        .partition_point(|line| line.start <= idx)
+        .saturating_sub(1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_wraps_at_spaces_and_newlines() {
+        let lines = wrap_lines("one two three\nfour", 8., |_| 1.);
+        assert_eq!(lines, vec![0..8, 8..13, 14..18]);
+        assert_eq!(line_of(&lines, 7), 0);
+        assert_eq!(line_of(&lines, 8), 1);
+        assert_eq!(line_of(&lines, 13), 1);
+        assert_eq!(line_of(&lines, 14), 2);
+    }
+
+    #[test]
+    fn test_splits_long_words() {
+        assert_eq!(
+            wrap_lines("abcdefghij", 4., |_| 1.),
+            vec![0..4, 4..8, 8..10]
+        );
+        assert_eq!(wrap_lines("", 4., |_| 1.), vec![0..0]);
+        assert_eq!(wrap_lines("ab\n", 4., |_| 1.), vec![0..2, 3..3]);
+    }
+}
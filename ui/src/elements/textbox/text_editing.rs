@@ -0,0 +1,177 @@
+use std::{ops::Range, time::Instant};
+
+use sfml::system::Vector2i;
+
+use crate::utils::animation_constants::DOUBLE_CLICK_INTERVAL;
+
+/// Most edits a text box can undo
+const UNDO_LIMIT: usize = 100;
+/// How far apart the clicks of a double click can be
+const DOUBLE_CLICK_DISTANCE: i32 = 4;
+
+/// The text of a text box, and where its cursor was
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TextSnapshot {
+    pub string: String,
+    pub cursor_idx: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditKind {
+    Typing,
+    Deleting,
+    /// Cut and paste. Never merged with the edit before
+    Other,
+}
+
+/// What a text box remembers for [`TextBox::event_handler`]: its undo and redo stacks, and
+/// its last click to notice double clicks.
+///
+/// Characters typed or deleted one after the other are undone together, until the cursor
+/// is moved.
+///
+/// [`TextBox::event_handler`]: super::traits::TextBox::event_handler
+#[derive(Debug, Clone, Default)]
+pub struct TextEditing {
+    undo_stack: Vec<TextSnapshot>,
+    redo_stack: Vec<TextSnapshot>,
+    /// Kind of the last edit, while the next one of the same kind merges with it
+    last_edit_kind: Option<EditKind>,
+    last_click: Option<(Instant, Vector2i)>,
+    /// Word selected by a double click, kept selected until the click is released
+    pub(super) double_clicked_word: Option<Range<usize>>,
+}
+
+impl TextEditing {
+    /// Remembers the text from `before` an edit of `kind`
+    pub fn record(&mut self, before: TextSnapshot, kind: EditKind) {
+        let merges = kind != EditKind::Other && self.last_edit_kind == Some(kind);
+        if !merges {
+            self.undo_stack.push(before);
+            if self.undo_stack.len() > UNDO_LIMIT {
+                self.undo_stack.remove(0);
+            }
+        }
+        self.redo_stack.clear();
+        self.last_edit_kind = Some(kind);
+    }
+
+    /// The text from before the last edit, if there is one. `current` can be redone
+    pub fn undo(&mut self, current: TextSnapshot) -> Option<TextSnapshot> {
+        let snapshot = self.undo_stack.pop()?;
+        self.redo_stack.push(current);
+        self.last_edit_kind = None;
+        Some(snapshot)
+    }
+
+    /// The text from before the last undo, if there is one. `current` can be undone
+    pub fn redo(&mut self, current: TextSnapshot) -> Option<TextSnapshot> {
+        let snapshot = self.redo_stack.pop()?;
+        self.undo_stack.push(current);
+        self.last_edit_kind = None;
+        Some(snapshot)
+    }
+
+    /// The next edit is undone on its own
+    pub fn break_merging(&mut self) {
+        self.last_edit_kind = None;
+    }
+
+    /// Returns whether the click at `position` finishes a double click
+    pub fn register_click(&mut self, position: Vector2i) -> bool {
+        let is_double_click = self.last_click.is_some_and(|(instant, last_position)| {
+            instant.elapsed() < DOUBLE_CLICK_INTERVAL
+                && (last_position.x - position.x).abs() <= DOUBLE_CLICK_DISTANCE
+                && (last_position.y - position.y).abs() <= DOUBLE_CLICK_DISTANCE
+        });
+        self.last_click = (!is_double_click).then(|| (Instant::now(), position));
+        self.break_merging();
+
+        is_double_click
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Start of the word before `idx`, skipping anything that is not a word on the way
+pub fn previous_word_start(string: &str, idx: usize) -> usize {
+    string[..idx.min(string.len())]
+        .trim_end_matches(|c| !is_word_char(c))
+        .trim_end_matches(is_word_char)
+        .len()
+}
+
+/// End of the word after `idx`, skipping anything that is not a word on the way
+pub fn next_word_end(string: &str, idx: usize) -> usize {
+    let rest = string[idx.min(string.len())..]
+        .trim_start_matches(|c| !is_word_char(c))
+        .trim_start_matches(is_word_char);
+    string.len() - rest.len()
+}
+
+/// The word at `idx`. When `idx` is not on a word, the characters around it that are not
+/// a word either
+pub fn word_at(string: &str, idx: usize) -> Range<usize> {
+    let idx = idx.min(string.len());
+    let Some(c) = string[idx..]
+        .chars()
+        .next()
+        .or_else(|| string[..idx].chars().next_back())
+    else {
+        return idx..idx;
+    };
+    let is_word = is_word_char(c);
+
+    let start = string[..idx]
+        .trim_end_matches(|c| is_word_char(c) == is_word)
+        .len();
+    let end = string.len()
+        - string[idx..]
+            .trim_start_matches(|c| is_word_char(c) == is_word)
+            .len();
+    start..end
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FILE_NAME: &str = "my_palette.pacrb";
+
+    fn snapshot(string: &str) -> TextSnapshot {
+        TextSnapshot {
+            string: string.to_string(),
+            cursor_idx: string.len(),
+        }
+    }
+
+    #[test]
+    fn test_word_jumps() {
+        assert_eq!(previous_word_start(FILE_NAME, 16), 11);
+        assert_eq!(previous_word_start(FILE_NAME, 11), 0);
+        assert_eq!(next_word_end(FILE_NAME, 0), 10);
+        assert_eq!(next_word_end(FILE_NAME, 10), 16);
+        assert_eq!(word_at(FILE_NAME, 3), 0..10);
+        assert_eq!(word_at(FILE_NAME, 10), 10..11);
+        assert_eq!(word_at(FILE_NAME, 16), 11..16);
+        assert_eq!(word_at("", 0), 0..0);
+    }
+
+    #[test]
+    fn test_typing_is_undone_together() {
+        let mut text_editing = TextEditing::default();
+        text_editing.record(snapshot(""), EditKind::Typing);
+        text_editing.record(snapshot("a"), EditKind::Typing);
+        text_editing.record(snapshot("ab"), EditKind::Deleting);
+
+        assert_eq!(text_editing.undo(snapshot("a")), Some(snapshot("ab")));
+        assert_eq!(text_editing.undo(snapshot("ab")), Some(snapshot("")));
+        assert_eq!(text_editing.undo(snapshot("")), None);
+        assert_eq!(text_editing.redo(snapshot("")), Some(snapshot("ab")));
+
+        text_editing.record(snapshot("ab"), EditKind::Other);
+        assert_eq!(text_editing.redo(snapshot("abc")), None);
+    }
+}
@@ -14,6 +14,10 @@ use crate::{
 
 use std::{fmt::Debug, ops::Deref, time::Duration};
 
+use super::text_editing::{
+    next_word_end, previous_word_start, word_at, EditKind, TextEditing, TextSnapshot,
+};
+
 pub(super) const CURSOR_FONT: &str = "SourceCodePro-SemiBold.ttf";
 pub(super) const CURSOR_CHAR: char = '\u{2581}';
 pub(super) const BLINK_INTERVAL: Duration = Duration::from_millis(900);
@@ -39,6 +43,57 @@ pub trait TextBox: ActionableElement + Debug {
     fn copy(&self);
     fn paste(&mut self);
     fn drag_mouse(&mut self, mouse_pos: Vector2i);
+    fn string(&self) -> &str;
+    fn cursor_idx(&self) -> usize;
+    /// Where the selection started, when there is one. It ends at the cursor
+    fn selection_anchor(&self) -> Option<usize>;
+    /// Moves the cursor to `cursor_idx`, and selects everything between it and `anchor`
+    fn select(&mut self, anchor: usize, cursor_idx: usize);
+    /// Replaces the text without it being undoable, like undo and redo do
+    fn replace_string(&mut self, string: String, cursor_idx: usize);
+    fn text_editing(&mut self) -> &mut TextEditing;
+
+    fn snapshot(&self) -> TextSnapshot {
+        TextSnapshot {
+            string: self.string().to_string(),
+            cursor_idx: self.cursor_idx(),
+        }
+    }
+
+    /// Makes the edit since `before` undoable, if it changed the text
+    fn record_edit(&mut self, before: TextSnapshot, kind: EditKind) {
+        if before.string != self.string() {
+            self.text_editing().record(before, kind);
+        }
+    }
+
+    fn undo(&mut self) {
+        let current = self.snapshot();
+        if let Some(snapshot) = self.text_editing().undo(current) {
+            self.replace_string(snapshot.string, snapshot.cursor_idx);
+        }
+    }
+
+    fn redo(&mut self) {
+        let current = self.snapshot();
+        if let Some(snapshot) = self.text_editing().redo(current) {
+            self.replace_string(snapshot.string, snapshot.cursor_idx);
+        }
+    }
+
+    /// Moves the cursor to `idx`. With `extend_selection`, the selection grows or shrinks to
+    /// end there instead of going away
+    fn move_cursor_to(&mut self, idx: usize, extend_selection: bool) {
+        self.text_editing().break_merging();
+        if extend_selection {
+            let anchor = self.selection_anchor().unwrap_or(self.cursor_idx());
+            self.select(anchor, idx);
+        } else {
+            self.make_select_box_dissappear();
+            self.move_cursor(idx);
+        }
+    }
+
     fn event_handler(&mut self, ui_settings: &UISettings, event: SFMLEvent) -> (Vec<Event>, bool) {
         self.set_hover(ui_settings.cursor_position);
         match event {
@@ -57,7 +112,9 @@ pub trait TextBox: ActionableElement + Debug {
 
             // Cut
             SFMLEvent::KeyPressed { code, ctrl, .. } if code == Key::X && ctrl => {
+                let before = self.snapshot();
                 self.cut();
+                self.record_edit(before, EditKind::Other);
                 (vec![self.triggered_event()], true)
             }
 
@@ -69,7 +126,9 @@ pub trait TextBox: ActionableElement + Debug {
 
             // Paste
             SFMLEvent::KeyPressed { code, ctrl, .. } if code == Key::V && ctrl => {
+                let before = self.snapshot();
                 self.paste();
+                self.record_edit(before, EditKind::Other);
                 (vec![self.triggered_event()], true)
             }
 
@@ -81,9 +140,65 @@ pub trait TextBox: ActionableElement + Debug {
                 (vec![], true)
             }
 
+            // Undo and Redo
+            SFMLEvent::KeyPressed {
+                code, ctrl, shift, ..
+            } if code == Key::Z && ctrl && self.is_selected() => {
+                if shift {
+                    self.redo();
+                } else {
+                    self.undo();
+                }
+                (vec![self.triggered_event()], true)
+            }
+            SFMLEvent::KeyPressed { code, ctrl, .. }
+                if code == Key::Y && ctrl && self.is_selected() =>
+            {
+                self.redo();
+                (vec![self.triggered_event()], true)
+            }
+
+            // Word jumps, and growing the selection with shift. Fixed keys like Home and End,
+            // since the arrow binds can be remapped
+            SFMLEvent::KeyPressed {
+                code, ctrl, shift, ..
+            } if code == Key::Left && (ctrl || shift) && self.is_selected() => {
+                let idx = if ctrl {
+                    previous_word_start(self.string(), self.cursor_idx())
+                } else {
+                    previous_char_idx(self.string(), self.cursor_idx())
+                };
+                self.move_cursor_to(idx, shift);
+                (vec![], true)
+            }
+            SFMLEvent::KeyPressed {
+                code, ctrl, shift, ..
+            } if code == Key::Right && (ctrl || shift) && self.is_selected() => {
+                let idx = if ctrl {
+                    next_word_end(self.string(), self.cursor_idx())
+                } else {
+                    next_char_idx(self.string(), self.cursor_idx())
+                };
+                self.move_cursor_to(idx, shift);
+                (vec![], true)
+            }
+            SFMLEvent::KeyPressed { code, shift, .. }
+                if code == Key::Home && self.is_selected() =>
+            {
+                self.move_cursor_to(0, shift);
+                (vec![], true)
+            }
+            SFMLEvent::KeyPressed { code, shift, .. } if code == Key::End && self.is_selected() => {
+                self.move_cursor_to(self.string().len(), shift);
+                (vec![], true)
+            }
+
             // Mouse dragging and selection
             SFMLEvent::MouseMoved { x: _, y: _ } if self.is_dragging() => {
-                self.drag_mouse(ui_settings.cursor_position);
+                // A double clicked word stays selected until the click is released
+                if self.text_editing().double_clicked_word.is_none() {
+                    self.drag_mouse(ui_settings.cursor_position);
+                }
                 (vec![], true)
             }
             SFMLEvent::MouseButtonPressed { button, x: _, y: _ }
@@ -95,7 +210,18 @@ pub trait TextBox: ActionableElement + Debug {
                 let original_selected_state = self.is_selected();
                 self.bind_pressed(ui_settings.cursor_position);
                 if original_selected_state ^ self.is_selected() {
+                    self.text_editing()
+                        .register_click(ui_settings.cursor_position);
                     (vec![self.triggered_event()], true)
+                } else if self.is_selected()
+                    && self
+                        .text_editing()
+                        .register_click(ui_settings.cursor_position)
+                {
+                    let word = word_at(self.string(), self.cursor_idx());
+                    self.select(word.start, word.end);
+                    self.text_editing().double_clicked_word = Some(word);
+                    (vec![], true)
                 } else {
                     (vec![], false)
                 }
@@ -107,6 +233,9 @@ pub trait TextBox: ActionableElement + Debug {
                 ) =>
             {
                 self.bind_released(ui_settings.cursor_position);
+                if let Some(word) = self.text_editing().double_clicked_word.take() {
+                    self.select(word.start, word.end);
+                }
                 (vec![], self.is_dragging())
             }
             SFMLEvent::KeyPressed { code, .. }
@@ -115,6 +244,7 @@ pub trait TextBox: ActionableElement + Debug {
                     PossibleBinds::UILeft,
                 ) =>
             {
+                self.text_editing().break_merging();
                 self.move_cursor_left();
                 self.make_select_box_dissappear();
                 (vec![], true)
@@ -125,35 +255,31 @@ pub trait TextBox: ActionableElement + Debug {
                     PossibleBinds::UIRight,
                 ) =>
             {
+                self.text_editing().break_merging();
                 self.move_cursor_right();
                 self.make_select_box_dissappear();
                 (vec![], true)
             }
+            // Confirms what was typed. Where the mouse is does not matter
             SFMLEvent::KeyPressed { code, .. }
-                if ui_settings.binds.is_bind_pressed_and_binded(
-                    PossibleInputs::from(code),
-                    PossibleBinds::Select,
-                ) =>
+                if self.is_selected()
+                    && ui_settings.binds.is_bind_pressed_and_binded(
+                        PossibleInputs::from(code),
+                        PossibleBinds::Select,
+                    ) =>
             {
-                let original_selected_state = self.is_selected();
-                self.bind_pressed(ui_settings.cursor_position);
-                if original_selected_state ^ self.is_selected() {
-                    (vec![self.triggered_event()], true)
-                } else {
-                    (vec![], false)
-                }
-            }
-            SFMLEvent::KeyReleased { code, .. }
-                if ui_settings.binds.is_bind_released_and_binded(
-                    PossibleInputs::from(code),
-                    PossibleBinds::Select,
-                ) =>
-            {
-                self.bind_released(ui_settings.cursor_position);
-                (vec![], self.is_dragging())
+                self.deselect();
+                (vec![self.triggered_event()], true)
             }
-            SFMLEvent::TextEntered { unicode: _ } if self.is_selected() => {
+            SFMLEvent::TextEntered { unicode } if self.is_selected() => {
+                let kind = if matches!(unicode, '\u{8}' | '\u{7f}') {
+                    EditKind::Deleting
+                } else {
+                    EditKind::Typing
+                };
+                let before = self.snapshot();
                 self.text_entered(event);
+                self.record_edit(before, kind);
                 (vec![self.triggered_event()], true)
             }
             // if it is selected, and a key is being pressed, more than likely, text is being entered
@@ -165,6 +291,21 @@ pub trait TextBox: ActionableElement + Debug {
     }
 }
 
+fn previous_char_idx(string: &str, idx: usize) -> usize {
+    let idx = idx.min(string.len());
+    string[..idx]
+        .chars()
+        .next_back()
+        .map_or(0, |c| idx - c.len_utf8())
+}
+
+fn next_char_idx(string: &str, idx: usize) -> usize {
+    string
+        .get(idx..)
+        .and_then(|rest| rest.chars().next())
+        .map_or(idx, |c| idx + c.len_utf8())
+}
+
 impl Clone for Box<dyn TextBox> {
     fn clone(&self) -> Self {
         TextBox::box_clone(self.deref())
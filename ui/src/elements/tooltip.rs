@@ -0,0 +1,92 @@
+use sfml::{
+    graphics::{Color, IntRect, RenderTexture},
+    system::Vector2i,
+};
+use utils::resource_manager::ResourceManager;
+
+use crate::utils::positioning::UIPosition;
+
+use super::{
+    background::repeatable_3x3_background::Repeatable3x3Background, misc::text::Text,
+    traits::Element as ElementTrait, Element,
+};
+
+const TOOLTIP_ASSET: &str = "dark_blue_background.png";
+const TOOLTIP_PADDING: UIPosition = UIPosition {
+    top: Some(8),
+    bottom: Some(8),
+    left: Some(12),
+    right: Some(12),
+};
+
+/// Help text of an actionable element, shown in a box next to it once the cursor rests on
+/// it. See [`TooltipHover`](crate::dom_controller::tooltip_hover::TooltipHover)
+#[derive(Clone, Debug)]
+pub struct Tooltip {
+    background: Repeatable3x3Background,
+}
+
+impl Tooltip {
+    pub fn new(
+        resource_manager: &ResourceManager,
+        text: &str,
+        scale: f32,
+        font_size: u32,
+        color: Color,
+    ) -> Self {
+        let text = Text::new(
+            resource_manager,
+            UIPosition::CENTER,
+            text,
+            true,
+            font_size,
+            color,
+            0,
+        );
+        Self {
+            background: Repeatable3x3Background::new(
+                resource_manager,
+                vec![Element::Text(text)],
+                UIPosition::CENTER,
+                TOOLTIP_ASSET,
+                0,
+                Some(TOOLTIP_PADDING),
+                None,
+                scale,
+            ),
+        }
+    }
+
+    /// Places the tooltip above `hovered_bounds`, starting at the cursor if it is on them.
+    /// It goes below them instead when there is no room above, and is moved left when it
+    /// would go past the right of `relative_rect`
+    pub fn place(
+        &mut self,
+        hovered_bounds: IntRect,
+        cursor_position: Vector2i,
+        relative_rect: IntRect,
+    ) {
+        let bounds = self.background.global_bounds();
+        // Elements focused with the keyboard are hovered without the cursor
+        let left = if hovered_bounds.contains(cursor_position) {
+            cursor_position.x
+        } else {
+            hovered_bounds.left
+        };
+        let at_cursor = IntRect::new(left, hovered_bounds.top, 0, hovered_bounds.height);
+
+        let mut ui_position = if at_cursor.top - bounds.height >= relative_rect.top {
+            UIPosition::position_above_bounds_in_relative_rect(bounds, at_cursor, relative_rect)
+        } else {
+            UIPosition::position_below_bounds_in_relative_rect(at_cursor, relative_rect)
+        };
+        let max_left = (relative_rect.width - bounds.width).max(0);
+        ui_position.left = ui_position.left.map(|left| left.clamp(0, max_left));
+
+        self.background.set_ui_position(ui_position, relative_rect);
+    }
+
+    pub fn render(&mut self, render_texture: &mut RenderTexture) {
+        self.background.render(render_texture);
+    }
+}
@@ -2,6 +2,8 @@ use sfml::system::Vector2f;
 
 use crate::elements::textbox::traits::TextBoxTriggeredEvent;
 
+pub mod event_routes;
+
 pub type EventId = u16;
 
 #[derive(Clone, PartialEq, Debug)]
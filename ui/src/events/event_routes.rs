@@ -0,0 +1,123 @@
+use tracing::error;
+
+use crate::{
+    dom_controller::DomController,
+    dom_loader::validation::{
+        scanner::{scan, XmlNode},
+        schema::ACTIONABLE_ELEMENTS,
+    },
+};
+
+use super::Event;
+
+pub type EventHandler<T> = fn(&Event, &mut T);
+
+/// Routes the events of a dom to handlers by the id of the element that emitted them,
+/// instead of by event id. Elements with an id do not need an event_id in their layout.
+///
+/// Handlers are usually an [`EventHandler`], handlers that need more than their component
+/// can be any other `fn` type.
+///
+/// # Usage
+///
+/// ```ignore
+/// const EVENT_ROUTES: EventRoutes<EventHandler<Minimap>> = EventRoutes(&[
+///     ("zoom_in", zoom_in),
+///     ("zoom_out", zoom_out),
+/// ]);
+///
+/// for (handler, event) in EVENT_ROUTES.resolve(&minimap.minimap_dom, &events) {
+///     handler(event, minimap);
+/// }
+/// ```
+pub struct EventRoutes<H: 'static>(pub &'static [(&'static str, H)]);
+
+impl<H: Copy + 'static> EventRoutes<H> {
+    /// The handler of every event, in order. Events of elements without an id, or without
+    /// a handler, are left out
+    pub fn resolve<'e>(&self, dom: &DomController, events: &'e [Event]) -> Vec<(H, &'e Event)> {
+        events
+            .iter()
+            .filter_map(|event| {
+                let element_id = dom.element_id_of_event(event.id)?;
+                let (_, handler) = self.0.iter().find(|(id, _)| *id == element_id)?;
+                Some((*handler, event))
+            })
+            .collect()
+    }
+
+    /// Logs every route without an element in `dom` that emits events, since its handler
+    /// would never run. Returns whether every route has one
+    pub fn check(&self, dom: &DomController) -> bool {
+        let missing_ids = self.missing_ids_by(|id| dom.event_id_of_element(id).is_some());
+        for id in &missing_ids {
+            error!("No element with id `{}` emits events for its handler", id);
+        }
+
+        missing_ids.is_empty()
+    }
+
+    /// Routes without an actionable element in `xml_doc`. Lets tests check the routes of a
+    /// component against its layout, without loading it
+    pub fn missing_ids(&self, xml_doc: &str) -> Vec<&'static str> {
+        let (root, _) = scan(xml_doc);
+        let mut ids = Vec::new();
+        if let Some(root) = &root {
+            collect_actionable_ids(root, &mut ids);
+        }
+
+        self.missing_ids_by(|id| ids.contains(&id))
+    }
+
+    /// Fails with every route that has no actionable element in `xml_doc`. For tests of
+    /// components, against their layout
+    #[track_caller]
+    pub fn assert_matches_layout(&self, xml_doc: &str) {
+        let missing_ids = self.missing_ids(xml_doc);
+        assert!(
+            missing_ids.is_empty(),
+            "No element emits events for the routes {:?}",
+            missing_ids
+        );
+    }
+
+    fn missing_ids_by(&self, has_element: impl Fn(&str) -> bool) -> Vec<&'static str> {
+        self.0
+            .iter()
+            .map(|&(id, _)| id)
+            .filter(|id| !has_element(id))
+            .collect()
+    }
+}
+
+fn collect_actionable_ids<'a>(node: &'a XmlNode, ids: &mut Vec<&'a str>) {
+    if ACTIONABLE_ELEMENTS.contains(&node.name.as_str()) {
+        if let Some(id) = node.attribute("id") {
+            ids.push(&id.value);
+        }
+    }
+    for child in &node.children {
+        collect_actionable_ids(child, ids);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn noop(_: &Event, _: &mut ()) {}
+
+    #[test]
+    fn test_missing_ids() {
+        const ROUTES: EventRoutes<EventHandler<()>> =
+            EventRoutes(&[("load", noop), ("title", noop), ("export", noop)]);
+        let xml_doc = r#"<RootNode xmlns="ns">
+  <Div>
+    <Button type="PrimitiveFillButton" id="load"/>
+    <Text id="title">Title</Text>
+  </Div>
+</RootNode>"#;
+
+        assert_eq!(ROUTES.missing_ids(xml_doc), vec!["title", "export"]);
+    }
+}
@@ -1,7 +1,9 @@
+pub mod dialogs;
 pub mod dom_controller;
 pub mod dom_loader;
 pub mod elements;
 pub mod events;
 pub mod syncs;
 pub mod ui_settings;
+pub mod ui_state;
 pub mod utils;
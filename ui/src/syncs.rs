@@ -1,4 +1,4 @@
-use sfml::system::Vector2f;
+use sfml::{graphics::Color, system::Vector2f};
 
 use crate::elements::slider::quad_color_picker::QuadColorPickerSync;
 
@@ -11,6 +11,8 @@ pub enum Syncs {
     Vector2f(Vector2f),
     String(String),
     QuadColorPicker(QuadColorPickerSync),
+    /// None clears the color
+    Color(Option<Color>),
     #[default]
     Null,
 }
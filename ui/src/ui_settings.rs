@@ -28,6 +28,9 @@ pub struct UISettings {
     vsync: bool,
     pub has_new_settings: bool,
     pub binds: Bindings,
+    /// Colors pinned to the favorites row of the swatch bar, as hex strings
+    #[serde(default)]
+    pub favorite_colors: Vec<String>,
 }
 
 impl UISettings {
@@ -35,7 +38,7 @@ impl UISettings {
         let file = File::open(SETTINGS_LOCK_FILE_NAME)?;
         let reader = BufReader::new(file);
 
-        let result = serde_json::from_reader(reader)?;
+        let result: Self = serde_json::from_reader(reader)?;
 
         Ok(result)
     }
@@ -136,6 +139,7 @@ impl Default for UISettings {
             vsync: true,
             has_new_settings: true,
             binds: Default::default(),
+            favorite_colors: Default::default(),
         }
     }
 }
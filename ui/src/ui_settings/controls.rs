@@ -1,25 +1,59 @@
-use serde::{Deserialize, Serialize};
-use sfml::window::Event;
-use std::collections::{HashMap, HashSet};
-
+use serde::{de::IgnoredAny, Deserialize, Serialize};
+use sfml::window::{joystick::Axis, Event};
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
+
+pub mod chord;
 pub mod possible_binds;
 pub mod possible_inputs;
+pub mod shortcut;
+use chord::*;
 use possible_binds::*;
 use possible_inputs::*;
-
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
-pub struct BindInfo {
-    pub is_pressed: bool,
-}
-
+use shortcut::*;
+
+/// Longest pause allowed between two chords of a sequence
+const SEQUENCE_TIMEOUT: Duration = Duration::from_millis(1000);
+/// Number of recently pressed chords remembered for matching sequences
+const MAX_RECENT_CHORDS: usize = 8;
+/// How far a joystick axis has to be pushed, out of 100, to press its direction
+const JOYSTICK_PRESS_THRESHOLD: f32 = 50.;
+/// How far back a joystick axis has to come to release its direction again
+const JOYSTICK_RELEASE_THRESHOLD: f32 = 30.;
+
+/// Maps every bind to the shortcuts that trigger it.
+///
+/// UI binds, such as [`PossibleBinds::Select`], are held down. They are pressed while the
+/// input of one of their single chord shortcuts is, whatever the modifiers are.
+///
+/// Application binds, see [`APPLICATION_BINDS`], are triggered by events instead. They
+/// need the exact modifiers of a chord, and can be sequences of chords. When shortcuts of
+/// two binds end on the same chord, the longest one wins.
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(from = "SerializedBindings")]
 pub struct Bindings {
-    input_bindings: HashMap<PossibleInputs, PossibleBinds>,
-    binded_inputs: HashMap<PossibleBinds, (HashSet<PossibleInputs>, BindInfo)>,
+    shortcuts: HashMap<PossibleBinds, Vec<Shortcut>>,
+    #[serde(skip)]
+    pressed_binds: HashSet<PossibleBinds>,
+    /// Binds pressed or released by the last event given to [`Bindings::event_handler`]
+    #[serde(skip)]
+    just_pressed_binds: HashSet<PossibleBinds>,
+    #[serde(skip)]
+    just_released_binds: HashSet<PossibleBinds>,
+    #[serde(skip)]
+    held_joystick_directions: HashSet<PossibleInputs>,
+    #[serde(skip)]
+    recent_chords: Vec<Chord>,
+    #[serde(skip)]
+    last_chord_time: Option<Instant>,
 }
 
 impl Bindings {
     pub fn event_handler(&mut self, event: Event) {
+        self.just_pressed_binds.clear();
+        self.just_released_binds.clear();
         match event {
             Event::MouseButtonPressed { button, x: _, y: _ } => {
                 self.input_pressed(PossibleInputs::from(button))
@@ -29,6 +63,27 @@ impl Bindings {
             }
             Event::KeyPressed { code, .. } => self.input_pressed(PossibleInputs::from(code)),
             Event::KeyReleased { code, .. } => self.input_released(PossibleInputs::from(code)),
+            Event::JoystickButtonPressed { button, .. } => {
+                if let Some(input) = PossibleInputs::from_joystick_button(button) {
+                    self.input_pressed(input)
+                }
+            }
+            Event::JoystickButtonReleased { button, .. } => {
+                if let Some(input) = PossibleInputs::from_joystick_button(button) {
+                    self.input_released(input)
+                }
+            }
+            Event::JoystickMoved { axis, position, .. } => self.joystick_axis_moved(axis, position),
+            _ => {}
+        }
+
+        match event {
+            Event::KeyPressed { code, .. } if is_modifier_key(code) => {}
+            Event::KeyPressed { .. } | Event::MouseButtonPressed { .. } => {
+                if let Some(chord) = Chord::from_event(event) {
+                    self.chord_pressed(chord, Instant::now());
+                }
+            }
             _ => {}
         }
     }
@@ -38,11 +93,7 @@ impl Bindings {
     }
 
     pub fn is_bind_released(&self, bind: PossibleBinds) -> bool {
-        if let Some(bind_info) = self.get_bind_info(bind) {
-            !bind_info.is_pressed
-        } else {
-            false
-        }
+        self.shortcuts(bind).next().is_some() && !self.pressed_binds.contains(&bind)
     }
 
     pub fn is_bind_pressed_and_binded(&self, input: PossibleInputs, bind: PossibleBinds) -> bool {
@@ -50,131 +101,448 @@ impl Bindings {
     }
 
     pub fn is_bind_and_input_binded(&self, input: PossibleInputs, bind: PossibleBinds) -> bool {
-        self.input_bindings.get(&input) == Some(&bind)
+        self.shortcuts(bind)
+            .any(|shortcut| shortcut.single_input() == Some(input))
     }
 
     pub fn is_bind_pressed(&self, bind: PossibleBinds) -> bool {
-        if let Some(bind_info) = self.get_bind_info(bind) {
-            bind_info.is_pressed
-        } else {
-            false
+        self.pressed_binds.contains(&bind)
+    }
+
+    /// True if the last event went from `bind` being up to it being held. Key repeats do
+    /// not count
+    pub fn is_bind_just_pressed(&self, bind: PossibleBinds) -> bool {
+        self.just_pressed_binds.contains(&bind)
+    }
+
+    pub fn is_bind_just_released(&self, bind: PossibleBinds) -> bool {
+        self.just_released_binds.contains(&bind)
+    }
+
+    pub fn input_pressed(&mut self, input: PossibleInputs) {
+        for bind in self.binds_of_input(input) {
+            if self.pressed_binds.insert(bind) {
+                self.just_pressed_binds.insert(bind);
+            }
         }
     }
 
-    pub fn ctrl_alt_shift_system_is_pressed(
-        &mut self,
-        ctrl: bool,
-        alt: bool,
-        shift: bool,
-        system: bool,
-    ) {
-        if alt {
-            self.input_pressed(PossibleInputs::LAlt);
-            self.input_pressed(PossibleInputs::RAlt);
+    pub fn input_released(&mut self, input: PossibleInputs) {
+        for bind in self.binds_of_input(input) {
+            if self.pressed_binds.remove(&bind) {
+                self.just_released_binds.insert(bind);
+            }
         }
+    }
+
+    /// Presses and releases the directions of a joystick axis as it crosses the thresholds
+    fn joystick_axis_moved(&mut self, axis: Axis, position: f32) {
+        let Some((negative, positive)) = PossibleInputs::from_joystick_axis(axis) else {
+            return;
+        };
 
-        if ctrl {
-            self.input_pressed(PossibleInputs::RControl);
-            self.input_pressed(PossibleInputs::LControl);
+        for (direction, distance) in [(negative, -position), (positive, position)] {
+            let is_held = self.held_joystick_directions.contains(&direction);
+            if !is_held && distance > JOYSTICK_PRESS_THRESHOLD {
+                self.held_joystick_directions.insert(direction);
+                self.input_pressed(direction);
+            } else if is_held && distance < JOYSTICK_RELEASE_THRESHOLD {
+                self.held_joystick_directions.remove(&direction);
+                self.input_released(direction);
+            }
         }
+    }
 
-        if shift {
-            self.input_pressed(PossibleInputs::LShift);
-            self.input_pressed(PossibleInputs::RShift);
+    fn binds_of_input(&self, input: PossibleInputs) -> Vec<PossibleBinds> {
+        self.shortcuts
+            .keys()
+            .copied()
+            .filter(|&bind| self.is_bind_and_input_binded(input, bind))
+            .collect()
+    }
+
+    /// Remembers `chord` for matching sequences. Forgets older chords if it has been too
+    /// long since the last one.
+    fn chord_pressed(&mut self, chord: Chord, now: Instant) {
+        if self
+            .last_chord_time
+            .is_some_and(|last_chord_time| now.duration_since(last_chord_time) > SEQUENCE_TIMEOUT)
+        {
+            self.recent_chords.clear();
         }
+        self.last_chord_time = Some(now);
 
-        if system {
-            self.input_pressed(PossibleInputs::RSystem);
-            self.input_pressed(PossibleInputs::LSystem);
+        self.recent_chords.push(chord);
+        if self.recent_chords.len() > MAX_RECENT_CHORDS {
+            self.recent_chords.remove(0);
         }
     }
 
-    pub fn input_pressed(&mut self, input: PossibleInputs) {
-        let Some(bind_info) = self.get_mut_bind_info_from_input(input) else {
+    pub fn remove_bind(&mut self, input: PossibleInputs, bind: PossibleBinds) {
+        let Some(shortcuts) = self.shortcuts.get_mut(&bind) else {
+            // no binds binded.
             return;
         };
+        shortcuts.retain(|shortcut| shortcut.single_input() != Some(input));
 
-        bind_info.is_pressed = true;
+        if shortcuts.is_empty() {
+            self.shortcuts.remove(&bind);
+            self.pressed_binds.remove(&bind);
+        }
     }
 
-    pub fn input_released(&mut self, input: PossibleInputs) {
-        let Some(binded_input) = self.get_mut_bind_info_from_input(input) else {
-            return;
+    pub fn set_bind(&mut self, input: PossibleInputs, bind: PossibleBinds) {
+        self.remove_bind(input, bind);
+        self.set_shortcut(Shortcut::from(input), bind);
+    }
+
+    /// True if `event` finishes one of the shortcuts of `bind`.
+    ///
+    /// Expects [`Bindings::event_handler`] to have already seen `event`, otherwise only
+    /// single chord shortcuts can match.
+    pub fn is_shortcut_pressed(&self, event: Event, bind: PossibleBinds) -> bool {
+        if !matches!(
+            event,
+            Event::KeyPressed { .. } | Event::MouseButtonPressed { .. }
+        ) {
+            return false;
+        }
+        let Some(chord) = Chord::from_event(event) else {
+            return false;
+        };
+        let single_chord = [chord];
+        let recent_chords = if self.recent_chords.last() == Some(&chord) {
+            self.recent_chords.as_slice()
+        } else {
+            &single_chord
+        };
+
+        let Some(match_len) = self.longest_match(bind, recent_chords) else {
+            return false;
         };
+        // A longer shortcut ending on the same chord wins. e.g. G then R over R
+        !self
+            .shortcuts
+            .keys()
+            .filter(|&&other_bind| other_bind != bind && is_same_group(bind, other_bind))
+            .any(|&other_bind| {
+                self.longest_match(other_bind, recent_chords)
+                    .is_some_and(|other_match_len| other_match_len > match_len)
+            })
+    }
 
-        binded_input.is_pressed = false;
+    /// True if `event` releases one of the single chord shortcuts of `bind`, with exactly
+    /// its modifiers
+    pub fn is_shortcut_released(&self, event: Event, bind: PossibleBinds) -> bool {
+        if !matches!(
+            event,
+            Event::KeyReleased { .. } | Event::MouseButtonReleased { .. }
+        ) {
+            return false;
+        }
+        let Some(chord) = Chord::from_event(event) else {
+            return false;
+        };
+        self.shortcuts(bind)
+            .any(|shortcut| *shortcut == Shortcut::from(chord))
     }
 
-    fn get_mut_bind_info_from_input(&mut self, input: PossibleInputs) -> Option<&mut BindInfo> {
-        let Some(binded_input) = self.input_bindings.get(&input) else {
-          return None;
+    /// True if `event` releases the last input of a shortcut of `bind`. Ignores the
+    /// modifiers, as they may have been let go of first.
+    pub fn is_shortcut_input_released(&self, event: Event, bind: PossibleBinds) -> bool {
+        let input = match event {
+            Event::KeyReleased { code, .. } => PossibleInputs::from(code),
+            Event::MouseButtonReleased { button, .. } => PossibleInputs::from(button),
+            _ => return false,
         };
+        self.shortcuts(bind)
+            .filter_map(Shortcut::last_chord)
+            .any(|chord| chord.input == input)
+    }
 
-        self.binded_inputs
-            .get_mut(binded_input)
-            .map(|tuple| &mut tuple.1)
+    /// True while the last input of any shortcut of `bind` is held down
+    pub fn is_shortcut_input_held(&self, bind: PossibleBinds) -> bool {
+        self.shortcuts(bind)
+            .filter_map(Shortcut::last_chord)
+            .any(Chord::is_input_held)
     }
 
-    fn get_bind_info(&self, bind: PossibleBinds) -> Option<BindInfo> {
-        self.binded_inputs.get(&bind).map(|tuple| tuple.1)
+    /// Length of the longest shortcut of `bind` that `recent_chords` ends with
+    fn longest_match(&self, bind: PossibleBinds, recent_chords: &[Chord]) -> Option<usize> {
+        self.shortcuts(bind)
+            .filter(|shortcut| shortcut.is_completed_by(recent_chords))
+            .map(|shortcut| shortcut.chords().len())
+            .max()
     }
 
-    pub fn remove_bind(&mut self, input: PossibleInputs, bind: PossibleBinds) {
-        let Some(binded_input) = self.binded_inputs.get_mut(&bind) else {
-            // no binds binded.
-            return;
-        };
-        self.input_bindings.remove(&input);
+    pub fn shortcuts(&self, bind: PossibleBinds) -> impl Iterator<Item = &Shortcut> {
+        self.shortcuts.get(&bind).into_iter().flatten()
+    }
 
-        binded_input.0.remove(&input);
+    /// Binds `shortcut` to `bind`, taking it away from whichever bind of the same kind had
+    /// it before
+    pub fn set_shortcut(&mut self, shortcut: Shortcut, bind: PossibleBinds) {
+        for (_, shortcuts) in self
+            .shortcuts
+            .iter_mut()
+            .filter(|(&other_bind, _)| is_same_group(bind, other_bind))
+        {
+            shortcuts.retain(|other_shortcut| *other_shortcut != shortcut);
+        }
+        self.shortcuts.entry(bind).or_default().push(shortcut);
+    }
 
-        if binded_input.0.is_empty() {
-            self.binded_inputs.remove(&bind);
+    pub fn remove_shortcut(&mut self, shortcut: &Shortcut, bind: PossibleBinds) {
+        if let Some(shortcuts) = self.shortcuts.get_mut(&bind) {
+            shortcuts.retain(|other_shortcut| other_shortcut != shortcut);
         }
     }
 
-    fn add_bind(&mut self, input: PossibleInputs, bind: PossibleBinds) {
-        self.input_bindings.insert(input, bind);
+    /// Unbinds every shortcut of `bind`. The bind is kept, so it does not get its defaults
+    /// back
+    pub fn clear_shortcuts(&mut self, bind: PossibleBinds) {
+        self.shortcuts.insert(bind, Default::default());
+        self.pressed_binds.remove(&bind);
+    }
 
-        if let Some(tuple) = self.binded_inputs.get_mut(&bind) {
-            tuple.0.insert(input);
-        } else {
-            self.binded_inputs
-                .insert(bind, (HashSet::from([input]), Default::default()));
+    /// Like [`Bindings::clear_shortcuts`], but keeps the shortcuts on the mouse. UI binds are
+    /// rebound this way, so the UI can still be clicked
+    pub fn clear_shortcuts_but_mouse(&mut self, bind: PossibleBinds) {
+        let mouse_shortcuts = self
+            .shortcuts(bind)
+            .filter(|shortcut| {
+                shortcut
+                    .single_input()
+                    .is_some_and(PossibleInputs::is_mouse)
+            })
+            .cloned()
+            .collect();
+        self.shortcuts.insert(bind, mouse_shortcuts);
+        self.pressed_binds.remove(&bind);
+    }
+
+    /// Another bind of the same kind as `bind` that `shortcut` already triggers
+    pub fn conflicting_bind(
+        &self,
+        shortcut: &Shortcut,
+        bind: PossibleBinds,
+    ) -> Option<PossibleBinds> {
+        self.shortcuts
+            .iter()
+            .find(|(&other_bind, shortcuts)| {
+                other_bind != bind
+                    && is_same_group(bind, other_bind)
+                    && shortcuts.contains(shortcut)
+            })
+            .map(|(other_bind, _)| *other_bind)
+    }
+
+    /// Gives `bind` its default shortcuts back, taking them from other binds if necessary
+    pub fn reset_shortcuts(&mut self, bind: PossibleBinds) {
+        self.clear_shortcuts(bind);
+        for (_, shortcut) in default_shortcuts()
+            .into_iter()
+            .filter(|(default_bind, _)| *default_bind == bind)
+        {
+            self.set_shortcut(shortcut, bind);
         }
     }
 
-    pub fn set_bind(&mut self, input: PossibleInputs, bind: PossibleBinds) {
-        self.remove_bind(input, bind);
-        self.add_bind(input, bind);
+    pub fn reset_all_shortcuts(&mut self) {
+        self.shortcuts.clear();
+        self.pressed_binds.clear();
+        self.add_missing_default_shortcuts();
+    }
+
+    /// Settings saved before a bind existed have no shortcuts for it. This gives those
+    /// binds their default shortcuts, without touching any bind that was already saved.
+    pub fn add_missing_default_shortcuts(&mut self) {
+        let saved_binds: HashSet<PossibleBinds> = self.shortcuts.keys().copied().collect();
+        for (bind, shortcut) in default_shortcuts() {
+            if saved_binds.contains(&bind) || self.conflicting_bind(&shortcut, bind).is_some() {
+                continue;
+            }
+            self.shortcuts.entry(bind).or_default().push(shortcut);
+        }
     }
 }
 
+/// Application binds only conflict with other application binds, so the same arrow key
+/// can move around the UI and pan the view
+fn is_same_group(bind: PossibleBinds, other_bind: PossibleBinds) -> bool {
+    APPLICATION_BINDS.contains(&bind) == APPLICATION_BINDS.contains(&other_bind)
+}
+
+fn default_shortcuts() -> Vec<(PossibleBinds, Shortcut)> {
+    let mut default_shortcuts = vec![
+        // Self explanotry buttons
+        (PossibleBinds::Select, PossibleInputs::ButtonLeft.into()),
+        (PossibleBinds::Escape, PossibleInputs::Escape.into()),
+        (PossibleBinds::Select, PossibleInputs::Enter.into()),
+        // UI arrow movement
+        (PossibleBinds::UIUp, PossibleInputs::Up.into()),
+        (PossibleBinds::UIDown, PossibleInputs::Down.into()),
+        (PossibleBinds::UILeft, PossibleInputs::Left.into()),
+        (PossibleBinds::UIRight, PossibleInputs::Right.into()),
+        // Gamepad
+        (
+            PossibleBinds::Select,
+            PossibleInputs::JoystickButton0.into(),
+        ),
+        (
+            PossibleBinds::Escape,
+            PossibleInputs::JoystickButton1.into(),
+        ),
+        (PossibleBinds::UIUp, PossibleInputs::DPadUp.into()),
+        (PossibleBinds::UIDown, PossibleInputs::DPadDown.into()),
+        (PossibleBinds::UILeft, PossibleInputs::DPadLeft.into()),
+        (PossibleBinds::UIRight, PossibleInputs::DPadRight.into()),
+        (PossibleBinds::UIUp, PossibleInputs::JoystickUp.into()),
+        (PossibleBinds::UIDown, PossibleInputs::JoystickDown.into()),
+        (PossibleBinds::UILeft, PossibleInputs::JoystickLeft.into()),
+        (PossibleBinds::UIRight, PossibleInputs::JoystickRight.into()),
+    ];
+    default_shortcuts.extend(
+        [
+            (
+                PossibleBinds::Undo,
+                Chord::new(PossibleInputs::Z).with_ctrl(),
+            ),
+            (
+                PossibleBinds::Redo,
+                Chord::new(PossibleInputs::R).with_ctrl(),
+            ),
+            (PossibleBinds::ToggleErase, Chord::new(PossibleInputs::E)),
+            (PossibleBinds::ToggleAutoRamp, Chord::new(PossibleInputs::A)),
+            (
+                PossibleBinds::QuickSave,
+                Chord::new(PossibleInputs::S).with_ctrl(),
+            ),
+            (
+                PossibleBinds::Eyedrop,
+                Chord::new(PossibleInputs::ButtonLeft).with_ctrl(),
+            ),
+            (PossibleBinds::ScreenEyedrop, Chord::new(PossibleInputs::I)),
+            (
+                PossibleBinds::RecenterView,
+                Chord::new(PossibleInputs::Space),
+            ),
+            (PossibleBinds::FitView, Chord::new(PossibleInputs::F)),
+            // Screen dragging
+            (
+                PossibleBinds::PanDrag,
+                Chord::new(PossibleInputs::ButtonRight),
+            ),
+            (
+                PossibleBinds::PanDrag,
+                Chord::new(PossibleInputs::ButtonMiddle),
+            ),
+            (
+                PossibleBinds::MoveReference,
+                Chord::new(PossibleInputs::ButtonLeft).with_alt(),
+            ),
+            (
+                PossibleBinds::ToggleRecolorPreview,
+                Chord::new(PossibleInputs::P),
+            ),
+            // Keyboard panning and zooming
+            (PossibleBinds::PanLeft, Chord::new(PossibleInputs::Left)),
+            (PossibleBinds::PanRight, Chord::new(PossibleInputs::Right)),
+            (PossibleBinds::PanUp, Chord::new(PossibleInputs::Up)),
+            (PossibleBinds::PanDown, Chord::new(PossibleInputs::Down)),
+            (PossibleBinds::ZoomIn, Chord::new(PossibleInputs::Equal)),
+            (PossibleBinds::ZoomIn, Chord::new(PossibleInputs::Add)),
+            (PossibleBinds::ZoomOut, Chord::new(PossibleInputs::Hyphen)),
+            (PossibleBinds::ZoomOut, Chord::new(PossibleInputs::Subtract)),
+            (PossibleBinds::ZoomPreset1, Chord::new(PossibleInputs::Num1)),
+            (PossibleBinds::ZoomPreset2, Chord::new(PossibleInputs::Num2)),
+            (PossibleBinds::ZoomPreset3, Chord::new(PossibleInputs::Num3)),
+            (PossibleBinds::ZoomPreset4, Chord::new(PossibleInputs::Num4)),
+            // Tabs
+            (
+                PossibleBinds::NextTab,
+                Chord::new(PossibleInputs::Tab).with_ctrl(),
+            ),
+            (
+                PossibleBinds::PreviousTab,
+                Chord::new(PossibleInputs::Tab).with_ctrl().with_shift(),
+            ),
+            // Focus
+            (PossibleBinds::FocusNext, Chord::new(PossibleInputs::Tab)),
+            (
+                PossibleBinds::FocusPrevious,
+                Chord::new(PossibleInputs::Tab).with_shift(),
+            ),
+        ]
+        .map(|(bind, chord)| (bind, Shortcut::from(chord))),
+    );
+
+    default_shortcuts
+}
+
 impl Default for Bindings {
     fn default() -> Self {
         let mut bind = Self {
-            input_bindings: Default::default(),
-            binded_inputs: Default::default(),
+            shortcuts: Default::default(),
+            pressed_binds: Default::default(),
+            just_pressed_binds: Default::default(),
+            just_released_binds: Default::default(),
+            held_joystick_directions: Default::default(),
+            recent_chords: Default::default(),
+            last_chord_time: None,
         };
+        bind.add_missing_default_shortcuts();
+        bind
+    }
+}
 
-        for pair in [
-            // Self explanotry buttons
-            (PossibleBinds::Select, PossibleInputs::ButtonLeft),
-            (PossibleBinds::Escape, PossibleInputs::Escape),
-            // UI arrow movement
-            (PossibleBinds::UIUp, PossibleInputs::Up),
-            (PossibleBinds::UIDown, PossibleInputs::Down),
-            (PossibleBinds::UILeft, PossibleInputs::Left),
-            (PossibleBinds::UIRight, PossibleInputs::Right),
-            // Scroll wheel
-            (PossibleBinds::UIUp, PossibleInputs::VerticalWheel),
-            (PossibleBinds::UIDown, PossibleInputs::VerticalWheel),
-            (PossibleBinds::UILeft, PossibleInputs::HorizontalWheel),
-            (PossibleBinds::UIRight, PossibleInputs::HorizontalWheel),
-        ] {
-            bind.add_bind(pair.1, pair.0);
+/// Every format [`Bindings`] has been saved in.
+///
+/// UI binds used to be saved per input in `binded_inputs`, and application binds per chord
+/// in `chords`. Both are moved into `shortcuts` when loaded.
+#[derive(Deserialize)]
+struct SerializedBindings {
+    #[serde(default)]
+    shortcuts: Option<HashMap<PossibleBinds, Vec<Shortcut>>>,
+    #[serde(default)]
+    binded_inputs: HashMap<PossibleBinds, (HashSet<PossibleInputs>, IgnoredAny)>,
+    #[serde(default)]
+    chords: HashMap<PossibleBinds, HashSet<Chord>>,
+}
+
+impl From<SerializedBindings> for Bindings {
+    fn from(serialized_bindings: SerializedBindings) -> Self {
+        let mut bindings = Self {
+            shortcuts: Default::default(),
+            pressed_binds: Default::default(),
+            just_pressed_binds: Default::default(),
+            just_released_binds: Default::default(),
+            held_joystick_directions: Default::default(),
+            recent_chords: Default::default(),
+            last_chord_time: None,
+        };
+
+        if let Some(shortcuts) = serialized_bindings.shortcuts {
+            bindings.shortcuts = shortcuts;
+        } else {
+            for (bind, (inputs, _)) in serialized_bindings.binded_inputs {
+                bindings
+                    .shortcuts
+                    .entry(bind)
+                    .or_default()
+                    .extend(inputs.into_iter().map(Shortcut::from));
+            }
+            for (bind, chords) in serialized_bindings.chords {
+                bindings
+                    .shortcuts
+                    .entry(bind)
+                    .or_default()
+                    .extend(chords.into_iter().map(Shortcut::from));
+            }
         }
-        bind
+        bindings.add_missing_default_shortcuts();
+
+        bindings
     }
 }
 
@@ -186,9 +554,13 @@ mod test {
         let binds: &mut Bindings = &mut Default::default();
 
         binds.remove_bind(PossibleInputs::ButtonLeft, PossibleBinds::Select);
-        assert_eq!(binds.input_bindings.get(&PossibleInputs::ButtonLeft), None);
+        assert!(!binds.is_bind_and_input_binded(PossibleInputs::ButtonLeft, PossibleBinds::Select));
 
-        assert!(!binds.binded_inputs.contains_key(&PossibleBinds::Select));
+        // The other default shortcuts of Select are kept
+        assert!(binds.is_bind_and_input_binded(PossibleInputs::Enter, PossibleBinds::Select));
+        assert!(
+            binds.is_bind_and_input_binded(PossibleInputs::JoystickButton0, PossibleBinds::Select)
+        );
     }
 
     #[test]
@@ -197,16 +569,10 @@ mod test {
 
         binds.set_bind(PossibleInputs::A, PossibleBinds::Select);
 
+        assert!(binds.is_bind_and_input_binded(PossibleInputs::A, PossibleBinds::Select));
         binds
-            .input_bindings
-            .get(&PossibleInputs::A)
-            .expect("unit-test");
-        binds
-            .binded_inputs
-            .get(&PossibleBinds::Select)
-            .expect("unit-test")
-            .0
-            .get(&PossibleInputs::A)
+            .shortcuts(PossibleBinds::Select)
+            .find(|shortcut| **shortcut == Shortcut::from(PossibleInputs::A))
             .expect("unit-test");
     }
 
@@ -218,6 +584,122 @@ mod test {
         assert!(binds.is_bind_released(PossibleBinds::Select));
     }
 
+    #[test]
+    fn test_set_shortcut_takes_it_from_other_binds() {
+        let binds: &mut Bindings = &mut Default::default();
+        let shortcut = Shortcut::from(Chord::new(PossibleInputs::Z).with_ctrl());
+
+        binds.set_shortcut(shortcut.clone(), PossibleBinds::Redo);
+        assert_eq!(
+            binds.conflicting_bind(&shortcut, PossibleBinds::Undo),
+            Some(PossibleBinds::Redo)
+        );
+        assert!(!binds.shortcuts(PossibleBinds::Undo).any(|s| *s == shortcut));
+    }
+
+    #[test]
+    fn test_add_missing_default_shortcuts_keeps_saved_shortcuts() {
+        let binds: &mut Bindings = &mut Default::default();
+        binds.shortcuts.clear();
+        binds.set_shortcut(PossibleInputs::U.into(), PossibleBinds::Undo);
+
+        binds.add_missing_default_shortcuts();
+        assert_eq!(binds.shortcuts(PossibleBinds::Undo).count(), 1);
+        assert_eq!(binds.shortcuts(PossibleBinds::PanDrag).count(), 2);
+    }
+
+    #[test]
+    fn test_default_shortcuts_are_not_shared() {
+        let default_shortcuts = default_shortcuts();
+        for (idx, (bind, shortcut)) in default_shortcuts.iter().enumerate() {
+            let is_shared =
+                default_shortcuts[idx + 1..]
+                    .iter()
+                    .any(|(other_bind, other_shortcut)| {
+                        other_shortcut == shortcut && is_same_group(*bind, *other_bind)
+                    });
+            assert!(!is_shared, "{:?} of {:?} is shared", shortcut, bind);
+        }
+    }
+
+    #[test]
+    fn test_reset_shortcuts() {
+        let binds: &mut Bindings = &mut Default::default();
+        let shortcut = Shortcut::from(Chord::new(PossibleInputs::Z).with_ctrl());
+        binds.set_shortcut(shortcut.clone(), PossibleBinds::Redo);
+
+        binds.reset_shortcuts(PossibleBinds::Undo);
+        assert_eq!(
+            binds.conflicting_bind(&shortcut, PossibleBinds::Redo),
+            Some(PossibleBinds::Undo)
+        );
+        assert_eq!(binds.shortcuts(PossibleBinds::Redo).count(), 1);
+    }
+
+    #[test]
+    fn test_clear_shortcuts_but_mouse() {
+        let binds: &mut Bindings = &mut Default::default();
+
+        binds.clear_shortcuts_but_mouse(PossibleBinds::Select);
+        assert!(binds.is_bind_and_input_binded(PossibleInputs::ButtonLeft, PossibleBinds::Select));
+        assert!(!binds.is_bind_and_input_binded(PossibleInputs::Enter, PossibleBinds::Select));
+        assert_eq!(binds.shortcuts(PossibleBinds::Select).count(), 1);
+    }
+
+    #[test]
+    fn test_sequence_wins_over_its_last_chord() {
+        let binds: &mut Bindings = &mut Default::default();
+        let sequence =
+            Shortcut::from(Chord::new(PossibleInputs::G)).then(Chord::new(PossibleInputs::R));
+        binds.set_shortcut(sequence, PossibleBinds::RecenterView);
+        binds.set_shortcut(PossibleInputs::R.into(), PossibleBinds::FitView);
+
+        let now = Instant::now();
+        binds.chord_pressed(Chord::new(PossibleInputs::G), now);
+        binds.chord_pressed(Chord::new(PossibleInputs::R), now);
+        assert_eq!(
+            binds.longest_match(PossibleBinds::RecenterView, &binds.recent_chords),
+            Some(2)
+        );
+
+        binds.chord_pressed(Chord::new(PossibleInputs::R), now + SEQUENCE_TIMEOUT * 2);
+        assert_eq!(binds.recent_chords, vec![Chord::new(PossibleInputs::R)]);
+        assert_eq!(
+            binds.longest_match(PossibleBinds::RecenterView, &binds.recent_chords),
+            None
+        );
+    }
+
+    #[test]
+    fn test_migrate_from_input_bindings() {
+        let json = r#"{
+            "input_bindings": {"ButtonLeft": "Select"},
+            "binded_inputs": {"Select": [["ButtonLeft", "A"], {"is_pressed": false}]},
+            "chords": {"Undo": [{"modifiers": {"ctrl": true, "alt": false, "shift": false, "system": false}, "input": "U"}]}
+        }"#;
+        let binds: Bindings = serde_json::from_str(json).expect("unit-test");
+
+        assert!(binds.is_bind_and_input_binded(PossibleInputs::A, PossibleBinds::Select));
+        assert!(binds
+            .shortcuts(PossibleBinds::Undo)
+            .any(|s| *s == Shortcut::from(Chord::new(PossibleInputs::U).with_ctrl())));
+        // Binds that were not saved get their defaults
+        assert_eq!(binds.shortcuts(PossibleBinds::PanDrag).count(), 2);
+    }
+
+    #[test]
+    fn test_joystick_axis_hysteresis() {
+        let binds: &mut Bindings = &mut Default::default();
+
+        binds.joystick_axis_moved(Axis::X, -80.);
+        assert!(binds.is_bind_just_pressed(PossibleBinds::UILeft));
+        binds.joystick_axis_moved(Axis::X, -40.);
+        assert!(binds.is_bind_pressed(PossibleBinds::UILeft));
+        binds.joystick_axis_moved(Axis::X, -10.);
+        assert!(binds.is_bind_just_released(PossibleBinds::UILeft));
+        assert!(!binds.is_bind_pressed(PossibleBinds::UIRight));
+    }
+
     #[test]
     fn test_is_pressed() {
         let binds: &mut Bindings = &mut Default::default();
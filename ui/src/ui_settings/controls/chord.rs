@@ -0,0 +1,143 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use sfml::window::{mouse::Button, Event, Key};
+
+use super::possible_inputs::PossibleInputs;
+
+/// Modifier keys that have to be held down. Left and right keys are treated the same.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub struct Modifiers {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub system: bool,
+}
+
+impl Modifiers {
+    /// Mouse events do not say which modifiers are held, so they are read from the keyboard.
+    pub fn currently_pressed() -> Self {
+        Self {
+            ctrl: Key::LControl.is_pressed() || Key::RControl.is_pressed(),
+            alt: Key::LAlt.is_pressed() || Key::RAlt.is_pressed(),
+            shift: Key::LShift.is_pressed() || Key::RShift.is_pressed(),
+            system: Key::LSystem.is_pressed() || Key::RSystem.is_pressed(),
+        }
+    }
+}
+
+/// Modifier keys are part of a chord, they never make one on their own
+pub fn is_modifier_key(key: Key) -> bool {
+    matches!(
+        key,
+        Key::LControl
+            | Key::RControl
+            | Key::LShift
+            | Key::RShift
+            | Key::LAlt
+            | Key::RAlt
+            | Key::LSystem
+            | Key::RSystem
+    )
+}
+
+/// An input, along with the exact modifiers that have to be held for it.
+///
+/// # Usage:
+/// ```
+/// # use ui::ui_settings::controls::{chord::Chord, possible_inputs::PossibleInputs};
+/// let chord = Chord::new(PossibleInputs::Z).with_ctrl().with_shift();
+/// assert_eq!(chord.to_string(), "Ctrl+Shift+Z");
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Chord {
+    pub modifiers: Modifiers,
+    pub input: PossibleInputs,
+}
+
+impl Chord {
+    pub fn new(input: PossibleInputs) -> Self {
+        Self {
+            modifiers: Default::default(),
+            input,
+        }
+    }
+
+    pub fn with_ctrl(mut self) -> Self {
+        self.modifiers.ctrl = true;
+        self
+    }
+
+    pub fn with_shift(mut self) -> Self {
+        self.modifiers.shift = true;
+        self
+    }
+
+    pub fn with_alt(mut self) -> Self {
+        self.modifiers.alt = true;
+        self
+    }
+
+    /// The chord a key or mouse button event was made with
+    pub fn from_event(event: Event) -> Option<Self> {
+        match event {
+            Event::KeyPressed {
+                code,
+                alt,
+                ctrl,
+                shift,
+                system,
+                ..
+            }
+            | Event::KeyReleased {
+                code,
+                alt,
+                ctrl,
+                shift,
+                system,
+                ..
+            } => Some(Self {
+                modifiers: Modifiers {
+                    ctrl,
+                    alt,
+                    shift,
+                    system,
+                },
+                input: PossibleInputs::from(code),
+            }),
+            Event::MouseButtonPressed { button, .. }
+            | Event::MouseButtonReleased { button, .. } => Some(Self {
+                modifiers: Modifiers::currently_pressed(),
+                input: PossibleInputs::from(button),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Whether the input of the chord is held down right now, ignoring the modifiers
+    pub fn is_input_held(&self) -> bool {
+        if let Ok(button) = Button::try_from(self.input) {
+            button.is_pressed()
+        } else if let Ok(key) = Key::try_from(self.input) {
+            key.is_pressed()
+        } else {
+            false
+        }
+    }
+}
+
+impl Display for Chord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (is_held, name) in [
+            (self.modifiers.ctrl, "Ctrl"),
+            (self.modifiers.alt, "Alt"),
+            (self.modifiers.shift, "Shift"),
+            (self.modifiers.system, "System"),
+        ] {
+            if is_held {
+                write!(f, "{}+", name)?;
+            }
+        }
+        write!(f, "{:?}", self.input)
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -8,4 +10,111 @@ pub enum PossibleBinds {
     UILeft,
     UIRight,
     Escape,
+    // Application binds. These are triggered by chords, see `Bindings::is_shortcut_pressed`
+    Undo,
+    Redo,
+    ToggleErase,
+    ToggleAutoRamp,
+    QuickSave,
+    Eyedrop,
+    ScreenEyedrop,
+    RecenterView,
+    FitView,
+    PanDrag,
+    MoveReference,
+    ToggleRecolorPreview,
+    PanLeft,
+    PanRight,
+    PanUp,
+    PanDown,
+    ZoomIn,
+    ZoomOut,
+    ZoomPreset1,
+    ZoomPreset2,
+    ZoomPreset3,
+    ZoomPreset4,
+    NextTab,
+    PreviousTab,
+    FocusNext,
+    FocusPrevious,
+}
+
+/// Binds that move around and act on the UI, in the order they are listed to the user
+pub const UI_BINDS: [PossibleBinds; 6] = [
+    PossibleBinds::Select,
+    PossibleBinds::UIUp,
+    PossibleBinds::UIDown,
+    PossibleBinds::UILeft,
+    PossibleBinds::UIRight,
+    PossibleBinds::Escape,
+];
+
+/// Binds that are triggered by chords, in the order they are listed to the user
+pub const APPLICATION_BINDS: [PossibleBinds; 26] = [
+    PossibleBinds::Undo,
+    PossibleBinds::Redo,
+    PossibleBinds::ToggleErase,
+    PossibleBinds::ToggleAutoRamp,
+    PossibleBinds::QuickSave,
+    PossibleBinds::Eyedrop,
+    PossibleBinds::ScreenEyedrop,
+    PossibleBinds::RecenterView,
+    PossibleBinds::FitView,
+    PossibleBinds::PanDrag,
+    PossibleBinds::MoveReference,
+    PossibleBinds::ToggleRecolorPreview,
+    PossibleBinds::PanLeft,
+    PossibleBinds::PanRight,
+    PossibleBinds::PanUp,
+    PossibleBinds::PanDown,
+    PossibleBinds::ZoomIn,
+    PossibleBinds::ZoomOut,
+    PossibleBinds::ZoomPreset1,
+    PossibleBinds::ZoomPreset2,
+    PossibleBinds::ZoomPreset3,
+    PossibleBinds::ZoomPreset4,
+    PossibleBinds::NextTab,
+    PossibleBinds::PreviousTab,
+    PossibleBinds::FocusNext,
+    PossibleBinds::FocusPrevious,
+];
+
+impl Display for PossibleBinds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            PossibleBinds::Select => "Select",
+            PossibleBinds::UIUp => "UI up",
+            PossibleBinds::UIDown => "UI down",
+            PossibleBinds::UILeft => "UI left",
+            PossibleBinds::UIRight => "UI right",
+            PossibleBinds::Escape => "Escape",
+            PossibleBinds::Undo => "Undo",
+            PossibleBinds::Redo => "Redo",
+            PossibleBinds::ToggleErase => "Toggle erase",
+            PossibleBinds::ToggleAutoRamp => "Toggle auto ramp",
+            PossibleBinds::QuickSave => "Quick save",
+            PossibleBinds::Eyedrop => "Eyedrop",
+            PossibleBinds::ScreenEyedrop => "Screen eyedrop",
+            PossibleBinds::RecenterView => "Recenter view",
+            PossibleBinds::FitView => "Fit view",
+            PossibleBinds::PanDrag => "Drag view",
+            PossibleBinds::MoveReference => "Move reference",
+            PossibleBinds::ToggleRecolorPreview => "Toggle recolor preview",
+            PossibleBinds::PanLeft => "Pan left",
+            PossibleBinds::PanRight => "Pan right",
+            PossibleBinds::PanUp => "Pan up",
+            PossibleBinds::PanDown => "Pan down",
+            PossibleBinds::ZoomIn => "Zoom in",
+            PossibleBinds::ZoomOut => "Zoom out",
+            PossibleBinds::ZoomPreset1 => "Zoom 100%",
+            PossibleBinds::ZoomPreset2 => "Zoom 200%",
+            PossibleBinds::ZoomPreset3 => "Zoom 300%",
+            PossibleBinds::ZoomPreset4 => "Zoom 400%",
+            PossibleBinds::NextTab => "Next tab",
+            PossibleBinds::PreviousTab => "Previous tab",
+            PossibleBinds::FocusNext => "Focus next",
+            PossibleBinds::FocusPrevious => "Focus previous",
+        };
+        write!(f, "{}", name)
+    }
 }
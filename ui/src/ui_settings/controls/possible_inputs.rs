@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use sfml::window::{
+    joystick::Axis,
     mouse::{Button, Wheel},
     Key,
 };
@@ -119,6 +120,84 @@ pub enum PossibleInputs {
     F14,
     F15,
     Pause,
+    // Joystick Buttons
+    JoystickButton0,
+    JoystickButton1,
+    JoystickButton2,
+    JoystickButton3,
+    JoystickButton4,
+    JoystickButton5,
+    JoystickButton6,
+    JoystickButton7,
+    JoystickButton8,
+    JoystickButton9,
+    JoystickButton10,
+    JoystickButton11,
+    JoystickButton12,
+    JoystickButton13,
+    JoystickButton14,
+    JoystickButton15,
+    // Joystick axes. Each direction of an axis is held like a button
+    JoystickLeft,
+    JoystickRight,
+    JoystickUp,
+    JoystickDown,
+    DPadLeft,
+    DPadRight,
+    DPadUp,
+    DPadDown,
+}
+
+const JOYSTICK_BUTTONS: [PossibleInputs; 16] = [
+    PossibleInputs::JoystickButton0,
+    PossibleInputs::JoystickButton1,
+    PossibleInputs::JoystickButton2,
+    PossibleInputs::JoystickButton3,
+    PossibleInputs::JoystickButton4,
+    PossibleInputs::JoystickButton5,
+    PossibleInputs::JoystickButton6,
+    PossibleInputs::JoystickButton7,
+    PossibleInputs::JoystickButton8,
+    PossibleInputs::JoystickButton9,
+    PossibleInputs::JoystickButton10,
+    PossibleInputs::JoystickButton11,
+    PossibleInputs::JoystickButton12,
+    PossibleInputs::JoystickButton13,
+    PossibleInputs::JoystickButton14,
+    PossibleInputs::JoystickButton15,
+];
+
+impl PossibleInputs {
+    /// Buttons past the 16th of a joystick are ignored
+    pub fn from_joystick_button(button: u32) -> Option<Self> {
+        JOYSTICK_BUTTONS.get(button as usize).copied()
+    }
+
+    pub fn is_mouse(self) -> bool {
+        matches!(
+            self,
+            Self::ButtonLeft
+                | Self::ButtonRight
+                | Self::ButtonMiddle
+                | Self::Button1
+                | Self::Button2
+                | Self::VerticalWheel
+                | Self::HorizontalWheel
+        )
+    }
+
+    /// The negative and positive directions of a joystick axis. Only the first stick and
+    /// the d-pad are supported.
+    pub fn from_joystick_axis(axis: Axis) -> Option<(Self, Self)> {
+        match axis {
+            Axis::X => Some((Self::JoystickLeft, Self::JoystickRight)),
+            Axis::Y => Some((Self::JoystickUp, Self::JoystickDown)),
+            Axis::PovX => Some((Self::DPadLeft, Self::DPadRight)),
+            // SFML reports up as positive on the d-pad, unlike the sticks
+            Axis::PovY => Some((Self::DPadDown, Self::DPadUp)),
+            _ => None,
+        }
+    }
 }
 
 impl From<Button> for PossibleInputs {
@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::{chord::Chord, possible_inputs::PossibleInputs};
+
+/// One or more chords that have to be pressed in order, e.g. G then R.
+///
+/// Most shortcuts are a single chord.
+///
+/// # Usage:
+/// ```
+/// # use ui::ui_settings::controls::{
+/// #     chord::Chord, possible_inputs::PossibleInputs, shortcut::Shortcut,
+/// # };
+/// let shortcut = Shortcut::from(Chord::new(PossibleInputs::G)).then(Chord::new(PossibleInputs::R));
+/// assert_eq!(shortcut.to_string(), "G, R");
+/// assert!(shortcut.is_sequence());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Shortcut(Vec<Chord>);
+
+impl Shortcut {
+    /// Adds a chord that has to be pressed after the current ones
+    pub fn then(mut self, chord: Chord) -> Self {
+        self.0.push(chord);
+        self
+    }
+
+    pub fn chords(&self) -> &[Chord] {
+        &self.0
+    }
+
+    /// The chord that finishes the shortcut
+    pub fn last_chord(&self) -> Option<&Chord> {
+        self.0.last()
+    }
+
+    pub fn is_sequence(&self) -> bool {
+        self.0.len() > 1
+    }
+
+    /// The input of the shortcut if it is a single chord, whatever its modifiers are
+    pub fn single_input(&self) -> Option<PossibleInputs> {
+        match self.0.as_slice() {
+            [chord] => Some(chord.input),
+            _ => None,
+        }
+    }
+
+    /// True if the most recently pressed chords end with this shortcut
+    pub fn is_completed_by(&self, recent_chords: &[Chord]) -> bool {
+        !self.0.is_empty() && recent_chords.ends_with(&self.0)
+    }
+}
+
+impl From<Chord> for Shortcut {
+    fn from(chord: Chord) -> Self {
+        Self(vec![chord])
+    }
+}
+
+impl From<PossibleInputs> for Shortcut {
+    fn from(input: PossibleInputs) -> Self {
+        Self::from(Chord::new(input))
+    }
+}
+
+impl Display for Shortcut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let chords: Vec<String> = self.0.iter().map(Chord::to_string).collect();
+        write!(f, "{}", chords.join(", "))
+    }
+}
@@ -0,0 +1,30 @@
+use sfml::window::Event;
+
+use crate::{
+    dialogs::dialog_stack::DialogStack,
+    dom_controller::{focus::Focus, text_input::TextInput},
+    ui_settings::UISettings,
+};
+
+/// What every [`DomController`] shares while the app runs: which element has focus, which
+/// doms are open as dialogs and whether a text box is being typed in. Unlike
+/// [`UISettings`], none of it is saved.
+///
+/// Owned by the app, and passed to every dom with its events
+///
+/// [`DomController`]: crate::dom_controller::DomController
+#[derive(Clone, Debug, Default)]
+pub struct UIState {
+    pub focus: Focus,
+    pub dialogs: DialogStack,
+    pub text_input: TextInput,
+}
+
+impl UIState {
+    /// Expects `ui_settings` to have already seen `event`
+    pub fn event_handler(&mut self, ui_settings: &UISettings, event: Event) {
+        self.focus
+            .event_handler(&ui_settings.binds, ui_settings.cursor_position, event);
+        self.dialogs.event_handler(&ui_settings.binds);
+    }
+}
@@ -2,3 +2,7 @@ use std::time::Duration;
 
 pub const TIME_BETWEEN_UPDATES: Duration = Duration::from_millis(34);
 pub const TIME_BETWEEN_BIND_PRESSED: Duration = Duration::from_millis(400);
+/// How long the cursor has to rest on an element before its tooltip shows
+pub const TOOLTIP_DELAY: Duration = Duration::from_millis(600);
+/// Longest time between the clicks of a double click
+pub const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
@@ -65,6 +65,19 @@ impl Quad {
         ])
     }
 
+    /// Returns a quad over `bounds` that shows the part of the texture in `texture_rect`,
+    /// stretched if the sizes differ
+    pub fn with_texture_rect(bounds: FloatRect, texture_rect: IntRect) -> Quad {
+        let pos = rect_corner_positions(bounds);
+        let tx = rect_corner_positions::<f32>(texture_rect.as_other());
+        Quad([
+            Vertex::new(pos.0, VERTEX_DEFAULT_COLOR, tx.0),
+            Vertex::new(pos.1, VERTEX_DEFAULT_COLOR, tx.1),
+            Vertex::new(pos.2, VERTEX_DEFAULT_COLOR, tx.2),
+            Vertex::new(pos.3, VERTEX_DEFAULT_COLOR, tx.3),
+        ])
+    }
+
     pub fn set_texture_rect_coordinates_from_sprite(&mut self, sprite: RcSprite) {
         let tx = rect_corner_positions::<f32>(sprite.texture_rect().as_other());
         self[0].tex_coords = tx.0;
@@ -112,6 +125,24 @@ impl Quad {
             vertex.position += position_diff;
         }
     }
+
+    /// Splits the quad into two triangles.
+    ///
+    /// Lets many quads be batched into one [`PrimitiveType::TRIANGLES`] draw call.
+    ///
+    /// # Usage:
+    /// ```
+    /// # use utils::quads::Quad;
+    /// # use sfml::graphics::FloatRect;
+    /// let quad = Quad::from(FloatRect::new(0., 0., 1., 1.));
+    /// let triangles = quad.as_triangles();
+    /// assert_eq!(triangles[0].position, quad[0].position);
+    /// assert_eq!(triangles[2].position, quad[2].position);
+    /// assert_eq!(triangles[5].position, quad[3].position);
+    /// ```
+    pub fn as_triangles(&self) -> [Vertex; 6] {
+        [self[0], self[1], self[2], self[0], self[2], self[3]]
+    }
 }
 
 impl Index<usize> for Quad {